@@ -26,6 +26,7 @@ async fn create_test_config(enable_cache: bool) -> ClientConfig {
         cache: cache_store,
         bypass_cache: false,
         benchmark_mode: true,
+        offline: false,
     }
 }
 
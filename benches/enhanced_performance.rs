@@ -35,6 +35,7 @@ async fn create_optimized_config(
                     max_size: 10 * 1024 * 1024, // 10MB
                     default_ttl: chrono::Duration::hours(1),
                     db_path: tempfile::NamedTempFile::new().unwrap().path().to_path_buf(),
+                    stale_window: chrono::Duration::days(7),
                 },
                 enable_l3: false,
                 l3_dir: tempfile::tempdir().unwrap().path().to_path_buf(),
@@ -47,6 +48,7 @@ async fn create_optimized_config(
                     max_size: 10 * 1024 * 1024, // 10MB
                     default_ttl: chrono::Duration::hours(1),
                     db_path: tempfile::NamedTempFile::new().unwrap().path().to_path_buf(),
+                    stale_window: chrono::Duration::days(7),
                 })
                 .await
                 .unwrap(),
@@ -66,6 +68,7 @@ async fn create_optimized_config(
         cache: cache_store.0.clone(),
         bypass_cache: false,
         benchmark_mode: true,
+        offline: false,
     };
 
     let batcher = if enable_batching {
@@ -79,6 +82,7 @@ async fn create_optimized_config(
             cache: client_config.cache.clone(),
             bypass_cache: false,
             benchmark_mode: true,
+            offline: false,
         };
         let client = ApiClientFactory::create(ApiType::Nlic, bench_config).unwrap();
         let batch_config = BatchConfig {
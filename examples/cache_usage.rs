@@ -11,6 +11,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_size: 50 * 1024 * 1024,       // 50MB
         default_ttl: Duration::hours(12), // 12 hours
         db_path: PathBuf::from("example_cache.db"),
+        stale_window: Duration::days(7),
     };
 
     // Initialize cache store
@@ -44,6 +44,8 @@ struct RequestKey {
     page_size: u32,
     law_type: Option<String>,
     department: Option<String>,
+    /// Remaining filters (region, dates, sort, scope, extras) in a fixed order
+    filters: String,
 }
 
 impl Hash for RequestKey {
@@ -53,6 +55,7 @@ impl Hash for RequestKey {
         self.page_size.hash(state);
         self.law_type.hash(state);
         self.department.hash(state);
+        self.filters.hash(state);
     }
 }
 
@@ -64,6 +67,19 @@ impl From<&UnifiedSearchRequest> for RequestKey {
             page_size: request.page_size,
             law_type: request.law_type.clone(),
             department: request.department.clone(),
+            filters: {
+                let mut extras: Vec<_> = request.extras.iter().collect();
+                extras.sort();
+                format!(
+                    "{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+                    request.region,
+                    request.date_from,
+                    request.date_to,
+                    request.sort,
+                    request.search_scope,
+                    extras
+                )
+            },
         }
     }
 }
@@ -281,7 +297,9 @@ impl RequestBatcher {
         // Group requests by similarity for efficient batching
         let grouped_requests = Self::group_similar_requests(batch);
 
-        for (_, group) in grouped_requests {
+        // Distinct requests of a batch run concurrently, so at most
+        // `max_batch_size` requests are in flight at once
+        futures::future::join_all(grouped_requests.into_values().map(|group| {
             Self::process_request_group(
                 Arc::clone(&client),
                 config,
@@ -289,8 +307,8 @@ impl RequestBatcher {
                 Arc::clone(&response_cache),
                 Arc::clone(&in_flight),
             )
-            .await;
-        }
+        }))
+        .await;
     }
 
     /// Group similar requests together for batch processing
@@ -511,6 +529,8 @@ mod tests {
                 items: vec![],
                 source: "MockBatchAPI".to_string(),
                 timestamp: chrono::Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
             })
         }
 
@@ -619,6 +639,29 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_requests_with_different_filters_are_not_merged() {
+        let client = Arc::new(MockBatchClient::new(Duration::from_millis(10)));
+        let batcher = RequestBatcher::new(client.clone(), BatchConfig::default());
+
+        let request = UnifiedSearchRequest {
+            query: "test".to_string(),
+            date_from: Some("20100101".to_string()),
+            ..Default::default()
+        };
+        batcher.submit_request(request.clone()).await.unwrap();
+        batcher
+            .submit_request(UnifiedSearchRequest {
+                date_from: Some("20200101".to_string()),
+                ..request.clone()
+            })
+            .await
+            .unwrap();
+        batcher.submit_request(request).await.unwrap();
+
+        assert_eq!(client.call_count.load(Ordering::Relaxed), 2);
+    }
+
     #[tokio::test]
     async fn test_batch_stats() {
         let client = Arc::new(MockBatchClient::new(Duration::from_millis(10)));
@@ -1,8 +1,12 @@
 use super::types::*;
 use super::ApiType;
-use crate::cache::CacheStore;
-use crate::error::Result;
+use crate::cache::{CacheLookup, CacheStore};
+use crate::error::{Result, WarpError};
+use crate::metrics::get_global_metrics;
 use async_trait::async_trait;
+use log::{debug, warn};
+use serde::de::DeserializeOwned;
+use std::future::Future;
 use std::sync::Arc;
 
 /// Core trait for Korean legal API clients
@@ -14,6 +18,7 @@ use std::sync::Arc;
 /// # Examples
 ///
 /// ```no_run
+/// use warp::api::client::ClientConfig;
 /// use warp::api::{ApiClientFactory, ApiType, LegalApiClient};
 /// use warp::api::types::UnifiedSearchRequest;
 /// use warp::config::Config;
@@ -21,7 +26,11 @@ use std::sync::Arc;
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let config = Config::load()?;
-/// let client = ApiClientFactory::create(ApiType::Nlic, config.to_client_config())?;
+/// let client_config = ClientConfig {
+///     api_key: config.get_nlic_api_key().unwrap_or_default(),
+///     ..Default::default()
+/// };
+/// let client = ApiClientFactory::create(ApiType::Nlic, client_config)?;
 ///
 /// // Search for documents
 /// let request = UnifiedSearchRequest {
@@ -31,11 +40,9 @@ use std::sync::Arc;
 /// let response = client.search(request).await?;
 ///
 /// // Get detailed information
-/// if let Some(laws) = &response.laws {
-///     if let Some(first_law) = laws.first() {
-///         let detail = client.get_detail(&first_law.law_id).await?;
-///         println!("Law: {}", detail.law_name);
-///     }
+/// if let Some(first_law) = response.items.first() {
+///     let detail = client.get_detail(&first_law.id).await?;
+///     println!("Law: {}", detail.law_name);
 /// }
 /// # Ok(())
 /// # }
@@ -142,11 +149,13 @@ pub trait LegalApiClient: Send + Sync {
 ///
 /// ```no_run
 /// use warp::api::client::ClientConfig;
-/// use warp::cache::CacheStore;
+/// use warp::cache::{CacheConfig, CacheStore};
 /// use std::sync::Arc;
 ///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// // Configuration with caching
-/// let cache = Arc::new(CacheStore::new("cache_dir").unwrap());
+/// let cache = Arc::new(CacheStore::new(CacheConfig::default()).await?);
 /// let config = ClientConfig {
 ///     api_key: "your-key".to_string(),
 ///     timeout: 30,
@@ -154,6 +163,8 @@ pub trait LegalApiClient: Send + Sync {
 ///     bypass_cache: false,
 ///     ..Default::default()
 /// };
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
@@ -204,6 +215,13 @@ pub struct ClientConfig {
     /// Disables background tasks and optimizations that might
     /// interfere with performance benchmarking.
     pub benchmark_mode: bool,
+
+    /// Whether to serve only from cache
+    ///
+    /// When `true`, no HTTP request is performed. Cached responses are
+    /// returned even if expired, and cache misses fail with
+    /// [`WarpError::Offline`].
+    pub offline: bool,
 }
 
 impl Default for ClientConfig {
@@ -217,10 +235,139 @@ impl Default for ClientConfig {
             cache: None,
             bypass_cache: false,
             benchmark_mode: false,
+            offline: false,
         }
     }
 }
 
+impl ClientConfig {
+    /// Whether cached responses should be consulted before requesting
+    pub fn reads_cache(&self) -> bool {
+        self.offline || !self.bypass_cache
+    }
+
+    /// Fail instead of performing HTTP when running offline
+    pub fn ensure_online(&self, item: impl Into<String>) -> Result<()> {
+        if self.offline {
+            Err(WarpError::Offline(item.into()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Serve a response from cache or run `fetch` to get a fresh one
+    ///
+    /// - fresh entries are returned as-is
+    /// - entries expired within the cache's stale window are returned
+    ///   flagged as stale while `fetch` refreshes them in the background
+    /// - older entries are returned flagged as stale only when `fetch` fails
+    ///   with a retryable error
+    /// - in offline mode any cached entry is returned and `fetch` never runs
+    ///
+    /// `fetch` is expected to store its result in the cache. `item` describes
+    /// the request for the offline error message.
+    pub async fn cached_or_fetch<T, F>(
+        &self,
+        api_type: ApiType,
+        cache_key: &str,
+        item: impl Into<String>,
+        fetch: F,
+    ) -> Result<T>
+    where
+        T: StaleResponse + Send + 'static,
+        F: Future<Output = Result<T>> + Send + 'static,
+    {
+        let metrics = get_global_metrics();
+        let mut fallback = None;
+
+        if let Some(cache) = self.cache.as_ref().filter(|_| self.reads_cache()) {
+            let lookup = cache.lookup(cache_key).await?;
+            let (data, fresh, revalidate) = match lookup {
+                CacheLookup::Fresh(data) => (Some(data), true, false),
+                CacheLookup::Stale(data) => (Some(data), false, true),
+                CacheLookup::Expired(data) => (Some(data), false, false),
+                CacheLookup::Miss => (None, false, false),
+            };
+
+            match data.map(|data| serde_json::from_slice::<T>(&data)) {
+                Some(Ok(mut cached)) => {
+                    metrics.record_cache_hit(api_type.as_str());
+                    if fresh {
+                        debug!("Cache hit for key: {}", cache_key);
+                        return Ok(cached);
+                    }
+
+                    cached.mark_stale();
+                    if self.offline {
+                        debug!("Serving expired entry offline for key: {}", cache_key);
+                        return Ok(cached);
+                    }
+                    if revalidate {
+                        debug!("Serving stale entry and refreshing key: {}", cache_key);
+                        let key = cache_key.to_string();
+                        crate::cache::spawn_refresh(async move {
+                            if let Err(e) = fetch.await {
+                                debug!("Background refresh failed for key {}: {}", key, e);
+                            }
+                        });
+                        return Ok(cached);
+                    }
+                    fallback = Some(cached);
+                }
+                Some(Err(e)) => {
+                    warn!(
+                        "Failed to deserialize cached entry: {}, removing from cache",
+                        e
+                    );
+                    let _ = cache.remove(cache_key).await;
+                    metrics.record_cache_miss(api_type.as_str());
+                }
+                None => {
+                    debug!("Cache miss for key: {}", cache_key);
+                    metrics.record_cache_miss(api_type.as_str());
+                }
+            }
+        }
+
+        self.ensure_online(item)?;
+
+        match fetch.await {
+            Err(e) if e.is_retryable() => match fallback {
+                Some(stale) => {
+                    warn!("Request failed ({}), serving stale cache entry", e);
+                    Ok(stale)
+                }
+                None => Err(e),
+            },
+            result => result,
+        }
+    }
+}
+
+/// Responses that can be served from an expired cache entry
+pub trait StaleResponse: DeserializeOwned {
+    /// Flag the response as served from an expired cache entry
+    fn mark_stale(&mut self);
+}
+
+impl StaleResponse for SearchResponse {
+    fn mark_stale(&mut self) {
+        self.stale = true;
+    }
+}
+
+impl StaleResponse for LawDetail {
+    fn mark_stale(&mut self) {
+        self.stale = true;
+    }
+}
+
+impl StaleResponse for LawHistory {
+    fn mark_stale(&mut self) {
+        self.stale = true;
+    }
+}
+
 /// Factory for creating Korean legal API clients
 ///
 /// Provides a centralized way to create and configure API clients for
@@ -249,6 +396,7 @@ impl Default for ClientConfig {
 /// ```
 ///
 /// ```no_run
+/// use warp::api::client::ClientConfig;
 /// use warp::api::{ApiClientFactory, ApiType};
 /// use warp::config::Config;
 ///
@@ -262,7 +410,11 @@ impl Default for ClientConfig {
 /// let mut clients = Vec::new();
 ///
 /// for api_type in apis {
-///     let client = ApiClientFactory::create(api_type, config.to_client_config())?;
+///     let client_config = ClientConfig {
+///         api_key: config.get_api_key(api_type.as_str()).unwrap_or_default(),
+///         ..Default::default()
+///     };
+///     let client = ApiClientFactory::create(api_type, client_config)?;
 ///     clients.push(client);
 /// }
 /// # Ok(())
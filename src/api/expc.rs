@@ -18,6 +18,7 @@ const BASE_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 const DETAIL_URL: &str = "https://www.law.go.kr/DRF/lawService.do";
 
 /// EXPC (법령해석례) API Client
+#[derive(Clone)]
 pub struct ExpcClient {
     config: ClientConfig,
     client: Client,
@@ -33,31 +34,6 @@ impl ExpcClient {
         Self { config, client }
     }
 
-    /// Check cache for cached search response
-    async fn check_search_cache(&self, cache_key: &str) -> Result<Option<SearchResponse>> {
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for EXPC search key: {}", cache_key);
-                if let Some(cached_data) = cache.get(cache_key).await? {
-                    debug!("Cache hit for EXPC search key: {}", cache_key);
-                    match serde_json::from_slice::<SearchResponse>(&cached_data) {
-                        Ok(response) => {
-                            info!("Successfully retrieved cached EXPC search response");
-                            return Ok(Some(response));
-                        }
-                        Err(e) => {
-                            warn!("Failed to deserialize cached EXPC search response: {}, removing from cache", e);
-                            let _ = cache.remove(cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for EXPC search key: {}", cache_key);
-                }
-            }
-        }
-        Ok(None)
-    }
-
     /// Store search response in cache
     async fn store_search_in_cache(
         &self,
@@ -187,6 +163,8 @@ impl ExpcClient {
                     summary: interp.interpretation_summary,
                     source: "EXPC".to_string(),
                     metadata,
+                    score: None,
+                    sources: Vec::new(),
                 }
             })
             .collect();
@@ -198,32 +176,17 @@ impl ExpcClient {
             items,
             source: "EXPC".to_string(),
             timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
         }
     }
-}
-
-#[async_trait]
-impl LegalApiClient for ExpcClient {
-    async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
-        if self.config.api_key.is_empty() {
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for this EXPC search request
-        let cache_key = CacheKeyGenerator::expc_key(
-            "search",
-            Some(&request.query),
-            None,                          // interpretation_type not used in this request
-            request.department.as_deref(), // requesting_agency
-            Some(request.page_no),
-            Some(request.page_size),
-        );
-
-        // Check cache first
-        if let Some(cached_response) = self.check_search_cache(&cache_key).await? {
-            return Ok(cached_response);
-        }
 
+    /// Fetch search results from the API and store them in cache
+    async fn fetch_search(
+        &self,
+        cache_key: &str,
+        request: UnifiedSearchRequest,
+    ) -> Result<SearchResponse> {
         // Calculate the starting position (offset) for the API
         let offset = ((request.page_no - 1) * request.page_size) + 1;
 
@@ -234,17 +197,19 @@ impl LegalApiClient for ExpcClient {
             ("query", request.query.clone()),
             ("page", offset.to_string()), // Use offset instead of page number
             ("display", request.page_size.to_string()),
+            ("search", request.search_scope.drf_code().to_string()),
         ];
 
         // Add optional parameters
         if let Some(department) = &request.department {
             params.push(("org", department.clone()));
         }
-        if let Some(date_from) = &request.date_from {
-            params.push(("fromDate", date_from.clone()));
+        // Interpretations have no in-force status, so the `status` extra is ignored
+        if let Some(date_range) = request.drf_date_range() {
+            params.push(("explYd", date_range)); // 해석일자 range
         }
-        if let Some(date_to) = &request.date_to {
-            params.push(("toDate", date_to.clone()));
+        if let Some(sort) = request.sort.and_then(|s| s.drf_code()) {
+            params.push(("sort", sort.to_string()));
         }
 
         let url = reqwest::Url::parse_with_params(BASE_URL, &params)
@@ -306,46 +271,15 @@ impl LegalApiClient for ExpcClient {
         let response = self.parse_search_response(raw, request.page_no);
 
         // Store in cache
-        if let Err(e) = self.store_search_in_cache(&cache_key, &response).await {
+        if let Err(e) = self.store_search_in_cache(cache_key, &response).await {
             warn!("Failed to cache EXPC search response: {}", e);
         }
 
         Ok(response)
     }
 
-    async fn get_detail(&self, id: &str) -> Result<LawDetail> {
-        if self.config.api_key.is_empty() {
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for detail request
-        let cache_key = format!("{}:detail:{}", self.api_type().as_str(), id);
-
-        // Check cache for detail response
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for EXPC detail key: {}", cache_key);
-                if let Some(cached_data) = cache.get(&cache_key).await? {
-                    debug!("Cache hit for EXPC detail key: {}", cache_key);
-                    match serde_json::from_slice::<LawDetail>(&cached_data) {
-                        Ok(detail) => {
-                            info!("Successfully retrieved cached EXPC law detail");
-                            return Ok(detail);
-                        }
-                        Err(e) => {
-                            warn!(
-                                "Failed to deserialize cached EXPC detail: {}, removing from cache",
-                                e
-                            );
-                            let _ = cache.remove(&cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for EXPC detail key: {}", cache_key);
-                }
-            }
-        }
-
+    /// Fetch a detail from the API and store it in cache
+    async fn fetch_detail(&self, cache_key: &str, id: &str) -> Result<LawDetail> {
         let params = vec![
             ("OC", self.config.api_key.clone()),
             ("target", "expc".to_string()),
@@ -356,6 +290,9 @@ impl LegalApiClient for ExpcClient {
         let url = reqwest::Url::parse_with_params(DETAIL_URL, &params)
             .map_err(|e| WarpError::Parse(e.to_string()))?;
 
+        self.config
+            .ensure_online(format!("{} 상세 {}", self.api_type().display_name(), id))?;
+
         let response = self.execute_with_retry(url.to_string()).await?;
         let response_text = response.text().await.map_err(WarpError::Network)?;
 
@@ -384,7 +321,7 @@ impl LegalApiClient for ExpcClient {
                 match serde_json::to_vec(&detail) {
                     Ok(serialized) => {
                         if let Err(e) = cache
-                            .put(&cache_key, serialized, self.api_type(), None)
+                            .put(cache_key, serialized, self.api_type(), None)
                             .await
                         {
                             warn!("Failed to store EXPC detail in cache: {}", e);
@@ -401,6 +338,50 @@ impl LegalApiClient for ExpcClient {
 
         Ok(detail)
     }
+}
+
+#[async_trait]
+impl LegalApiClient for ExpcClient {
+    async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+        if self.config.api_key.is_empty() && !self.config.offline {
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for this EXPC search request
+        let cache_key = CacheKeyGenerator::search_key(self.api_type(), &request);
+
+        let item = format!(
+            "{} 검색 '{}'",
+            self.api_type().display_name(),
+            request.query
+        );
+        let client = self.clone();
+        let key = cache_key.clone();
+        self.config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_search(&key, request).await
+            })
+            .await
+    }
+
+    async fn get_detail(&self, id: &str) -> Result<LawDetail> {
+        if self.config.api_key.is_empty() && !self.config.offline {
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for detail request
+        let cache_key = format!("{}:detail:{}", self.api_type().as_str(), id);
+
+        let item = format!("{} 상세 {}", self.api_type().display_name(), id);
+        let client = self.clone();
+        let key = cache_key.clone();
+        let id = id.to_string();
+        self.config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_detail(&key, &id).await
+            })
+            .await
+    }
 
     async fn get_history(&self, _id: &str) -> Result<LawHistory> {
         // Legal interpretations don't have history in the same way laws do
@@ -410,6 +391,7 @@ impl LegalApiClient for ExpcClient {
             law_name: String::new(),
             total_count: 0,
             entries: vec![],
+            stale: false,
         })
     }
 
@@ -541,6 +523,8 @@ impl ExpcDetailResponse {
             attachments: vec![],
             related_laws: vec![],
             metadata: HashMap::new(),
+            fetched_at: Some(Utc::now()),
+            stale: false,
         }
     }
 }
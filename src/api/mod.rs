@@ -23,6 +23,7 @@
 //! ### Basic Law Search
 //!
 //! ```no_run
+//! use warp::api::client::ClientConfig;
 //! use warp::api::{ApiClientFactory, ApiType};
 //! use warp::api::types::UnifiedSearchRequest;
 //! use warp::config::Config;
@@ -30,18 +31,22 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let config = Config::load()?;
-//! let client = ApiClientFactory::create(ApiType::Nlic, config.to_client_config())?;
+//! let client_config = ClientConfig {
+//!     api_key: config.get_nlic_api_key().unwrap_or_default(),
+//!     ..Default::default()
+//! };
+//! let client = ApiClientFactory::create(ApiType::Nlic, client_config)?;
 //!
 //! let request = UnifiedSearchRequest {
 //!     query: "민법".to_string(),
-//!     page: Some(1),
-//!     size: Some(20),
+//!     page_no: 1,
+//!     page_size: 20,
 //!     ..Default::default()
 //! };
 //!
 //! let response = client.search(request).await?;
-//! for law in response.laws.unwrap_or_default() {
-//!     println!("{}: {}", law.law_num, law.law_name);
+//! for law in response.items {
+//!     println!("{}: {}", law.law_no.unwrap_or_default(), law.title);
 //! }
 //! # Ok(())
 //! # }
@@ -50,22 +55,34 @@
 //! ### Unified Multi-API Search
 //!
 //! ```no_run
-//! use warp::api::{ApiClientFactory, ApiType};
+//! use warp::api::client::ClientConfig;
+//! use warp::api::parallel::search_all_apis;
 //! use warp::api::types::UnifiedSearchRequest;
+//! use warp::api::{ApiClientFactory, ApiType, LegalApiClient};
 //! use warp::config::Config;
+//! use std::sync::Arc;
 //!
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let config = Config::load()?;
-//! let client = ApiClientFactory::create(ApiType::All, config.to_client_config())?;
+//! let mut clients: Vec<(ApiType, Arc<dyn LegalApiClient>)> = Vec::new();
+//! for api_type in [ApiType::Nlic, ApiType::Prec, ApiType::Expc] {
+//!     let client_config = ClientConfig {
+//!         api_key: config.get_api_key(api_type.as_str()).unwrap_or_default(),
+//!         ..Default::default()
+//!     };
+//!     clients.push((api_type, Arc::from(ApiClientFactory::create(api_type, client_config)?)));
+//! }
 //!
 //! let request = UnifiedSearchRequest {
 //!     query: "환경보호".to_string(),
 //!     ..Default::default()
 //! };
 //!
-//! let response = client.search(request).await?;
-//! println!("Total results across all APIs: {}", response.total_count.unwrap_or(0));
+//! let result = search_all_apis(clients, request).await?;
+//! if let Some(response) = result.merge_responses() {
+//!     println!("Total results across all APIs: {}", response.total_count);
+//! }
 //! # Ok(())
 //! # }
 //! ```
@@ -73,17 +90,24 @@
 //! ### Detailed Document Retrieval
 //!
 //! ```no_run
+//! use warp::api::client::ClientConfig;
 //! use warp::api::{ApiClientFactory, ApiType};
 //! use warp::config::Config;
 //!
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let config = Config::load()?;
-//! let client = ApiClientFactory::create(ApiType::Nlic, config.to_client_config())?;
+//! let client_config = ClientConfig {
+//!     api_key: config.get_nlic_api_key().unwrap_or_default(),
+//!     ..Default::default()
+//! };
+//! let client = ApiClientFactory::create(ApiType::Nlic, client_config)?;
 //!
 //! let detail = client.get_detail("000001").await?;
 //! println!("Law: {}", detail.law_name);
-//! println!("Content: {}", detail.law_content.unwrap_or_default());
+//! for article in &detail.articles {
+//!     println!("{}: {}", article.number, article.content);
+//! }
 //! # Ok(())
 //! # }
 //! ```
@@ -124,7 +148,7 @@ pub use client::{ApiClientFactory, LegalApiClient};
 /// // Display names in Korean
 /// assert_eq!(api_type.display_name(), "국가법령정보센터");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ApiType {
     /// **National Law Information Center** (국가법령정보센터)
     ///
@@ -225,4 +249,18 @@ impl ApiType {
             Self::All => "통합검색",
         }
     }
+
+    /// Source named by [`display_name`](Self::display_name), as unified search
+    /// writes it into `SearchItem::source`
+    pub fn from_display_name(name: &str) -> Option<Self> {
+        [Self::Nlic, Self::Elis, Self::Prec, Self::Admrul, Self::Expc]
+            .into_iter()
+            .find(|api_type| api_type.display_name() == name)
+    }
+
+    /// Whether the source has a revision history endpoint; the clients of the
+    /// others answer `get_history` with an empty history
+    pub fn has_history(&self) -> bool {
+        matches!(self, Self::Nlic)
+    }
 }
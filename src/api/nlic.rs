@@ -41,6 +41,7 @@ const SEARCH_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 /// ## Basic Search
 ///
 /// ```no_run
+/// use warp::api::client::ClientConfig;
 /// use warp::api::{ApiClientFactory, ApiType};
 /// use warp::api::types::UnifiedSearchRequest;
 /// use warp::config::Config;
@@ -48,23 +49,25 @@ const SEARCH_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let config = Config::load()?;
-/// let client = ApiClientFactory::create(ApiType::Nlic, config.to_client_config())?;
+/// let client_config = ClientConfig {
+///     api_key: config.get_nlic_api_key().unwrap_or_default(),
+///     ..Default::default()
+/// };
+/// let client = ApiClientFactory::create(ApiType::Nlic, client_config)?;
 ///
 /// let request = UnifiedSearchRequest {
 ///     query: "민법".to_string(),
-///     page: Some(1),
-///     size: Some(10),
+///     page_no: 1,
+///     page_size: 10,
 ///     ..Default::default()
 /// };
 ///
 /// let response = client.search(request).await?;
-/// println!("Found {} laws", response.total_count.unwrap_or(0));
+/// println!("Found {} laws", response.total_count);
 ///
 /// // Process results
-/// if let Some(laws) = response.laws {
-///     for law in laws.iter().take(5) {
-///         println!("- {}: {}", law.law_num, law.law_name);
-///     }
+/// for law in response.items.iter().take(5) {
+///     println!("- {}: {}", law.law_no.as_deref().unwrap_or("-"), law.title);
 /// }
 /// # Ok(())
 /// # }
@@ -73,37 +76,41 @@ const SEARCH_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 /// ## Advanced Search with Filters
 ///
 /// ```no_run
+/// use warp::api::client::ClientConfig;
 /// use warp::api::{ApiClientFactory, ApiType};
-/// use warp::api::types::UnifiedSearchRequest;
+/// use warp::api::types::{SortOrder, UnifiedSearchRequest};
 /// use warp::config::Config;
 ///
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let config = Config::load()?;
-/// let client = ApiClientFactory::create(ApiType::Nlic, config.to_client_config())?;
+/// let client_config = ClientConfig {
+///     api_key: config.get_nlic_api_key().unwrap_or_default(),
+///     ..Default::default()
+/// };
+/// let client = ApiClientFactory::create(ApiType::Nlic, client_config)?;
 ///
-/// let request = UnifiedSearchRequest {
+/// let mut request = UnifiedSearchRequest {
 ///     query: "환경보호".to_string(),
-///     page: Some(1),
-///     size: Some(20),
-///     sort: Some("날짜".to_string()),
-///     search_target: Some("법령명".to_string()),
+///     page_no: 1,
+///     page_size: 20,
+///     sort: Some(SortOrder::DateDesc),
+///     date_from: Some("20200101".to_string()),
 ///     law_type: Some("법률".to_string()),
 ///     ..Default::default()
 /// };
+/// request.extras.insert("status".to_string(), "시행중".to_string());
 ///
 /// let response = client.search(request).await?;
 ///
 /// // Get detailed information for first result
-/// if let Some(laws) = &response.laws {
-///     if let Some(first_law) = laws.first() {
-///         let detail = client.get_detail(&first_law.law_id).await?;
-///         println!("Law: {}", detail.law_name);
+/// if let Some(first_law) = response.items.first() {
+///     let detail = client.get_detail(&first_law.id).await?;
+///     println!("Law: {}", detail.law_name);
 ///
-///         // Get revision history
-///         let history = client.get_history(&first_law.law_id).await?;
-///         println!("Revisions: {}", history.revisions.len());
-///     }
+///     // Get revision history
+///     let history = client.get_history(&first_law.id).await?;
+///     println!("Revisions: {}", history.entries.len());
 /// }
 /// # Ok(())
 /// # }
@@ -114,13 +121,13 @@ const SEARCH_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 /// ```no_run
 /// use warp::api::{ApiClientFactory, ApiType};
 /// use warp::api::client::ClientConfig;
-/// use warp::cache::CacheStore;
+/// use warp::cache::{CacheConfig, CacheStore};
 /// use std::sync::Arc;
 ///
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// // Configure with caching for better performance
-/// let cache = Arc::new(CacheStore::new("./cache")?);
+/// let cache = Arc::new(CacheStore::new(CacheConfig::default()).await?);
 /// let config = ClientConfig {
 ///     api_key: "your-api-key".to_string(),
 ///     timeout: 60,
@@ -140,6 +147,7 @@ const SEARCH_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct NlicClient {
     config: ClientConfig,
     http_client: Client,
@@ -165,6 +173,8 @@ impl NlicClient {
     /// use warp::api::nlic::NlicClient;
     /// use warp::api::client::ClientConfig;
     ///
+    /// # #[tokio::main]
+    /// # async fn main() {
     /// let config = ClientConfig {
     ///     api_key: "your-api-key".to_string(),
     ///     timeout: 60,
@@ -173,6 +183,7 @@ impl NlicClient {
     /// };
     ///
     /// let client = NlicClient::new(config);
+    /// # }
     /// ```
     pub fn new(config: ClientConfig) -> Self {
         // Use appropriate HTTP client based on benchmark mode
@@ -188,36 +199,6 @@ impl NlicClient {
         }
     }
 
-    /// Check cache for cached response
-    async fn check_cache(&self, cache_key: &str) -> Result<Option<SearchResponse>> {
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for key: {}", cache_key);
-                if let Some(cached_data) = cache.get(cache_key).await? {
-                    debug!("Cache hit for key: {}", cache_key);
-                    match serde_json::from_slice::<SearchResponse>(&cached_data) {
-                        Ok(response) => {
-                            info!("Successfully retrieved cached search response");
-                            return Ok(Some(response));
-                        }
-                        Err(e) => {
-                            warn!(
-                                "Failed to deserialize cached response: {}, removing from cache",
-                                e
-                            );
-                            let _ = cache.remove(cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for key: {}", cache_key);
-                }
-            } else {
-                debug!("Cache bypassed for key: {}", cache_key);
-            }
-        }
-        Ok(None)
-    }
-
     /// Store response in cache
     async fn store_in_cache(&self, cache_key: &str, response: &SearchResponse) -> Result<()> {
         if let Some(ref cache) = self.config.cache {
@@ -342,6 +323,8 @@ impl NlicClient {
                     summary: law.law_summary,
                     source: "NLIC".to_string(),
                     metadata,
+                    score: None,
+                    sources: Vec::new(),
                 }
             })
             .collect();
@@ -353,53 +336,37 @@ impl NlicClient {
             items,
             source: "NLIC".to_string(),
             timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
         }
     }
-}
-
-#[async_trait]
-impl LegalApiClient for NlicClient {
-    async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
-        // Start performance monitoring
-        let timer = OperationTimer::start("nlic_search".to_string(), get_global_metrics());
-        let metrics = get_global_metrics();
-
-        if self.config.api_key.is_empty() {
-            timer.finish_failure();
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for this request
-        let cache_key = CacheKeyGenerator::nlic_key(
-            "search",
-            Some(&request.query),
-            request.law_type.as_deref(),
-            Some(request.page_no),
-            Some(request.page_size),
-        );
-
-        // Check cache first
-        if let Some(cached_response) = self.check_cache(&cache_key).await? {
-            metrics.record_cache_hit("nlic");
-            timer.finish_success();
-            return Ok(cached_response);
-        }
-
-        metrics.record_cache_miss("nlic");
 
+    /// Fetch search results from the API and store them in cache
+    async fn fetch_search(
+        &self,
+        cache_key: &str,
+        request: UnifiedSearchRequest,
+    ) -> Result<SearchResponse> {
         // Calculate the starting position (offset) for the API
         // The API seems to expect an offset rather than a page number
         // For page 1 with size 10, offset should be 1
         // For page 2 with size 10, offset should be 11
         let offset = ((request.page_no - 1) * request.page_size) + 1;
 
+        // Current/scheduled/history filters are only available on the
+        // enforcement-date based `eflaw` target
+        let statuses = request.status_filters();
+        let nw = statuses.iter().find_map(|s| s.eflaw_nw_code());
+        let target = if nw.is_some() { "eflaw" } else { "law" };
+
         let mut params = vec![
             ("OC", self.config.api_key.clone()),
-            ("target", "law".to_string()),
+            ("target", target.to_string()),
             ("type", "JSON".to_string()),
             ("query", request.query.clone()),
             ("page", offset.to_string()), // Use offset instead of page number
             ("display", request.page_size.to_string()),
+            ("search", request.search_scope.drf_code().to_string()),
         ];
 
         // Add optional parameters
@@ -409,8 +376,18 @@ impl LegalApiClient for NlicClient {
         if let Some(department) = &request.department {
             params.push(("ORG", department.clone()));
         }
-        if let Some(date_from) = &request.date_from {
-            params.push(("efYd", date_from.clone()));
+        if let Some(date_range) = request.drf_date_range() {
+            params.push(("efYd", date_range));
+        }
+        if let Some(sort) = request.sort.and_then(|s| s.drf_code()) {
+            params.push(("sort", sort.to_string()));
+        }
+        if let Some(nw) = nw {
+            params.push(("nw", nw.to_string()));
+        }
+        let revision_codes: Vec<&str> = statuses.iter().filter_map(|s| s.revision_code()).collect();
+        if !revision_codes.is_empty() {
+            params.push(("rrClsCd", revision_codes.join(",")));
         }
 
         // Build URL with query parameters
@@ -433,7 +410,6 @@ impl LegalApiClient for NlicClient {
 
         // Check if response is HTML (common when API key is invalid)
         if is_html || response_text.starts_with("<") {
-            timer.finish_failure();
             return Err(WarpError::ApiError {
                 code: "INVALID_RESPONSE".to_string(),
                 message: "API returned HTML instead of JSON. This usually means the API key is invalid or the service is unavailable.".to_string(),
@@ -443,7 +419,6 @@ impl LegalApiClient for NlicClient {
 
         // Check if response is empty
         if response_text.trim().is_empty() {
-            timer.finish_failure();
             return Err(WarpError::ApiError {
                 code: "EMPTY_RESPONSE".to_string(),
                 message: "API returned an empty response.".to_string(),
@@ -458,7 +433,6 @@ impl LegalApiClient for NlicClient {
         let raw: NlicSearchResponse = match serde_json::from_str(&response_text) {
             Ok(parsed) => parsed,
             Err(e) => {
-                timer.finish_failure();
                 // Try to provide more context about the error
                 if response_text.contains("error") || response_text.contains("Error") {
                     return Err(WarpError::ApiError {
@@ -482,58 +456,28 @@ impl LegalApiClient for NlicClient {
         let response = self.parse_search_response(raw, request.page_no);
 
         // Store in cache
-        if let Err(e) = self.store_in_cache(&cache_key, &response).await {
+        if let Err(e) = self.store_in_cache(cache_key, &response).await {
             warn!("Failed to cache response: {}", e);
         }
 
-        timer.finish_success();
         Ok(response)
     }
 
-    async fn get_detail(&self, id: &str) -> Result<LawDetail> {
-        if self.config.api_key.is_empty() {
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for detail request
-        let cache_key = format!("{}:detail:{}", self.api_type().as_str(), id);
-
-        // Check cache for detail response
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for detail key: {}", cache_key);
-                if let Some(cached_data) = cache.get(&cache_key).await? {
-                    debug!("Cache hit for detail key: {}", cache_key);
-                    match serde_json::from_slice::<LawDetail>(&cached_data) {
-                        Ok(detail) => {
-                            info!("Successfully retrieved cached law detail");
-                            return Ok(detail);
-                        }
-                        Err(e) => {
-                            warn!(
-                                "Failed to deserialize cached detail: {}, removing from cache",
-                                e
-                            );
-                            let _ = cache.remove(&cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for detail key: {}", cache_key);
-                }
-            }
-        }
-
+    /// Fetch a law's revision history from the API and store it in cache
+    async fn fetch_history(&self, cache_key: &str, id: &str) -> Result<LawHistory> {
         let params = vec![
             ("OC", self.config.api_key.clone()),
-            ("target", "law".to_string()),
+            ("target", "lsHstry".to_string()),
             ("type", "JSON".to_string()),
             ("MST", id.to_string()),
-            ("JO_YN", "Y".to_string()), // Include articles
         ];
 
         let url = reqwest::Url::parse_with_params(BASE_URL, &params)
             .map_err(|e| WarpError::Parse(e.to_string()))?;
 
+        self.config
+            .ensure_online(format!("{} 연혁 {}", self.api_type().display_name(), id))?;
+
         let response = self.execute_with_retry(url.to_string()).await?;
 
         // Get response text for better error reporting
@@ -548,80 +492,52 @@ impl LegalApiClient for NlicClient {
             });
         }
 
-        let raw: NlicDetailResponse = serde_json::from_str(&response_text)
-            .map_err(|e| WarpError::Parse(format!("Failed to parse detail response: {}", e)))?;
+        let raw: NlicHistoryResponse = serde_json::from_str(&response_text)
+            .map_err(|e| WarpError::Parse(format!("Failed to parse history response: {}", e)))?;
 
-        // Convert NLIC response to unified format
-        let detail = raw.law.into_law_detail();
+        let history = raw.into_law_history();
 
-        // Store detail in cache
+        // Store history in cache
         if let Some(ref cache) = self.config.cache {
             if !self.config.bypass_cache {
-                debug!("Storing detail in cache for key: {}", cache_key);
-                match serde_json::to_vec(&detail) {
+                debug!("Storing history in cache for key: {}", cache_key);
+                match serde_json::to_vec(&history) {
                     Ok(serialized) => {
                         if let Err(e) = cache
-                            .put(&cache_key, serialized, self.api_type(), None)
+                            .put(cache_key, serialized, self.api_type(), None)
                             .await
                         {
-                            warn!("Failed to store detail in cache: {}", e);
+                            warn!("Failed to store history in cache: {}", e);
                         } else {
-                            info!("Successfully cached law detail");
+                            info!("Successfully cached law history");
                         }
                     }
                     Err(e) => {
-                        warn!("Failed to serialize detail for caching: {}", e);
+                        warn!("Failed to serialize history for caching: {}", e);
                     }
                 }
             }
         }
 
-        Ok(detail)
+        Ok(history)
     }
 
-    async fn get_history(&self, id: &str) -> Result<LawHistory> {
-        if self.config.api_key.is_empty() {
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for history request
-        let cache_key = format!("{}:history:{}", self.api_type().as_str(), id);
-
-        // Check cache for history response
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for history key: {}", cache_key);
-                if let Some(cached_data) = cache.get(&cache_key).await? {
-                    debug!("Cache hit for history key: {}", cache_key);
-                    match serde_json::from_slice::<LawHistory>(&cached_data) {
-                        Ok(history) => {
-                            info!("Successfully retrieved cached law history");
-                            return Ok(history);
-                        }
-                        Err(e) => {
-                            warn!(
-                                "Failed to deserialize cached history: {}, removing from cache",
-                                e
-                            );
-                            let _ = cache.remove(&cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for history key: {}", cache_key);
-                }
-            }
-        }
-
+    /// Fetch a detail from the API and store it in cache
+    async fn fetch_detail(&self, cache_key: &str, id: &str) -> Result<LawDetail> {
         let params = vec![
             ("OC", self.config.api_key.clone()),
-            ("target", "lsHstry".to_string()),
+            ("target", "law".to_string()),
             ("type", "JSON".to_string()),
             ("MST", id.to_string()),
+            ("JO_YN", "Y".to_string()), // Include articles
         ];
 
         let url = reqwest::Url::parse_with_params(BASE_URL, &params)
             .map_err(|e| WarpError::Parse(e.to_string()))?;
 
+        self.config
+            .ensure_online(format!("{} 상세 {}", self.api_type().display_name(), id))?;
+
         let response = self.execute_with_retry(url.to_string()).await?;
 
         // Get response text for better error reporting
@@ -636,34 +552,109 @@ impl LegalApiClient for NlicClient {
             });
         }
 
-        let raw: NlicHistoryResponse = serde_json::from_str(&response_text)
-            .map_err(|e| WarpError::Parse(format!("Failed to parse history response: {}", e)))?;
+        let raw: NlicDetailResponse = serde_json::from_str(&response_text)
+            .map_err(|e| WarpError::Parse(format!("Failed to parse detail response: {}", e)))?;
 
-        let history = raw.into_law_history();
+        // Convert NLIC response to unified format
+        let detail = raw.law.into_law_detail();
 
-        // Store history in cache
+        // Store detail in cache
         if let Some(ref cache) = self.config.cache {
             if !self.config.bypass_cache {
-                debug!("Storing history in cache for key: {}", cache_key);
-                match serde_json::to_vec(&history) {
+                debug!("Storing detail in cache for key: {}", cache_key);
+                match serde_json::to_vec(&detail) {
                     Ok(serialized) => {
                         if let Err(e) = cache
-                            .put(&cache_key, serialized, self.api_type(), None)
+                            .put(cache_key, serialized, self.api_type(), None)
                             .await
                         {
-                            warn!("Failed to store history in cache: {}", e);
+                            warn!("Failed to store detail in cache: {}", e);
                         } else {
-                            info!("Successfully cached law history");
+                            info!("Successfully cached law detail");
                         }
                     }
                     Err(e) => {
-                        warn!("Failed to serialize history for caching: {}", e);
+                        warn!("Failed to serialize detail for caching: {}", e);
                     }
                 }
             }
         }
 
-        Ok(history)
+        Ok(detail)
+    }
+}
+
+#[async_trait]
+impl LegalApiClient for NlicClient {
+    async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+        // Start performance monitoring
+        let timer = OperationTimer::start("nlic_search".to_string(), get_global_metrics());
+
+        if self.config.api_key.is_empty() && !self.config.offline {
+            timer.finish_failure();
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for this request
+        let cache_key = CacheKeyGenerator::search_key(self.api_type(), &request);
+
+        let item = format!(
+            "{} 검색 '{}'",
+            self.api_type().display_name(),
+            request.query
+        );
+        let client = self.clone();
+        let key = cache_key.clone();
+        let result = self
+            .config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_search(&key, request).await
+            })
+            .await;
+
+        match result {
+            Ok(_) => timer.finish_success(),
+            Err(_) => timer.finish_failure(),
+        }
+        result
+    }
+
+    async fn get_detail(&self, id: &str) -> Result<LawDetail> {
+        if self.config.api_key.is_empty() && !self.config.offline {
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for detail request
+        let cache_key = format!("{}:detail:{}", self.api_type().as_str(), id);
+
+        let item = format!("{} 상세 {}", self.api_type().display_name(), id);
+        let client = self.clone();
+        let key = cache_key.clone();
+        let id = id.to_string();
+        self.config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_detail(&key, &id).await
+            })
+            .await
+    }
+
+    async fn get_history(&self, id: &str) -> Result<LawHistory> {
+        if self.config.api_key.is_empty() && !self.config.offline {
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for history request
+        let cache_key = format!("{}:history:{}", self.api_type().as_str(), id);
+
+        let item = format!("{} 연혁 {}", self.api_type().display_name(), id);
+        let client = self.clone();
+        let key = cache_key.clone();
+        let id = id.to_string();
+        self.config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_history(&key, &id).await
+            })
+            .await
     }
 
     fn api_type(&self) -> ApiType {
@@ -803,6 +794,8 @@ impl NlicDetailContent {
             attachments: vec![],
             related_laws: vec![],
             metadata: HashMap::new(),
+            fetched_at: Some(Utc::now()),
+            stale: false,
         }
     }
 }
@@ -828,6 +821,8 @@ struct NlicHistoryEntry {
     enforcement_date: Option<String>,
     #[serde(rename = "개정이유")]
     reason: Option<String>,
+    #[serde(rename = "법령일련번호", default)]
+    serial_no: Option<String>,
 }
 
 impl NlicHistoryResponse {
@@ -841,6 +836,7 @@ impl NlicHistoryResponse {
                 revision_type: entry.revision_type,
                 reason: entry.reason,
                 changed_articles: vec![], // TODO: Parse changed articles
+                version_id: entry.serial_no,
             });
         }
 
@@ -849,6 +845,7 @@ impl NlicHistoryResponse {
             law_name: String::new(), // Will be filled from first entry
             total_count: entries.len() as u32,
             entries,
+            stale: false,
         }
     }
 }
@@ -1,6 +1,7 @@
 use super::types::*;
 use super::{ApiType, LegalApiClient};
 use crate::error::{Result, WarpError};
+use crate::search::{dedup_items, RankFusion};
 use futures::stream::{self, StreamExt};
 use std::sync::Arc;
 use std::time::Duration;
@@ -42,7 +43,25 @@ pub struct ParallelSearchResult {
 
 impl ParallelSearchResult {
     /// Merge all successful responses into a single unified response
+    ///
+    /// Items are ordered by reciprocal-rank fusion of each API's ranking and
+    /// the same document returned by several APIs (or in several revisions)
+    /// is kept once, see [`RankFusion`] and [`dedup_items`].
     pub fn merge_responses(&self) -> Option<SearchResponse> {
+        self.merge(&RankFusion::default(), true)
+    }
+
+    /// Merge all successful responses, keeping duplicate documents
+    pub fn merge_responses_with_duplicates(&self) -> Option<SearchResponse> {
+        self.merge(&RankFusion::default(), false)
+    }
+
+    /// Merge all successful responses, ranking sources with the given fusion
+    pub fn merge_responses_with_fusion(&self, fusion: &RankFusion) -> Option<SearchResponse> {
+        self.merge(fusion, true)
+    }
+
+    fn merge(&self, fusion: &RankFusion, dedup: bool) -> Option<SearchResponse> {
         if self.successes.is_empty() {
             return None;
         }
@@ -53,23 +72,38 @@ impl ParallelSearchResult {
         let mut page_size = 10u32;
 
         // Collect all items from successful responses
-        for (_api_type, response) in &self.successes {
-            merged_items.extend(response.items.iter().cloned());
+        for (api_type, response) in &self.successes {
+            merged_items.extend(response.items.iter().map(|item| (*api_type, item.clone())));
             total_count = total_count.saturating_add(response.total_count);
             page_no = response.page_no; // Use last response's page info
             page_size = response.page_size;
         }
 
-        // Sort items by relevance (you might want to implement custom scoring)
-        // For now, we'll just maintain order and add source diversity
+        // Interleave the APIs by reciprocal-rank fusion of their own orderings
+        let merged_items = fusion.fuse(merged_items);
+
+        let items = if dedup {
+            let before = merged_items.len();
+            let items = dedup_items(merged_items);
+            total_count = total_count.saturating_sub((before - items.len()) as u32);
+            items
+        } else {
+            merged_items.into_iter().map(|(_, item)| item).collect()
+        };
 
         Some(SearchResponse {
             total_count,
             page_no,
             page_size,
-            items: merged_items,
+            items,
             source: format!("Unified ({})", self.successes.len()),
             timestamp: chrono::Utc::now(),
+            stale: self.successes.iter().any(|(_, response)| response.stale),
+            cursors: self
+                .successes
+                .iter()
+                .flat_map(|(_, response)| response.cursors.iter().cloned())
+                .collect(),
         })
     }
 
@@ -117,6 +151,28 @@ impl ParallelExecutor {
         &self,
         clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
         request: UnifiedSearchRequest,
+    ) -> Result<ParallelSearchResult> {
+        self.run_searches(clients, request, None).await
+    }
+
+    /// Fetch at least `depth` results from every API in parallel
+    ///
+    /// Each API is paged through with its own cursor (see [`fetch_to_depth`]),
+    /// so merging the results yields the top `depth` items of the combined list.
+    pub async fn search_parallel_to_depth(
+        &self,
+        clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
+        request: UnifiedSearchRequest,
+        depth: u32,
+    ) -> Result<ParallelSearchResult> {
+        self.run_searches(clients, request, Some(depth)).await
+    }
+
+    async fn run_searches(
+        &self,
+        clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
+        request: UnifiedSearchRequest,
+        depth: Option<u32>,
     ) -> Result<ParallelSearchResult> {
         if clients.is_empty() {
             return Err(WarpError::InvalidInput("No clients provided".to_string()));
@@ -131,7 +187,12 @@ impl ParallelExecutor {
                 let request = request.clone();
                 async move {
                     // Add timeout to individual requests
-                    let search_future = client.search(request);
+                    let search_future = async {
+                        match depth {
+                            Some(depth) => fetch_to_depth(client.as_ref(), request, depth).await,
+                            None => client.search(request).await,
+                        }
+                    };
 
                     match tokio::time::timeout(self.config.request_timeout, search_future).await {
                         Ok(result) => (api_type, result),
@@ -254,6 +315,66 @@ impl ParallelExecutor {
     }
 }
 
+/// Page through one source until at least `depth` results are fetched
+///
+/// Pages of `request.page_size` are requested starting from page 1, stopping
+/// early once the source is exhausted. The returned response holds every
+/// fetched item together with the source's total and its [`SourceCursor`].
+/// A failure after the first page ends the walk with the items fetched so far.
+pub async fn fetch_to_depth<C>(
+    client: &C,
+    mut request: UnifiedSearchRequest,
+    depth: u32,
+) -> Result<SearchResponse>
+where
+    C: LegalApiClient + ?Sized,
+{
+    let mut cursor = SourceCursor::new(client.api_type().display_name());
+    let mut merged: Option<SearchResponse> = None;
+
+    while cursor.fetched < depth && !cursor.is_exhausted() {
+        request.page_no = cursor.next_page;
+        let page = match client.search(request.clone()).await {
+            Ok(page) => page,
+            Err(e) if merged.is_some() => {
+                log::warn!(
+                    "Stopping at page {} of {}: {}",
+                    cursor.next_page,
+                    cursor.source,
+                    e
+                );
+                break;
+            }
+            Err(e) => return Err(e),
+        };
+        cursor.advance(&page);
+
+        match merged.as_mut() {
+            Some(merged) => {
+                merged.stale |= page.stale;
+                merged.items.extend(page.items);
+            }
+            None => merged = Some(page),
+        }
+    }
+
+    let mut merged = merged.unwrap_or_else(|| SearchResponse {
+        total_count: 0,
+        page_no: 1,
+        page_size: request.page_size,
+        items: Vec::new(),
+        source: cursor.source.clone(),
+        timestamp: chrono::Utc::now(),
+        stale: false,
+        cursors: Vec::new(),
+    });
+    merged.total_count = cursor.total_count;
+    merged.page_no = 1;
+    merged.page_size = request.page_size;
+    merged.cursors = vec![cursor];
+    Ok(merged)
+}
+
 /// Helper function to create parallel search with default configuration
 pub async fn search_all_apis(
     clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
@@ -324,6 +445,8 @@ mod tests {
                     items: vec![],
                     source: "Mock".to_string(),
                     timestamp: chrono::Utc::now(),
+                    stale: false,
+                    cursors: Vec::new(),
                 })
             }
         }
@@ -401,4 +524,143 @@ mod tests {
         assert_eq!(result.successes.len(), 1);
         assert_eq!(result.failures.len(), 1);
     }
+
+    #[test]
+    fn test_merge_responses_dedups_across_apis() {
+        let item = |title: &str, case_no: &str, source: &str| SearchItem {
+            id: case_no.to_string(),
+            title: title.to_string(),
+            law_no: Some(case_no.to_string()),
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: source.to_string(),
+            metadata: std::collections::HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        };
+        let response = |items: Vec<SearchItem>| SearchResponse {
+            total_count: items.len() as u32,
+            page_no: 1,
+            page_size: 10,
+            items,
+            source: "Mock".to_string(),
+            timestamp: chrono::Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+
+        let result = ParallelSearchResult {
+            successes: vec![
+                (
+                    ApiType::Prec,
+                    response(vec![
+                        item("손해배상", "2019다1", "PREC"),
+                        item("사기", "2019도2", "PREC"),
+                    ]),
+                ),
+                (
+                    ApiType::Expc,
+                    response(vec![item("손해배상", "2019다1", "EXPC")]),
+                ),
+            ],
+            failures: vec![],
+            execution_time: Duration::ZERO,
+        };
+
+        let merged = result.merge_responses().unwrap();
+        assert_eq!(merged.items.len(), 2);
+        assert_eq!(merged.total_count, 2);
+        assert_eq!(merged.items[0].sources, vec!["PREC", "EXPC"]);
+
+        let merged = result.merge_responses_with_duplicates().unwrap();
+        assert_eq!(merged.items.len(), 3);
+    }
+
+    /// Client serving `total` numbered items in pages
+    struct PagedClient {
+        total: u32,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl LegalApiClient for PagedClient {
+        async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            let start = (request.page_no - 1) * request.page_size;
+            let end = (start + request.page_size).min(self.total);
+            Ok(SearchResponse {
+                total_count: self.total,
+                page_no: request.page_no,
+                page_size: request.page_size,
+                items: (start..end)
+                    .map(|i| SearchItem {
+                        id: i.to_string(),
+                        title: format!("법령 {}", i),
+                        law_no: None,
+                        law_type: None,
+                        department: None,
+                        enforcement_date: None,
+                        revision_date: None,
+                        summary: None,
+                        source: "Mock".to_string(),
+                        metadata: std::collections::HashMap::new(),
+                        score: None,
+                        sources: Vec::new(),
+                    })
+                    .collect(),
+                source: "Mock".to_string(),
+                timestamp: chrono::Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
+            })
+        }
+
+        async fn get_detail(&self, _id: &str) -> Result<LawDetail> {
+            unimplemented!("Mock detail not implemented")
+        }
+
+        async fn get_history(&self, _id: &str) -> Result<LawHistory> {
+            unimplemented!("Mock history not implemented")
+        }
+
+        fn api_type(&self) -> ApiType {
+            ApiType::Prec
+        }
+        fn base_url(&self) -> &str {
+            "http://mock"
+        }
+        fn is_configured(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_to_depth_pages_until_depth_or_exhausted() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = PagedClient {
+            total: 25,
+            calls: calls.clone(),
+        };
+        let request = UnifiedSearchRequest {
+            query: "test".to_string(),
+            page_size: 10,
+            ..Default::default()
+        };
+
+        let response = fetch_to_depth(&client, request.clone(), 15).await.unwrap();
+        assert_eq!(response.items.len(), 20);
+        assert_eq!(response.total_count, 25);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        let cursor = &response.cursors[0];
+        assert_eq!((cursor.fetched, cursor.next_page), (20, 3));
+        assert!(!cursor.is_exhausted());
+
+        let response = fetch_to_depth(&client, request, 100).await.unwrap();
+        assert_eq!(response.items.len(), 25);
+        assert!(response.cursors[0].is_exhausted());
+        assert_eq!(calls.load(Ordering::Relaxed), 5);
+    }
 }
@@ -11,13 +11,14 @@ use super::client::ClientConfig;
 use super::deserializers::{single_or_vec, single_or_vec_or_null};
 use super::types::{LawDetail, LawHistory, SearchItem, SearchResponse, UnifiedSearchRequest};
 use super::{ApiType, LegalApiClient};
-use crate::cache::key::{CacheKeyGenerator, PrecKeyParams};
+use crate::cache::key::CacheKeyGenerator;
 use crate::error::{Result, WarpError};
 
 const BASE_URL: &str = "https://www.law.go.kr/DRF/lawSearch.do";
 const DETAIL_URL: &str = "https://www.law.go.kr/DRF/lawService.do";
 
 /// PREC (판례) API Client
+#[derive(Clone)]
 pub struct PrecClient {
     config: ClientConfig,
     client: Client,
@@ -33,31 +34,6 @@ impl PrecClient {
         Self { config, client }
     }
 
-    /// Check cache for cached search response
-    async fn check_search_cache(&self, cache_key: &str) -> Result<Option<SearchResponse>> {
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for PREC search key: {}", cache_key);
-                if let Some(cached_data) = cache.get(cache_key).await? {
-                    debug!("Cache hit for PREC search key: {}", cache_key);
-                    match serde_json::from_slice::<SearchResponse>(&cached_data) {
-                        Ok(response) => {
-                            info!("Successfully retrieved cached PREC search response");
-                            return Ok(Some(response));
-                        }
-                        Err(e) => {
-                            warn!("Failed to deserialize cached PREC search response: {}, removing from cache", e);
-                            let _ = cache.remove(cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for PREC search key: {}", cache_key);
-                }
-            }
-        }
-        Ok(None)
-    }
-
     /// Store search response in cache
     async fn store_search_in_cache(
         &self,
@@ -189,6 +165,8 @@ impl PrecClient {
                     summary: case.case_summary,
                     source: "PREC".to_string(),
                     metadata,
+                    score: None,
+                    sources: Vec::new(),
                 }
             })
             .collect();
@@ -200,34 +178,17 @@ impl PrecClient {
             items,
             source: "PREC".to_string(),
             timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
         }
     }
-}
-
-#[async_trait]
-impl LegalApiClient for PrecClient {
-    async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
-        if self.config.api_key.is_empty() {
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for this PREC search request
-        let cache_key = CacheKeyGenerator::prec_key(PrecKeyParams {
-            endpoint: "search",
-            query: Some(&request.query),
-            court: request.extras.get("court").map(|s| s.as_str()),
-            case_type: request.extras.get("case_type").map(|s| s.as_str()),
-            date_from: request.date_from.as_deref(),
-            date_to: request.date_to.as_deref(),
-            page: Some(request.page_no),
-            size: Some(request.page_size),
-        });
-
-        // Check cache first
-        if let Some(cached_response) = self.check_search_cache(&cache_key).await? {
-            return Ok(cached_response);
-        }
 
+    /// Fetch search results from the API and store them in cache
+    async fn fetch_search(
+        &self,
+        cache_key: &str,
+        request: UnifiedSearchRequest,
+    ) -> Result<SearchResponse> {
         // Calculate the starting position (offset) for the API
         let offset = ((request.page_no - 1) * request.page_size) + 1;
 
@@ -238,6 +199,7 @@ impl LegalApiClient for PrecClient {
             ("query", request.query.clone()),
             ("page", offset.to_string()), // Use offset instead of page number
             ("display", request.page_size.to_string()),
+            ("search", request.search_scope.drf_code().to_string()),
         ];
 
         // Add optional parameters for precedent search
@@ -247,11 +209,12 @@ impl LegalApiClient for PrecClient {
         if let Some(case_type) = request.extras.get("case_type") {
             params.push(("caseType", case_type.clone()));
         }
-        if let Some(date_from) = &request.date_from {
-            params.push(("fromDate", date_from.clone()));
+        // Precedents have no in-force status, so the `status` extra is ignored
+        if let Some(date_range) = request.drf_date_range() {
+            params.push(("prncYd", date_range)); // 선고일자 range
         }
-        if let Some(date_to) = &request.date_to {
-            params.push(("toDate", date_to.clone()));
+        if let Some(sort) = request.sort.and_then(|s| s.drf_code()) {
+            params.push(("sort", sort.to_string()));
         }
 
         let url = reqwest::Url::parse_with_params(BASE_URL, &params)
@@ -310,46 +273,15 @@ impl LegalApiClient for PrecClient {
         let response = self.parse_search_response(raw, request.page_no);
 
         // Store in cache
-        if let Err(e) = self.store_search_in_cache(&cache_key, &response).await {
+        if let Err(e) = self.store_search_in_cache(cache_key, &response).await {
             warn!("Failed to cache PREC search response: {}", e);
         }
 
         Ok(response)
     }
 
-    async fn get_detail(&self, id: &str) -> Result<LawDetail> {
-        if self.config.api_key.is_empty() {
-            return Err(WarpError::NoApiKey);
-        }
-
-        // Generate cache key for detail request
-        let cache_key = format!("{}:detail:{}", self.api_type().as_str(), id);
-
-        // Check cache for detail response
-        if let Some(ref cache) = self.config.cache {
-            if !self.config.bypass_cache {
-                debug!("Checking cache for PREC detail key: {}", cache_key);
-                if let Some(cached_data) = cache.get(&cache_key).await? {
-                    debug!("Cache hit for PREC detail key: {}", cache_key);
-                    match serde_json::from_slice::<LawDetail>(&cached_data) {
-                        Ok(detail) => {
-                            info!("Successfully retrieved cached PREC law detail");
-                            return Ok(detail);
-                        }
-                        Err(e) => {
-                            warn!(
-                                "Failed to deserialize cached PREC detail: {}, removing from cache",
-                                e
-                            );
-                            let _ = cache.remove(&cache_key).await;
-                        }
-                    }
-                } else {
-                    debug!("Cache miss for PREC detail key: {}", cache_key);
-                }
-            }
-        }
-
+    /// Fetch a detail from the API and store it in cache
+    async fn fetch_detail(&self, cache_key: &str, id: &str) -> Result<LawDetail> {
         let params = vec![
             ("OC", self.config.api_key.clone()),
             ("target", "prec".to_string()),
@@ -360,6 +292,9 @@ impl LegalApiClient for PrecClient {
         let url = reqwest::Url::parse_with_params(DETAIL_URL, &params)
             .map_err(|e| WarpError::Parse(e.to_string()))?;
 
+        self.config
+            .ensure_online(format!("{} 상세 {}", self.api_type().display_name(), id))?;
+
         let response = self.execute_with_retry(url.to_string()).await?;
         let response_text = response.text().await.map_err(WarpError::Network)?;
 
@@ -384,7 +319,7 @@ impl LegalApiClient for PrecClient {
                 match serde_json::to_vec(&detail) {
                     Ok(serialized) => {
                         if let Err(e) = cache
-                            .put(&cache_key, serialized, self.api_type(), None)
+                            .put(cache_key, serialized, self.api_type(), None)
                             .await
                         {
                             warn!("Failed to store PREC detail in cache: {}", e);
@@ -401,6 +336,50 @@ impl LegalApiClient for PrecClient {
 
         Ok(detail)
     }
+}
+
+#[async_trait]
+impl LegalApiClient for PrecClient {
+    async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+        if self.config.api_key.is_empty() && !self.config.offline {
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for this PREC search request
+        let cache_key = CacheKeyGenerator::search_key(self.api_type(), &request);
+
+        let item = format!(
+            "{} 검색 '{}'",
+            self.api_type().display_name(),
+            request.query
+        );
+        let client = self.clone();
+        let key = cache_key.clone();
+        self.config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_search(&key, request).await
+            })
+            .await
+    }
+
+    async fn get_detail(&self, id: &str) -> Result<LawDetail> {
+        if self.config.api_key.is_empty() && !self.config.offline {
+            return Err(WarpError::NoApiKey);
+        }
+
+        // Generate cache key for detail request
+        let cache_key = format!("{}:detail:{}", self.api_type().as_str(), id);
+
+        let item = format!("{} 상세 {}", self.api_type().display_name(), id);
+        let client = self.clone();
+        let key = cache_key.clone();
+        let id = id.to_string();
+        self.config
+            .cached_or_fetch(self.api_type(), &cache_key, item, async move {
+                client.fetch_detail(&key, &id).await
+            })
+            .await
+    }
 
     async fn get_history(&self, _id: &str) -> Result<LawHistory> {
         // Precedents don't have history in the same way laws do
@@ -410,6 +389,7 @@ impl LegalApiClient for PrecClient {
             law_name: String::new(),
             total_count: 0,
             entries: vec![],
+            stale: false,
         })
     }
 
@@ -560,6 +540,8 @@ impl PrecDetailResponse {
             attachments: vec![],
             related_laws: vec![],
             metadata,
+            fetched_at: Some(Utc::now()),
+            stale: false,
         }
     }
 }
@@ -65,6 +65,14 @@ impl SearchResultStream {
         }
     }
 
+    /// Resume from `page` (1-based), as if the earlier pages were fetched
+    pub fn starting_at(mut self, page: u32) -> Self {
+        let page = page.max(1);
+        self.current_page = page;
+        self.items_fetched = (page - 1).saturating_mul(self.config.page_size);
+        self
+    }
+
     /// Convert to a stream of individual SearchItem
     pub fn into_item_stream(self) -> impl Stream<Item = Result<SearchItem>> {
         stream::unfold(self, |mut state| async move {
@@ -102,10 +110,7 @@ impl SearchResultStream {
 
                     Some((stream::iter(items), state))
                 }
-                Ok(None) => {
-                    state.finished = true;
-                    None
-                }
+                Ok(None) => None,
                 Err(e) => {
                     state.finished = true;
                     Some((stream::iter(vec![Err(e)]), state))
@@ -147,10 +152,7 @@ impl SearchResultStream {
 
                     Some((Ok(response), state))
                 }
-                Ok(None) => {
-                    state.finished = true;
-                    None
-                }
+                Ok(None) => None,
                 Err(e) => {
                     state.finished = true;
                     Some((Err(e), state))
@@ -223,6 +225,9 @@ pub struct ParallelSearchStream {
 
 impl ParallelSearchStream {
     /// Create parallel streams for multiple API clients
+    ///
+    /// Items are tagged with the display name of their source, as in merged
+    /// unified search results.
     pub fn new(
         clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
         request: UnifiedSearchRequest,
@@ -230,10 +235,15 @@ impl ParallelSearchStream {
     ) -> Self {
         let streams = clients
             .into_iter()
-            .map(|(_api_type, client)| {
+            .map(|(api_type, client)| {
                 let stream = SearchResultStream::new(client, request.clone(), config.clone());
-                Box::pin(stream.into_item_stream())
-                    as Pin<Box<dyn Stream<Item = Result<SearchItem>> + Send>>
+                let items = stream.into_item_stream().map(move |item| {
+                    item.map(|mut item| {
+                        item.source = api_type.display_name().to_string();
+                        item
+                    })
+                });
+                Box::pin(items) as Pin<Box<dyn Stream<Item = Result<SearchItem>> + Send>>
             })
             .collect();
 
@@ -345,6 +355,8 @@ mod tests {
                     summary: None,
                     source: "MockAPI".to_string(),
                     metadata: std::collections::HashMap::new(),
+                    score: None,
+                    sources: Vec::new(),
                 })
                 .collect();
 
@@ -355,6 +367,8 @@ mod tests {
                 items,
                 source: "MockAPI".to_string(),
                 timestamp: chrono::Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
             })
         }
 
@@ -402,6 +416,27 @@ mod tests {
         assert_eq!(client.call_count.load(Ordering::Relaxed), 2);
     }
 
+    #[tokio::test]
+    async fn test_search_result_stream_resumes_at_page() {
+        let client = Arc::new(MockStreamClient::new(250, 50, Duration::ZERO));
+        let config = StreamConfig {
+            page_size: 50,
+            page_delay: Duration::ZERO,
+            ..StreamConfig::default()
+        };
+
+        let stream = SearchResultStream::new(client.clone(), Default::default(), config);
+        let pages: Vec<Result<SearchResponse>> =
+            stream.starting_at(4).into_page_stream().collect().await;
+
+        let pages: Vec<u32> = pages
+            .into_iter()
+            .map(|page| page.unwrap().page_no)
+            .collect();
+        assert_eq!(pages, vec![4, 5]);
+        assert_eq!(client.call_count.load(Ordering::Relaxed), 2);
+    }
+
     #[tokio::test]
     async fn test_parallel_search_stream() {
         let clients = vec![
@@ -434,6 +469,10 @@ mod tests {
 
         assert_eq!(items.len(), 100); // 50 items from each API
         assert!(items.iter().all(|item| item.is_ok()));
+        assert!(items
+            .iter()
+            .flatten()
+            .any(|item| item.source == "자치법규정보시스템"));
     }
 
     #[tokio::test]
@@ -1,6 +1,13 @@
 use chrono::{DateTime, Utc};
+use log::warn;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Lower bound used when only `date_to` is given for a DRF date range
+const DRF_MIN_DATE: &str = "19000101";
+/// Upper bound used when only `date_from` is given for a DRF date range
+const DRF_MAX_DATE: &str = "99991231";
 
 /// Unified search request for all API types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +32,9 @@ pub struct UnifiedSearchRequest {
     pub date_to: Option<String>,
     /// Sort order
     pub sort: Option<SortOrder>,
+    /// Part of the document the query is matched against
+    #[serde(default)]
+    pub search_scope: SearchScope,
     /// API-specific extra parameters
     pub extras: HashMap<String, String>,
 }
@@ -42,11 +52,51 @@ impl Default for UnifiedSearchRequest {
             date_from: None,
             date_to: None,
             sort: None,
+            search_scope: SearchScope::default(),
             extras: HashMap::new(),
         }
     }
 }
 
+impl UnifiedSearchRequest {
+    /// Date range in the DRF `YYYYMMDD~YYYYMMDD` form
+    ///
+    /// Open-ended ranges are closed with a far past/future date so that
+    /// `--from` or `--to` alone still restrict results on the server.
+    pub fn drf_date_range(&self) -> Option<String> {
+        match (self.date_from.as_deref(), self.date_to.as_deref()) {
+            (None, None) => None,
+            (from, to) => Some(format!(
+                "{}~{}",
+                from.unwrap_or(DRF_MIN_DATE),
+                to.unwrap_or(DRF_MAX_DATE)
+            )),
+        }
+    }
+
+    /// Status filters parsed from the comma-separated `status` extra
+    ///
+    /// Unknown values are skipped with a warning rather than failing the search.
+    pub fn status_filters(&self) -> Vec<LawStatus> {
+        let Some(status) = self.extras.get("status") else {
+            return Vec::new();
+        };
+
+        status
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .filter_map(|s| match s.parse::<LawStatus>() {
+                Ok(status) => Some(status),
+                Err(e) => {
+                    warn!("{}", e);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum ResponseType {
     Json,
@@ -67,6 +117,141 @@ pub enum SortOrder {
     TitleDesc,
 }
 
+impl SortOrder {
+    /// Value of the DRF `sort` parameter for this order
+    ///
+    /// Returns `None` for relevance, which is the server's default ordering.
+    pub fn drf_code(&self) -> Option<&'static str> {
+        match self {
+            Self::Relevance => None,
+            Self::DateAsc => Some("dasc"),
+            Self::DateDesc => Some("ddes"),
+            Self::TitleAsc => Some("lasc"),
+            Self::TitleDesc => Some("ldes"),
+        }
+    }
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "relevance" => Ok(Self::Relevance),
+            "date_asc" => Ok(Self::DateAsc),
+            "date_desc" => Ok(Self::DateDesc),
+            "title_asc" => Ok(Self::TitleAsc),
+            "title_desc" => Ok(Self::TitleDesc),
+            _ => Err(format!(
+                "Unknown sort order: {} (expected relevance, date_asc, date_desc, title_asc or title_desc)",
+                s
+            )),
+        }
+    }
+}
+
+/// Part of a document a search query is matched against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchScope {
+    /// Document title only (법령명/사건명/안건명), the DRF default
+    #[default]
+    Title,
+    /// Full document text, title included (본문검색)
+    Body,
+}
+
+impl FromStr for SearchScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "title" | "제목" => Ok(Self::Title),
+            "body" | "본문" => Ok(Self::Body),
+            _ => Err(format!(
+                "Unknown search scope: {} (expected title or body)",
+                s
+            )),
+        }
+    }
+}
+
+impl SearchScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Body => "body",
+        }
+    }
+
+    /// Value of the DRF `search` parameter for this scope
+    pub fn drf_code(&self) -> &'static str {
+        match self {
+            Self::Title => "1",
+            Self::Body => "2",
+        }
+    }
+}
+
+/// Document status filter (시행중, 폐지, 일부개정, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LawStatus {
+    /// Currently in force (시행중/현행)
+    InForce,
+    /// Promulgated but not yet in force (시행예정)
+    Scheduled,
+    /// Past versions (연혁)
+    Historical,
+    /// Newly enacted (제정)
+    Enacted,
+    /// Partially amended (일부개정)
+    PartialAmendment,
+    /// Wholly amended (전부개정)
+    FullAmendment,
+    /// Repealed (폐지)
+    Repealed,
+}
+
+impl FromStr for LawStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "시행중" | "현행" | "in_force" | "current" => Ok(Self::InForce),
+            "시행예정" | "scheduled" => Ok(Self::Scheduled),
+            "연혁" | "history" | "historical" => Ok(Self::Historical),
+            "제정" | "enacted" => Ok(Self::Enacted),
+            "일부개정" | "partial_amendment" => Ok(Self::PartialAmendment),
+            "전부개정" | "full_amendment" => Ok(Self::FullAmendment),
+            "폐지" | "repealed" => Ok(Self::Repealed),
+            _ => Err(format!("Unknown status filter: {}", s)),
+        }
+    }
+}
+
+impl LawStatus {
+    /// DRF `nw` value for the current/scheduled/history split of `eflaw` searches
+    pub fn eflaw_nw_code(&self) -> Option<&'static str> {
+        match self {
+            Self::InForce => Some("3"),
+            Self::Scheduled => Some("2"),
+            Self::Historical => Some("1"),
+            _ => None,
+        }
+    }
+
+    /// DRF `rrClsCd` (제개정구분) code for revision-kind filters
+    pub fn revision_code(&self) -> Option<&'static str> {
+        match self {
+            Self::Enacted => Some("300201"),
+            Self::PartialAmendment => Some("300202"),
+            Self::FullAmendment => Some("300203"),
+            Self::Repealed => Some("300204"),
+            _ => None,
+        }
+    }
+}
+
 /// Unified search response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResponse {
@@ -82,6 +267,55 @@ pub struct SearchResponse {
     pub source: String,
     /// Response timestamp
     pub timestamp: DateTime<Utc>,
+    /// Served from an expired cache entry
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stale: bool,
+    /// Per-source progress when results of several sources were merged
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cursors: Vec<SourceCursor>,
+}
+
+/// Position of one source within a merged multi-source result list
+///
+/// Sources are paged independently; the cursor records how far into a
+/// source's own result list we have read to fill the combined pages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceCursor {
+    /// Source API name
+    pub source: String,
+    /// Total number of results reported by the source
+    pub total_count: u32,
+    /// Number of results fetched from the source so far
+    pub fetched: u32,
+    /// Next page to request from the source
+    pub next_page: u32,
+}
+
+impl SourceCursor {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            total_count: 0,
+            fetched: 0,
+            next_page: 1,
+        }
+    }
+
+    /// Whether every result of the source has been fetched
+    pub fn is_exhausted(&self) -> bool {
+        self.next_page > 1 && self.fetched >= self.total_count
+    }
+
+    /// Record a fetched page of the source
+    pub fn advance(&mut self, page: &SearchResponse) {
+        self.total_count = page.total_count;
+        self.fetched += page.items.len() as u32;
+        self.next_page += 1;
+        // An empty page means the source has nothing more, whatever it reported
+        if page.items.is_empty() {
+            self.total_count = self.fetched;
+        }
+    }
 }
 
 /// Individual search result item
@@ -107,6 +341,12 @@ pub struct SearchItem {
     pub source: String,
     /// Additional metadata
     pub metadata: HashMap<String, String>,
+    /// Relevance score (0.0-1.0) relative to the best result in the set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+    /// Every source that returned this item when duplicates were merged
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
 }
 
 /// Law detail information
@@ -136,6 +376,12 @@ pub struct LawDetail {
     pub related_laws: Vec<RelatedLaw>,
     /// Metadata
     pub metadata: HashMap<String, String>,
+    /// When the detail was fetched from the API (kept when served from cache)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetched_at: Option<DateTime<Utc>>,
+    /// Served from an expired cache entry
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stale: bool,
 }
 
 /// Law article
@@ -201,6 +447,9 @@ pub struct LawHistory {
     pub total_count: u32,
     /// History entries
     pub entries: Vec<HistoryEntry>,
+    /// Served from an expired cache entry
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stale: bool,
 }
 
 /// History entry
@@ -218,4 +467,7 @@ pub struct HistoryEntry {
     pub reason: Option<String>,
     /// Changed articles
     pub changed_articles: Vec<String>,
+    /// ID that fetches the text of this revision with `get_detail`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
 }
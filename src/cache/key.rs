@@ -1,3 +1,4 @@
+use crate::api::types::UnifiedSearchRequest;
 use crate::api::ApiType;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
@@ -218,6 +219,45 @@ impl CacheKeyGenerator {
         Self::generate_key(ApiType::Expc, endpoint, &params, None)
     }
 
+    /// Generate key for a search request covering every server-side filter
+    ///
+    /// Search scope, date range, sort order and status change what the API
+    /// returns, so they must be part of the key alongside the query and pagination.
+    pub fn search_key(api_type: ApiType, request: &UnifiedSearchRequest) -> String {
+        let mut params = HashMap::new();
+        params.insert("query".to_string(), request.query.clone());
+        params.insert("page".to_string(), request.page_no.to_string());
+        params.insert("size".to_string(), request.page_size.to_string());
+        params.insert(
+            "scope".to_string(),
+            request.search_scope.as_str().to_string(),
+        );
+
+        if let Some(ref region) = request.region {
+            params.insert("region".to_string(), region.clone());
+        }
+        if let Some(ref law_type) = request.law_type {
+            params.insert("law_type".to_string(), law_type.clone());
+        }
+        if let Some(ref department) = request.department {
+            params.insert("department".to_string(), department.clone());
+        }
+        if let Some(ref date_from) = request.date_from {
+            params.insert("date_from".to_string(), date_from.clone());
+        }
+        if let Some(ref date_to) = request.date_to {
+            params.insert("date_to".to_string(), date_to.clone());
+        }
+        if let Some(code) = request.sort.and_then(|s| s.drf_code()) {
+            params.insert("sort".to_string(), code.to_string());
+        }
+        for (key, value) in &request.extras {
+            params.insert(format!("extra.{}", key), value.clone());
+        }
+
+        Self::generate_key(api_type, "search", &params, None)
+    }
+
     /// Generate key for unified search across multiple APIs
     pub fn unified_search_key(
         query: &str,
@@ -369,6 +409,58 @@ mod tests {
         assert!(key1.starts_with("nlic:"));
     }
 
+    #[test]
+    fn test_search_key_includes_filters() {
+        use crate::api::types::{SearchScope, SortOrder};
+
+        let base = UnifiedSearchRequest {
+            query: "민법".to_string(),
+            ..Default::default()
+        };
+        let dated = UnifiedSearchRequest {
+            date_to: Some("20201231".to_string()),
+            ..base.clone()
+        };
+        let sorted = UnifiedSearchRequest {
+            sort: Some(SortOrder::DateDesc),
+            ..base.clone()
+        };
+        let body = UnifiedSearchRequest {
+            search_scope: SearchScope::Body,
+            ..base.clone()
+        };
+        let mut with_status = base.clone();
+        with_status
+            .extras
+            .insert("status".to_string(), "폐지".to_string());
+
+        let base_key = CacheKeyGenerator::search_key(ApiType::Nlic, &base);
+        assert_eq!(
+            base_key,
+            CacheKeyGenerator::search_key(ApiType::Nlic, &base)
+        );
+        assert_ne!(
+            base_key,
+            CacheKeyGenerator::search_key(ApiType::Nlic, &dated)
+        );
+        assert_ne!(
+            base_key,
+            CacheKeyGenerator::search_key(ApiType::Nlic, &sorted)
+        );
+        assert_ne!(
+            base_key,
+            CacheKeyGenerator::search_key(ApiType::Nlic, &body)
+        );
+        assert_ne!(
+            base_key,
+            CacheKeyGenerator::search_key(ApiType::Nlic, &with_status)
+        );
+        assert_ne!(
+            base_key,
+            CacheKeyGenerator::search_key(ApiType::Prec, &base)
+        );
+    }
+
     #[test]
     fn test_unified_search_key() {
         let apis = vec![ApiType::Nlic, ApiType::Elis, ApiType::Prec];
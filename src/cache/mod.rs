@@ -1,9 +1,13 @@
 use crate::api::ApiType;
 use crate::error::Result;
 use chrono::{DateTime, Duration, Utc};
+use log::debug;
+use once_cell::sync::Lazy;
+use std::future::Future;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 
 pub mod key;
 pub mod storage;
@@ -21,6 +25,9 @@ pub struct CacheConfig {
     pub default_ttl: Duration,
     /// Database file path
     pub db_path: PathBuf,
+    /// How long after expiry an entry is still served while it is refreshed
+    /// in the background (default: 7 days)
+    pub stale_window: Duration,
 }
 
 impl Default for CacheConfig {
@@ -32,6 +39,7 @@ impl Default for CacheConfig {
                 .unwrap_or_else(|| PathBuf::from("."))
                 .join("warp")
                 .join("cache.db"),
+            stale_window: Duration::days(7),
         }
     }
 }
@@ -59,6 +67,55 @@ impl CacheEntry {
     }
 }
 
+/// Outcome of a cache lookup that keeps expired entries around
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheLookup {
+    /// Entry has not expired yet
+    Fresh(Vec<u8>),
+    /// Entry expired but is still within the stale window
+    Stale(Vec<u8>),
+    /// Entry expired before the stale window; only usable as a fallback
+    Expired(Vec<u8>),
+    /// No entry for the key
+    Miss,
+}
+
+/// Background cache refreshes started while serving stale entries
+static PENDING_REFRESHES: Lazy<Mutex<Vec<JoinHandle<()>>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Run a cache refresh in the background
+///
+/// Refreshes are tracked so a short-lived process can wait for them with
+/// [`wait_for_refreshes`] before exiting.
+pub fn spawn_refresh<F>(refresh: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let handle = tokio::spawn(refresh);
+    let mut pending = PENDING_REFRESHES.lock().unwrap_or_else(|e| e.into_inner());
+    pending.retain(|h| !h.is_finished());
+    pending.push(handle);
+}
+
+/// Wait up to `timeout` for background refreshes to finish
+pub async fn wait_for_refreshes(timeout: std::time::Duration) {
+    let handles: Vec<_> = {
+        let mut pending = PENDING_REFRESHES.lock().unwrap_or_else(|e| e.into_inner());
+        pending.drain(..).collect()
+    };
+    if handles.is_empty() {
+        return;
+    }
+
+    debug!("Waiting for {} background cache refreshes", handles.len());
+    if tokio::time::timeout(timeout, futures::future::join_all(handles))
+        .await
+        .is_err()
+    {
+        debug!("Background cache refreshes did not finish in time");
+    }
+}
+
 /// Main cache store for API responses
 #[derive(Debug)]
 pub struct CacheStore {
@@ -102,6 +159,26 @@ impl CacheStore {
         }
     }
 
+    /// Look up an entry without discarding expired data
+    pub async fn lookup(&self, key: &str) -> Result<CacheLookup> {
+        let storage = self.storage.read().await;
+
+        Ok(match storage.get(key).await? {
+            Some(entry) if entry.is_valid() => CacheLookup::Fresh(entry.data),
+            Some(entry) if Utc::now() <= entry.expires_at + self.config.stale_window => {
+                CacheLookup::Stale(entry.data)
+            }
+            Some(entry) => CacheLookup::Expired(entry.data),
+            None => CacheLookup::Miss,
+        })
+    }
+
+    /// Get cached entry by key without checking or removing expired entries
+    pub async fn get_entry(&self, key: &str) -> Result<Option<CacheEntry>> {
+        let storage = self.storage.read().await;
+        storage.get(key).await
+    }
+
     /// Store data in cache
     pub async fn put(
         &self,
@@ -154,6 +231,12 @@ impl CacheStore {
         storage.clear_by_api_type(api_type).await
     }
 
+    /// Get all stored entries for specific API type, including expired ones
+    pub async fn entries_for_api(&self, api_type: ApiType) -> Result<Vec<CacheEntry>> {
+        let storage = self.storage.read().await;
+        storage.get_entries_by_api_type(api_type).await
+    }
+
     /// Get cache statistics
     pub async fn stats(&self) -> Result<CacheStats> {
         let storage = self.storage.read().await;
@@ -230,6 +313,7 @@ mod tests {
             max_size: 1024, // 1KB for testing
             default_ttl: Duration::minutes(5),
             db_path: temp_dir.path().join("test_cache.db"),
+            stale_window: Duration::days(7),
         };
 
         let cache = CacheStore::new(config).await.unwrap();
@@ -272,6 +356,52 @@ mod tests {
         assert_eq!(retrieved, None);
     }
 
+    #[tokio::test]
+    async fn test_cache_lookup_keeps_expired_entries() {
+        let (cache, _temp_dir) = create_test_cache().await;
+        let data = b"test_data".to_vec();
+
+        cache
+            .put("fresh", data.clone(), ApiType::Nlic, None)
+            .await
+            .unwrap();
+        cache
+            .put(
+                "stale",
+                data.clone(),
+                ApiType::Nlic,
+                Some(Duration::hours(-1)),
+            )
+            .await
+            .unwrap();
+        cache
+            .put(
+                "expired",
+                data.clone(),
+                ApiType::Nlic,
+                Some(Duration::days(-30)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cache.lookup("fresh").await.unwrap(),
+            CacheLookup::Fresh(data.clone())
+        );
+        assert_eq!(
+            cache.lookup("stale").await.unwrap(),
+            CacheLookup::Stale(data.clone())
+        );
+        assert_eq!(
+            cache.lookup("expired").await.unwrap(),
+            CacheLookup::Expired(data.clone())
+        );
+        assert_eq!(cache.lookup("missing").await.unwrap(), CacheLookup::Miss);
+
+        // Lookups never discard entries
+        assert!(cache.get_entry("expired").await.unwrap().is_some());
+    }
+
     #[tokio::test]
     async fn test_cache_permissions() {
         let temp_dir = TempDir::new().unwrap();
@@ -279,6 +409,7 @@ mod tests {
             max_size: 1024,
             default_ttl: Duration::minutes(5),
             db_path: temp_dir.path().join("test_permissions.db"),
+            stale_window: Duration::days(7),
         };
 
         let _cache = CacheStore::new(config.clone()).await.unwrap();
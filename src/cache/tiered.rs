@@ -487,6 +487,7 @@ mod tests {
                 max_size: 1024 * 1024, // 1MB
                 default_ttl: Duration::hours(1),
                 db_path: temp_dir.path().join("test_tiered.db"),
+                stale_window: Duration::days(7),
             },
             enable_l3: false,
             l3_dir: temp_dir.path().join("l3"),
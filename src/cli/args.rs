@@ -1,4 +1,25 @@
+use crate::api::types::SearchScope;
 use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+/// Options for paging through every result of a search
+#[derive(Args, Debug, Clone, Default)]
+pub struct PagingArgs {
+    /// Fetch every page of results instead of a single page
+    #[arg(long)]
+    pub all: bool,
+
+    /// Fetch pages until N results have been returned
+    #[arg(long, value_name = "N")]
+    pub limit: Option<u32>,
+}
+
+impl PagingArgs {
+    /// Whether results should be streamed page by page
+    pub fn is_enabled(&self) -> bool {
+        self.all || self.limit.is_some()
+    }
+}
 
 /// Law command arguments
 #[derive(Args, Debug)]
@@ -17,6 +38,13 @@ pub struct LawArgs {
     #[arg(short = 's', long, default_value = "50")]
     pub size: u32,
 
+    /// Match the query against the title, or the full text including the title
+    #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+    pub scope: SearchScope,
+
+    #[command(flatten)]
+    pub paging: PagingArgs,
+
     /// Law type filter
     #[arg(short = 't', long)]
     pub law_type: Option<String>,
@@ -40,6 +68,13 @@ pub enum LawCommand {
         /// Results per page
         #[arg(short = 's', long, default_value = "50")]
         size: u32,
+
+        /// Match the query against the title, or the full text including the title
+        #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+        scope: SearchScope,
+
+        #[command(flatten)]
+        paging: PagingArgs,
     },
 
     /// Get law details
@@ -53,6 +88,24 @@ pub enum LawCommand {
         /// Law ID
         id: String,
     },
+
+    /// Compare two versions of a law article by article
+    Diff {
+        /// Law ID
+        id: String,
+
+        /// Older version: a date (YYYYMMDD, the last revision promulgated by then), a revision number or latest
+        #[arg(long)]
+        from: String,
+
+        /// Newer version, in the same forms as --from
+        #[arg(long, default_value = "latest")]
+        to: String,
+
+        /// Print a unified diff of the full text instead
+        #[arg(short = 'u', long)]
+        unified: bool,
+    },
 }
 
 /// Ordinance command arguments
@@ -72,6 +125,13 @@ pub struct OrdinanceArgs {
     #[arg(short = 's', long, default_value = "50")]
     pub size: u32,
 
+    /// Match the query against the title, or the full text including the title
+    #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+    pub scope: SearchScope,
+
+    #[command(flatten)]
+    pub paging: PagingArgs,
+
     /// Region filter
     #[arg(short = 'r', long)]
     pub region: Option<String>,
@@ -95,6 +155,13 @@ pub enum OrdinanceCommand {
         /// Results per page
         #[arg(short = 's', long, default_value = "50")]
         size: u32,
+
+        /// Match the query against the title, or the full text including the title
+        #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+        scope: SearchScope,
+
+        #[command(flatten)]
+        paging: PagingArgs,
     },
 
     /// Get ordinance details
@@ -121,6 +188,13 @@ pub struct PrecedentArgs {
     #[arg(short = 's', long, default_value = "50")]
     pub size: u32,
 
+    /// Match the query against the title, or the full text including the title
+    #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+    pub scope: SearchScope,
+
+    #[command(flatten)]
+    pub paging: PagingArgs,
+
     /// Court filter
     #[arg(short = 'c', long)]
     pub court: Option<String>,
@@ -152,6 +226,13 @@ pub enum PrecedentCommand {
         /// Results per page
         #[arg(short = 's', long, default_value = "50")]
         size: u32,
+
+        /// Match the query against the title, or the full text including the title
+        #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+        scope: SearchScope,
+
+        #[command(flatten)]
+        paging: PagingArgs,
     },
 
     /// Get precedent details
@@ -174,6 +255,13 @@ pub struct AdmruleArgs {
     /// Results per page
     #[arg(short = 's', long, default_value = "50")]
     pub size: u32,
+
+    /// Match the query against the title, or the full text including the title
+    #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+    pub scope: SearchScope,
+
+    #[command(flatten)]
+    pub paging: PagingArgs,
 }
 
 /// Legal interpretation command arguments
@@ -189,6 +277,13 @@ pub struct InterpretationArgs {
     /// Results per page
     #[arg(short = 's', long, default_value = "50")]
     pub size: u32,
+
+    /// Match the query against the title, or the full text including the title
+    #[arg(long = "in", value_name = "SCOPE", default_value = "title")]
+    pub scope: SearchScope,
+
+    #[command(flatten)]
+    pub paging: PagingArgs,
 }
 
 /// Unified search command arguments
@@ -205,6 +300,14 @@ pub struct SearchArgs {
     #[arg(short = 's', long, default_value = "50")]
     pub size: u32,
 
+    /// Match the query against the title, or the full text including the title
+    #[arg(
+        long = "in",
+        value_name = "SCOPE",
+        help = "Match query against: title (default), body (full text, title included)"
+    )]
+    pub scope: Option<SearchScope>,
+
     /// Source to search (nlic, elis, all)
     #[arg(short = 'S', long, default_value = "all")]
     pub source: String,
@@ -261,12 +364,12 @@ pub struct SearchArgs {
     #[arg(long, help = "Department filter (comma-separated: 법무부,행안부)")]
     pub department: Option<String>,
 
-    /// Date range start (YYYYMMDD format)
-    #[arg(long, help = "Start date for filtering (YYYYMMDD)")]
+    /// Date range start (YYYY or YYYYMMDD)
+    #[arg(long, help = "Start date for filtering (YYYY or YYYYMMDD)")]
     pub from: Option<String>,
 
-    /// Date range end (YYYYMMDD format)
-    #[arg(long, help = "End date for filtering (YYYYMMDD)")]
+    /// Date range end (YYYY or YYYYMMDD)
+    #[arg(long, help = "End date for filtering (YYYY or YYYYMMDD)")]
     pub to: Option<String>,
 
     /// Recent days filter (alternative to from/to)
@@ -274,7 +377,10 @@ pub struct SearchArgs {
     pub recent_days: Option<u32>,
 
     /// Status filter (시행중, 폐지, 일부개정, etc.)
-    #[arg(long, help = "Status filter (시행중,폐지,일부개정)")]
+    #[arg(
+        long,
+        help = "Status filter (시행중,시행예정,연혁,제정,일부개정,전부개정,폐지)"
+    )]
     pub status: Option<String>,
 
     /// Region filter for local ordinances
@@ -301,8 +407,12 @@ pub struct SearchArgs {
     #[arg(long, help = "Enable regular expression search")]
     pub regex: bool,
 
-    /// Search only in title
-    #[arg(long, help = "Search only in document titles")]
+    /// Search only in title (shorthand for `--in title`)
+    #[arg(
+        long,
+        conflicts_with = "scope",
+        help = "Search only in document titles (same as --in title)"
+    )]
     pub title_only: bool,
 
     /// Minimum relevance score (0.0-1.0)
@@ -312,6 +422,18 @@ pub struct SearchArgs {
     /// Use saved filter preset
     #[arg(long, help = "Apply saved filter preset")]
     pub filter: Option<String>,
+
+    /// Keep duplicate documents returned by several sources or revisions
+    #[arg(long, help = "Do not merge duplicate results across sources")]
+    pub no_dedup: bool,
+
+    /// Browse the results in the full-screen interface (same as `warp tui`)
+    #[arg(long, help = "Open the results in the interactive browser")]
+    pub interactive: bool,
+
+    /// Fetch every page; results stream in arrival order without ranking
+    #[command(flatten)]
+    pub paging: PagingArgs,
 }
 
 /// Configuration command arguments
@@ -342,6 +464,162 @@ pub struct FilterArgs {
     pub command: FilterCommand,
 }
 
+/// Local full-text index arguments
+#[derive(Args, Debug)]
+pub struct IndexArgs {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+/// Watch list arguments
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    #[command(subcommand)]
+    pub command: WatchCommand,
+}
+
+/// Export arguments
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    #[command(subcommand)]
+    pub command: ExportCommand,
+}
+
+/// Interactive browser arguments
+#[derive(Args, Debug)]
+pub struct TuiArgs {
+    /// Search to run on start (press `s` to search from inside the browser)
+    pub query: Option<String>,
+
+    /// Sources to search (nlic, elis, prec, admrul, expc, all or a comma-separated list)
+    #[arg(short = 'S', long, default_value = "all")]
+    pub source: String,
+
+    /// Results fetched per source
+    #[arg(short = 's', long, default_value = "50")]
+    pub size: u32,
+}
+
+/// Interactive shell arguments
+#[derive(Args, Debug)]
+pub struct ShellArgs {
+    /// Sources searched by `search` (nlic, elis, prec, admrul, expc, all or a comma-separated list)
+    #[arg(short = 'S', long, default_value = "all")]
+    pub source: String,
+
+    /// Results fetched per source (change with `size` in the shell)
+    #[arg(short = 's', long, default_value = "20")]
+    pub size: u32,
+}
+
+/// Local API server arguments
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// Port to listen on
+    #[arg(short = 'p', long, default_value = "8080")]
+    pub port: u16,
+
+    /// Sources the server answers for (nlic, elis, prec, admrul, expc, all or a comma-separated list)
+    #[arg(short = 'S', long, default_value = "all")]
+    pub source: String,
+
+    /// Results fetched per source when a search has no `size`
+    #[arg(short = 's', long, default_value = "20")]
+    pub size: u32,
+}
+
+/// MCP server arguments
+#[derive(Args, Debug)]
+pub struct McpArgs {
+    /// Sources the tools may use (nlic, elis, prec, admrul, expc, all or a comma-separated list)
+    #[arg(short = 'S', long, default_value = "all")]
+    pub source: String,
+
+    /// Results fetched per source when a search has no `size`
+    #[arg(short = 's', long, default_value = "10")]
+    pub size: u32,
+}
+
+/// Mirror arguments
+#[derive(Args, Debug)]
+pub struct MirrorArgs {
+    /// Directory the documents are written to, one JSON file each
+    pub dir: PathBuf,
+
+    /// Source to mirror (nlic, elis, prec, admrul, expc)
+    #[arg(long, default_value = "prec")]
+    pub api: String,
+
+    /// Search query
+    #[arg(long)]
+    pub query: String,
+
+    /// Start of the date range (YYYY or YYYYMMDD)
+    #[arg(long)]
+    pub from: Option<String>,
+
+    /// End of the date range (YYYY or YYYYMMDD)
+    #[arg(long)]
+    pub to: Option<String>,
+
+    /// Results per page (1-100); cannot change when resuming
+    #[arg(long, default_value = "100")]
+    pub page_size: u32,
+
+    /// Detail requests in flight at once
+    #[arg(short = 'j', long, default_value = "4")]
+    pub concurrency: usize,
+
+    /// Pause between pages in milliseconds
+    #[arg(long, default_value = "500")]
+    pub delay: u64,
+}
+
+/// Batch query arguments
+#[derive(Args, Debug)]
+pub struct BatchArgs {
+    /// Query file: one query per line, or CSV/JSONL rows with per-row filters (`-` reads stdin)
+    pub file: PathBuf,
+
+    /// How to read the file (default: .csv and .jsonl/.ndjson by extension, otherwise lines)
+    #[arg(long, value_enum)]
+    pub input: Option<BatchInput>,
+
+    /// Sources for rows that name none (nlic, elis, prec, admrul, expc, all or a comma-separated list)
+    #[arg(short = 'S', long, default_value = "nlic")]
+    pub source: String,
+
+    /// Results per query and source, for rows that set no size
+    #[arg(short = 's', long, default_value = "10")]
+    pub size: u32,
+
+    /// Requests in flight at once
+    #[arg(short = 'j', long, default_value = "4")]
+    pub concurrency: usize,
+}
+
+/// Layout of a batch query file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum BatchInput {
+    /// One query per line; blank lines and lines starting with # are skipped
+    Text,
+    /// CSV with a header row: query, source, law_type, department, region, from, to, size
+    Csv,
+    /// One JSON object per line with the same keys as the CSV columns
+    Jsonl,
+}
+
+/// Local search arguments
+#[derive(Args, Debug)]
+pub struct LocalArgs {
+    #[command(subcommand)]
+    pub command: LocalCommand,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum CacheCommand {
     /// Show cache status and statistics
@@ -368,6 +646,83 @@ pub enum CacheCommand {
     Disable,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum WatchCommand {
+    /// Watch a law by exact name or ID, from its current revision on
+    Add {
+        /// Law name (e.g. "개인정보 보호법") or ID
+        law: String,
+
+        /// Source of the law (nlic, elis, admrul)
+        #[arg(short = 'S', long, default_value = "nlic")]
+        source: String,
+    },
+
+    /// Stop watching a law, by name or ID
+    Remove {
+        /// Law name or ID
+        law: String,
+    },
+
+    /// Show watched laws and their last seen revision
+    List,
+
+    /// Report laws revised since the last check (exit code 2 when any changed, 1 when any check failed)
+    Check,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum IndexCommand {
+    /// Index every cached law detail article by article
+    Build {
+        /// Drop the existing index before building
+        #[arg(long)]
+        rebuild: bool,
+    },
+
+    /// Show index statistics
+    Status,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ExportCommand {
+    /// Write laws as a linked Markdown vault (e.g. for Obsidian)
+    Vault {
+        /// Vault directory
+        dir: PathBuf,
+
+        /// Law ID to export (repeatable)
+        #[arg(long = "law", value_name = "ID", required = true, num_args = 1..)]
+        laws: Vec<String>,
+
+        /// Also write one note per article
+        #[arg(long)]
+        articles: bool,
+    },
+
+    /// Write a law's revision history as a git repository, one commit per revision
+    Git {
+        /// Law ID
+        law_id: String,
+
+        /// Repository directory (created if missing; re-run to append new revisions)
+        dir: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum LocalCommand {
+    /// Search indexed articles without calling the API
+    Search {
+        /// Search query (all terms must match)
+        query: String,
+
+        /// Maximum number of hits
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum ConfigCommand {
     /// Set a configuration value
@@ -466,7 +821,7 @@ pub enum MetricsCommand {
         #[arg(long, help = "Report end date")]
         to: Option<String>,
 
-        /// Report output format (text, json, csv)
+        /// Report output format (text, json, yaml, toml, csv, html)
         #[arg(long, default_value = "text", help = "Report output format")]
         output_format: String,
     },
@@ -543,6 +898,10 @@ pub enum FilterCommand {
         #[arg(long)]
         title_only: bool,
 
+        /// Search scope (title, body)
+        #[arg(long = "in", value_name = "SCOPE", conflicts_with = "title_only")]
+        scope: Option<SearchScope>,
+
         /// Minimum score
         #[arg(long)]
         min_score: Option<f32>,
@@ -1,31 +1,35 @@
-use crate::api::client::ClientConfig;
+use crate::api::client::{ClientConfig, LegalApiClient};
 use crate::api::types::UnifiedSearchRequest;
 use crate::api::{ApiClientFactory, ApiType};
 use crate::cache::CacheStore;
 use crate::cli::args::AdmruleArgs;
-use crate::cli::OutputFormat;
+use crate::cli::commands::stream;
 use crate::config::Config;
 use crate::error::Result;
-use crate::output::formatter::Formatter;
+use crate::output;
+use crate::output::Formatter;
 use std::sync::Arc;
 
 /// Execute admrule (administrative rule) command
 pub async fn execute(
     args: AdmruleArgs,
-    format: OutputFormat,
+    formatter: Formatter,
     _quiet: bool,
     _verbose: bool,
     no_cache: bool,
+    offline: bool,
 ) -> Result<()> {
     let config = Config::load()?;
+    let offline = offline || config.cache.offline;
 
     // Check for API key
     let api_key = config
         .get_admrul_api_key()
+        .or_else(|| offline.then(String::new))
         .ok_or(crate::error::WarpError::NoApiKey)?;
 
-    // Create cache store if cache is enabled and not bypassed
-    let cache = if config.cache.enabled && !no_cache {
+    // Create cache store if cache is enabled and not bypassed; offline mode always needs it
+    let cache = if (config.cache.enabled && !no_cache) || offline {
         let cache_config = config.cache.to_cache_config();
         Some(Arc::new(CacheStore::new(cache_config).await?))
     } else {
@@ -36,11 +40,12 @@ pub async fn execute(
         api_key,
         cache,
         bypass_cache: no_cache,
+        offline,
         ..Default::default()
     };
 
-    let client = ApiClientFactory::create(ApiType::Admrul, client_config)?;
-    let formatter = Formatter::new(format);
+    let client: Arc<dyn LegalApiClient> =
+        Arc::from(ApiClientFactory::create(ApiType::Admrul, client_config)?);
 
     // Handle search query
     if let Some(query) = args.query {
@@ -48,12 +53,16 @@ pub async fn execute(
             query,
             page_no: args.page,
             page_size: args.size,
+            search_scope: args.scope,
             ..Default::default()
         };
 
+        if args.paging.is_enabled() {
+            return stream::stream_search(client, request, &args.paging, &formatter).await;
+        }
+
         let response = client.search(request).await?;
-        let output = formatter.format_search(&response)?;
-        println!("{}", output);
+        output::print_search_response(&response, &formatter)?;
     } else {
         println!("Usage: warp admrule <QUERY>");
         println!("\nExample:");
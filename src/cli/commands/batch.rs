@@ -0,0 +1,613 @@
+use colored::*;
+use comfy_table::{Cell, Color, Table};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+use crate::api::batcher::{BatchConfig, RequestBatcher};
+use crate::api::types::{SearchItem, UnifiedSearchRequest};
+use crate::api::ApiType;
+use crate::cli::args::{BatchArgs, BatchInput};
+use crate::cli::commands::search::{create_clients, date_bound, parse_source};
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::error::{Result, WarpError};
+use crate::output;
+
+/// Largest page the APIs accept
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// One row of the query file
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+struct QueryRow {
+    query: String,
+    source: Option<String>,
+    law_type: Option<String>,
+    department: Option<String>,
+    region: Option<String>,
+    #[serde(alias = "date_from")]
+    from: Option<String>,
+    #[serde(alias = "date_to")]
+    to: Option<String>,
+    size: Option<u32>,
+    /// Line in the file, for messages
+    #[serde(skip)]
+    line: usize,
+}
+
+/// A search result tagged with the query that found it
+#[derive(Debug, Serialize)]
+struct BatchHit {
+    line: usize,
+    query: String,
+    api: String,
+    #[serde(flatten)]
+    item: SearchItem,
+}
+
+/// A query that failed on one source
+#[derive(Debug, Serialize)]
+struct BatchFailure {
+    line: usize,
+    query: String,
+    api: String,
+    error: String,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchSummary {
+    queries: usize,
+    requests: usize,
+    results: usize,
+    failed: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchReport {
+    summary: BatchSummary,
+    results: Vec<BatchHit>,
+    failures: Vec<BatchFailure>,
+}
+
+/// One search of one row on one source
+struct Job {
+    row: usize,
+    api_type: ApiType,
+    request: UnifiedSearchRequest,
+}
+
+/// Execute the batch command
+pub async fn execute(
+    args: BatchArgs,
+    format: OutputFormat,
+    no_cache: bool,
+    offline: bool,
+) -> Result<()> {
+    if !matches!(
+        format,
+        OutputFormat::Table
+            | OutputFormat::Json
+            | OutputFormat::Jsonl
+            | OutputFormat::Yaml
+            | OutputFormat::Csv
+    ) {
+        let name = clap::ValueEnum::to_possible_value(&format)
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        return Err(WarpError::InvalidInput(format!(
+            "batch는 {} 형식을 지원하지 않습니다 (table, json, jsonl, yaml, csv)",
+            name
+        )));
+    }
+
+    let input = args.input.unwrap_or_else(|| detect_input(&args.file));
+    let text = if args.file == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        std::fs::read_to_string(&args.file)?
+    };
+    let rows = read_rows(&text, input)?;
+    if rows.is_empty() {
+        return Err(WarpError::InvalidInput(format!(
+            "{}에 검색어가 없습니다",
+            args.file.display()
+        )));
+    }
+    let jobs = plan(&rows, &args.source, args.size)?;
+
+    let mut api_types: Vec<ApiType> = Vec::new();
+    for job in &jobs {
+        if !api_types.contains(&job.api_type) {
+            api_types.push(job.api_type);
+        }
+    }
+    let config = Config::load()?;
+    let clients = create_clients(&api_types, &config, no_cache, offline).await?;
+    if clients.is_empty() {
+        return Err(WarpError::NoApiKey);
+    }
+    let concurrency = args.concurrency.max(1);
+    let batchers: HashMap<ApiType, RequestBatcher> = clients
+        .into_iter()
+        .map(|(api_type, client)| {
+            let config = BatchConfig {
+                max_batch_size: concurrency,
+                ..Default::default()
+            };
+            (api_type, RequestBatcher::new(client, config))
+        })
+        .collect();
+
+    let (results, mut failures) = run(&rows, jobs, &batchers, concurrency).await;
+    let requests = results.len() + failures.len();
+    if failures.len() == requests {
+        if let Some((_, e)) = failures.pop() {
+            return Err(e);
+        }
+    }
+
+    let report = BatchReport {
+        summary: BatchSummary {
+            queries: rows.len(),
+            requests,
+            results: results.iter().map(Vec::len).sum(),
+            failed: failures.len(),
+        },
+        results: results.into_iter().flatten().collect(),
+        failures: failures.into_iter().map(|(failure, _)| failure).collect(),
+    };
+    print_report(&report, format)
+}
+
+/// Layout implied by the file extension
+fn detect_input(path: &Path) -> BatchInput {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("csv") => BatchInput::Csv,
+        Some("jsonl" | "ndjson") => BatchInput::Jsonl,
+        _ => BatchInput::Text,
+    }
+}
+
+/// Parse the query file; a malformed row stops the batch before any request
+fn read_rows(text: &str, input: BatchInput) -> Result<Vec<QueryRow>> {
+    let text = text.trim_start_matches('\u{feff}');
+    let mut rows = Vec::new();
+    match input {
+        BatchInput::Text => {
+            for (i, line) in text.lines().enumerate() {
+                let query = line.trim();
+                if query.is_empty() || query.starts_with('#') {
+                    continue;
+                }
+                rows.push(QueryRow {
+                    query: query.to_string(),
+                    line: i + 1,
+                    ..Default::default()
+                });
+            }
+        }
+        BatchInput::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .flexible(true)
+                .from_reader(text.as_bytes());
+            let headers = reader.headers()?.clone();
+            if !headers.iter().any(|h| h == "query") {
+                return Err(WarpError::InvalidInput(
+                    "CSV 첫 행에 query 열이 있어야 합니다".to_string(),
+                ));
+            }
+            let mut record = csv::StringRecord::new();
+            while reader.read_record(&mut record)? {
+                let line = record.position().map_or(0, |p| p.line() as usize);
+                let mut row: QueryRow = record.deserialize(Some(&headers)).map_err(|e| {
+                    WarpError::InvalidInput(format!("{}행을 읽을 수 없습니다: {}", line, e))
+                })?;
+                row.line = line;
+                rows.push(row);
+            }
+        }
+        BatchInput::Jsonl => {
+            for (i, line) in text.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let mut row: QueryRow = serde_json::from_str(line).map_err(|e| {
+                    WarpError::InvalidInput(format!("{}행을 읽을 수 없습니다: {}", i + 1, e))
+                })?;
+                row.line = i + 1;
+                rows.push(row);
+            }
+        }
+    }
+
+    for row in &mut rows {
+        row.query = row.query.trim().to_string();
+        if row.query.is_empty() {
+            return Err(WarpError::InvalidInput(format!(
+                "{}행에 검색어(query)가 없습니다",
+                row.line
+            )));
+        }
+        for field in [
+            &mut row.source,
+            &mut row.law_type,
+            &mut row.department,
+            &mut row.region,
+            &mut row.from,
+            &mut row.to,
+        ] {
+            *field = field
+                .take()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+        }
+        // Same forms as --from/--to of `warp mirror`: YYYY or YYYYMMDD
+        for (field, end) in [(&mut row.from, false), (&mut row.to, true)] {
+            *field = field
+                .take()
+                .map(|value| date_bound(&value, end))
+                .transpose()
+                .map_err(|e| match e {
+                    WarpError::InvalidInput(message) => {
+                        WarpError::InvalidInput(format!("{}행: {}", row.line, message))
+                    }
+                    e => e,
+                })?;
+        }
+    }
+    Ok(rows)
+}
+
+/// One job per row and source
+fn plan(rows: &[QueryRow], default_source: &str, default_size: u32) -> Result<Vec<Job>> {
+    let mut jobs = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        let source = row.source.as_deref().unwrap_or(default_source);
+        let api_types = parse_source(source);
+        if api_types.is_empty() {
+            return Err(WarpError::InvalidInput(format!(
+                "{}행: 알 수 없는 소스: {}",
+                row.line, source
+            )));
+        }
+        let request = UnifiedSearchRequest {
+            query: row.query.clone(),
+            page_size: row.size.unwrap_or(default_size).clamp(1, MAX_PAGE_SIZE),
+            law_type: row.law_type.clone(),
+            department: row.department.clone(),
+            region: row.region.clone(),
+            date_from: row.from.clone(),
+            date_to: row.to.clone(),
+            ..Default::default()
+        };
+        jobs.extend(api_types.into_iter().map(|api_type| Job {
+            row: index,
+            api_type,
+            request: request.clone(),
+        }));
+    }
+    Ok(jobs)
+}
+
+/// Run the jobs in file order, at most `concurrency` at a time
+///
+/// Identical searches (same source, query and filters) reach the API once;
+/// the batchers share the response between the rows that asked for it.
+async fn run(
+    rows: &[QueryRow],
+    jobs: Vec<Job>,
+    batchers: &HashMap<ApiType, RequestBatcher>,
+    concurrency: usize,
+) -> (Vec<Vec<BatchHit>>, Vec<(BatchFailure, WarpError)>) {
+    let outcomes: Vec<(Job, Result<Vec<SearchItem>>)> = stream::iter(jobs)
+        .map(|job| async move {
+            let result = match batchers.get(&job.api_type) {
+                Some(batcher) => batcher
+                    .submit_request(job.request.clone())
+                    .await
+                    .map(|response| response.items),
+                None => Err(WarpError::Config(format!(
+                    "{} API 키가 설정되지 않았습니다",
+                    job.api_type.display_name()
+                ))),
+            };
+            (job, result)
+        })
+        .buffered(concurrency)
+        .collect()
+        .await;
+
+    let mut results = Vec::new();
+    let mut failures = Vec::new();
+    for (job, result) in outcomes {
+        let row = &rows[job.row];
+        let api = job.api_type.as_str().to_string();
+        match result {
+            Ok(items) => results.push(
+                items
+                    .into_iter()
+                    .map(|item| BatchHit {
+                        line: row.line,
+                        query: row.query.clone(),
+                        api: api.clone(),
+                        item,
+                    })
+                    .collect(),
+            ),
+            Err(e) => failures.push((
+                BatchFailure {
+                    line: row.line,
+                    query: row.query.clone(),
+                    api,
+                    error: e.to_string(),
+                },
+                e,
+            )),
+        }
+    }
+    (results, failures)
+}
+
+fn print_report(report: &BatchReport, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(report)?);
+            return Ok(());
+        }
+        OutputFormat::Yaml => {
+            print!("{}", output::formatter::to_yaml(report)?);
+            return Ok(());
+        }
+        OutputFormat::Jsonl => {
+            for hit in &report.results {
+                println!("{}", output::formatter::to_json_line(hit)?);
+            }
+        }
+        OutputFormat::Csv => print!("{}", to_csv(&report.results)?),
+        _ => {
+            if report.results.is_empty() {
+                println!("{}", "검색 결과가 없습니다.".dimmed());
+            } else {
+                let mut table = Table::new();
+                table.set_header(vec![
+                    Cell::new("행").fg(Color::Cyan),
+                    Cell::new("검색어").fg(Color::Cyan),
+                    Cell::new("소스").fg(Color::Cyan),
+                    Cell::new("ID").fg(Color::Cyan),
+                    Cell::new("제목").fg(Color::Cyan),
+                    Cell::new("날짜").fg(Color::Cyan),
+                ]);
+                for hit in &report.results {
+                    table.add_row(vec![
+                        Cell::new(hit.line),
+                        Cell::new(&hit.query),
+                        Cell::new(&hit.api),
+                        Cell::new(&hit.item.id),
+                        Cell::new(&hit.item.title),
+                        Cell::new(date(&hit.item)),
+                    ]);
+                }
+                println!("{}", table);
+            }
+            println!(
+                "{} 검색어 {}개 · 요청 {}건 · 결과 {}건 · 실패 {}건",
+                "📦".bold(),
+                report.summary.queries,
+                report.summary.requests,
+                report.summary.results,
+                report.summary.failed
+            );
+        }
+    }
+
+    // Keep stdout machine-readable; failures go to stderr
+    if !report.failures.is_empty() {
+        eprintln!(
+            "{} {}건 실패",
+            "⚠️".yellow(),
+            report.failures.len().to_string().bold()
+        );
+        for failure in &report.failures {
+            eprintln!(
+                "  {}행 '{}' ({}): {}",
+                failure.line, failure.query, failure.api, failure.error
+            );
+        }
+    }
+    Ok(())
+}
+
+fn date(item: &SearchItem) -> &str {
+    item.enforcement_date
+        .as_deref()
+        .or(item.revision_date.as_deref())
+        .unwrap_or("")
+}
+
+fn to_csv(hits: &[BatchHit]) -> Result<String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+    wtr.write_record([
+        "line",
+        "query",
+        "api",
+        "id",
+        "title",
+        "law_type",
+        "department",
+        "date",
+    ])?;
+    for hit in hits {
+        wtr.write_record([
+            hit.line.to_string().as_str(),
+            &hit.query,
+            &hit.api,
+            &hit.item.id,
+            &hit.item.title,
+            hit.item.law_type.as_deref().unwrap_or(""),
+            hit.item.department.as_deref().unwrap_or(""),
+            date(&hit.item),
+        ])?;
+    }
+    let data = wtr
+        .into_inner()
+        .map_err(|e| WarpError::Other(e.to_string()))?;
+
+    // Add BOM for Excel compatibility
+    let mut result = vec![0xEF, 0xBB, 0xBF];
+    result.extend_from_slice(&data);
+    String::from_utf8(result).map_err(|e| WarpError::Other(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::LegalApiClient;
+    use crate::api::types::{LawDetail, LawHistory, SearchResponse};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Returns one item per search, named after the query; fails for "실패"
+    struct MockClient {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LegalApiClient for MockClient {
+        async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            if request.query == "실패" {
+                return Err(WarpError::ServerError("503".to_string()));
+            }
+            Ok(SearchResponse {
+                total_count: 1,
+                page_no: 1,
+                page_size: request.page_size,
+                items: vec![SearchItem {
+                    id: format!("id-{}", request.query),
+                    title: request.query.clone(),
+                    law_no: None,
+                    law_type: None,
+                    department: None,
+                    enforcement_date: None,
+                    revision_date: None,
+                    summary: None,
+                    source: "Mock".to_string(),
+                    metadata: HashMap::new(),
+                    score: None,
+                    sources: Vec::new(),
+                }],
+                source: "Mock".to_string(),
+                timestamp: chrono::Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
+            })
+        }
+
+        async fn get_detail(&self, _id: &str) -> Result<LawDetail> {
+            unimplemented!()
+        }
+
+        async fn get_history(&self, _id: &str) -> Result<LawHistory> {
+            unimplemented!()
+        }
+
+        fn api_type(&self) -> ApiType {
+            ApiType::Nlic
+        }
+        fn base_url(&self) -> &str {
+            "http://mock"
+        }
+        fn is_configured(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_read_rows_in_every_layout() {
+        let rows = read_rows("민법\n\n# 주석\n  형법 \n", BatchInput::Text).unwrap();
+        let queries: Vec<_> = rows.iter().map(|r| (r.line, r.query.as_str())).collect();
+        assert_eq!(queries, vec![(1, "민법"), (4, "형법")]);
+
+        let csv = "\u{feff}query,source,from,size\n명예훼손,prec,20100101,5\n민법,,,\n";
+        let rows = read_rows(csv, BatchInput::Csv).unwrap();
+        assert_eq!(rows[0].source.as_deref(), Some("prec"));
+        assert_eq!(rows[0].from.as_deref(), Some("20100101"));
+        assert_eq!(rows[0].size, Some(5));
+        assert_eq!((rows[1].line, rows[1].source.as_deref()), (3, None));
+
+        let jsonl = "{\"query\": \"민법\", \"date_to\": \"20201231\"}\n\n{\"query\": \"형법\"}\n";
+        let rows = read_rows(jsonl, BatchInput::Jsonl).unwrap();
+        assert_eq!(rows[0].to.as_deref(), Some("20201231"));
+        assert_eq!(rows[1].line, 3);
+
+        assert!(read_rows("source\nprec\n", BatchInput::Csv).is_err());
+        let err = read_rows("{\"query\": \" \"}\n", BatchInput::Jsonl).unwrap_err();
+        assert!(err.to_string().contains("1행"));
+
+        // Dates are normalized like `warp mirror --from/--to`; malformed ones stop the batch
+        let rows = read_rows("query,from,to\n민법,2010,2020-06-30\n", BatchInput::Csv).unwrap();
+        assert_eq!(rows[0].from.as_deref(), Some("20100101"));
+        assert_eq!(rows[0].to.as_deref(), Some("20200630"));
+        let err = read_rows("query,to\n민법,\n형법,2020.6\n", BatchInput::Csv).unwrap_err();
+        assert!(err.to_string().contains("3행"), "{}", err);
+    }
+
+    #[test]
+    fn test_plan_expands_sources() {
+        let rows = read_rows("query,source\n민법,\"nlic,prec\"\n형법,\n", BatchInput::Csv).unwrap();
+        let jobs = plan(&rows, "nlic", 10).unwrap();
+        let planned: Vec<_> = jobs.iter().map(|j| (j.row, j.api_type)).collect();
+        assert_eq!(
+            planned,
+            vec![(0, ApiType::Nlic), (0, ApiType::Prec), (1, ApiType::Nlic)]
+        );
+
+        let rows = read_rows("query,source\n민법,nowhere\n", BatchInput::Csv).unwrap();
+        assert!(plan(&rows, "nlic", 10).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_tags_results_and_dedups_queries() {
+        let client = Arc::new(MockClient {
+            calls: AtomicUsize::new(0),
+        });
+        let batchers = HashMap::from([(
+            ApiType::Nlic,
+            RequestBatcher::new(client.clone(), BatchConfig::default()),
+        )]);
+        let rows = read_rows("민법\n실패\n민법\n", BatchInput::Text).unwrap();
+        let jobs = plan(&rows, "nlic,prec", 10).unwrap();
+
+        let (results, failures) = run(&rows, jobs, &batchers, 2).await;
+
+        let hits: Vec<_> = results
+            .iter()
+            .flatten()
+            .map(|hit| (hit.line, hit.query.as_str(), hit.item.id.as_str()))
+            .collect();
+        assert_eq!(hits, vec![(1, "민법", "id-민법"), (3, "민법", "id-민법")]);
+
+        // Row 2 failed on nlic; prec has no client in every row
+        let failed: Vec<_> = failures
+            .iter()
+            .map(|(f, _)| (f.line, f.api.as_str()))
+            .collect();
+        assert_eq!(
+            failed,
+            vec![(1, "prec"), (2, "nlic"), (2, "prec"), (3, "prec")]
+        );
+        assert_eq!(client.calls.load(Ordering::Relaxed), 2);
+    }
+}
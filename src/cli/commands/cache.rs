@@ -123,6 +123,20 @@ fn show_config(config: &Config) -> Result<()> {
         cache_config.ttl_seconds / 3600
     );
     println!("  {} {} MB", "최대 크기:".bold(), cache_config.max_size_mb);
+    println!(
+        "  {} {} 시간",
+        "만료 후 재사용:".bold(),
+        cache_config.stale_seconds / 3600
+    );
+    println!(
+        "  {} {}",
+        "오프라인 모드:".bold(),
+        if cache_config.offline {
+            "Yes".yellow()
+        } else {
+            "No".normal()
+        }
+    );
 
     if let Some(ref dir) = cache_config.cache_dir {
         println!("  {} {}", "캐시 디렉토리:".bold(), dir.display());
@@ -0,0 +1,155 @@
+use crate::api::client::{ClientConfig, LegalApiClient};
+use crate::api::{ApiClientFactory, ApiType};
+use crate::cache::CacheStore;
+use crate::cli::args::{ExportArgs, ExportCommand};
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::error::{Result, WarpError};
+use crate::output::git_history::{chronological, GitExportReport, GitRepo};
+use crate::output::vault::Vault;
+use colored::*;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Execute export commands
+pub async fn execute(
+    args: ExportArgs,
+    format: OutputFormat,
+    no_cache: bool,
+    offline: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+
+    match args.command {
+        ExportCommand::Vault {
+            dir,
+            laws,
+            articles,
+        } => {
+            let client = create_law_client(&config, no_cache, offline).await?;
+            export_vault(client.as_ref(), &dir, &laws, articles, format).await
+        }
+        ExportCommand::Git { law_id, dir } => {
+            let client = create_law_client(&config, no_cache, offline).await?;
+            export_git(client.as_ref(), &law_id, &dir, format).await
+        }
+    }
+}
+
+/// Client for the national law API, sharing the regular cache
+pub(crate) async fn create_law_client(
+    config: &Config,
+    no_cache: bool,
+    offline: bool,
+) -> Result<Box<dyn LegalApiClient>> {
+    let offline = offline || config.cache.offline;
+    let api_key = config
+        .get_nlic_api_key()
+        .or_else(|| offline.then(String::new))
+        .ok_or(WarpError::NoApiKey)?;
+
+    let cache = if (config.cache.enabled && !no_cache) || offline {
+        Some(Arc::new(
+            CacheStore::new(config.cache.to_cache_config()).await?,
+        ))
+    } else {
+        None
+    };
+
+    ApiClientFactory::create(
+        ApiType::Nlic,
+        ClientConfig {
+            api_key,
+            cache,
+            bypass_cache: no_cache,
+            offline,
+            ..Default::default()
+        },
+    )
+}
+
+/// Fetch the given laws and write them into a Markdown vault
+async fn export_vault(
+    client: &dyn LegalApiClient,
+    dir: &Path,
+    laws: &[String],
+    articles: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut details = Vec::with_capacity(laws.len());
+    for id in laws {
+        details.push(client.get_detail(id).await?);
+    }
+
+    let report = Vault::new(dir).write(&details, articles)?;
+
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("{}", "볼트 내보내기 완료 (Vault exported)".bold().cyan());
+    println!("  {} {}", "위치:".bold(), dir.display());
+    println!("  {} {}", "법령:".bold(), details.len());
+    println!("  {} {}", "작성:".bold(), report.written.len());
+    println!("  {} {}", "변경 없음:".bold(), report.unchanged);
+    Ok(())
+}
+
+/// Commit every revision of a law not yet in the repository, oldest first
+async fn export_git(
+    client: &dyn LegalApiClient,
+    law_id: &str,
+    dir: &Path,
+    format: OutputFormat,
+) -> Result<()> {
+    let history = client.get_history(law_id).await?;
+    if history.entries.is_empty() {
+        return Err(WarpError::NotFound(format!("법령 {}의 연혁", law_id)));
+    }
+    let repo = GitRepo::open(dir, law_id)?;
+    let committed = repo.committed_versions()?;
+
+    let mut report = GitExportReport {
+        dir: dir.to_path_buf(),
+        ..Default::default()
+    };
+    for entry in chronological(&history.entries) {
+        let label = format!("{} {}", entry.revision_date, entry.revision_type);
+        let Some(version_id) = &entry.version_id else {
+            report.missing.push(label);
+            continue;
+        };
+        if committed.contains(version_id) {
+            report.existing += 1;
+            continue;
+        }
+        let detail = client.get_detail(version_id).await?;
+        repo.commit_revision(&detail, entry)?;
+        report.committed.push(label);
+    }
+
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        "연혁 저장소 내보내기 완료 (Git history exported)"
+            .bold()
+            .cyan()
+    );
+    println!("  {} {}", "위치:".bold(), dir.display());
+    println!("  {} {}", "새 커밋:".bold(), report.committed.len());
+    println!("  {} {}", "기존 커밋:".bold(), report.existing);
+    if !report.missing.is_empty() {
+        println!(
+            "  {} {} ({})",
+            "본문 없음:".bold().yellow(),
+            report.missing.len(),
+            report.missing.join(", ")
+        );
+    }
+    Ok(())
+}
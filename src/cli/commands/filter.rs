@@ -1,3 +1,4 @@
+use crate::api::types::SearchScope;
 use crate::cli::args::{FilterArgs, FilterCommand};
 use crate::config::{Config, FilterPreset};
 use crate::error::{Result, WarpError};
@@ -19,6 +20,7 @@ pub async fn execute(args: FilterArgs) -> Result<()> {
             recent_days,
             regex,
             title_only,
+            scope,
             min_score,
         } => {
             execute_save_command(
@@ -33,6 +35,7 @@ pub async fn execute(args: FilterArgs) -> Result<()> {
                 recent_days,
                 regex,
                 title_only,
+                scope,
                 min_score,
             )
             .await
@@ -56,6 +59,7 @@ async fn execute_save_command(
     recent_days: Option<u32>,
     regex: bool,
     title_only: bool,
+    scope: Option<SearchScope>,
     min_score: Option<f32>,
 ) -> Result<()> {
     let mut config = Config::load()?;
@@ -90,6 +94,7 @@ async fn execute_save_command(
         recent_days,
         regex,
         title_only,
+        search_scope: scope,
         min_score,
         created_at: Utc::now(),
     };
@@ -136,6 +141,9 @@ async fn execute_list_command() -> Result<()> {
         if preset.title_only {
             filters.push("제목만".to_string());
         }
+        if let Some(scope) = preset.search_scope {
+            filters.push(format!("검색범위: {}", scope.as_str()));
+        }
 
         if !filters.is_empty() {
             println!("   필터: {}", filters.join(", "));
@@ -192,6 +200,9 @@ async fn execute_show_command(name: String) -> Result<()> {
     if preset.title_only {
         println!("제목만 검색: 활성화");
     }
+    if let Some(scope) = preset.search_scope {
+        println!("검색 범위: {}", scope.as_str());
+    }
     if let Some(min_score) = preset.min_score {
         println!("최소 점수: {}", min_score);
     }
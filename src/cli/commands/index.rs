@@ -0,0 +1,90 @@
+use crate::cache::CacheStore;
+use crate::cli::args::{IndexArgs, IndexCommand};
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::index::LocalIndex;
+use colored::*;
+
+/// Execute local index commands
+pub async fn execute(args: IndexArgs, format: OutputFormat) -> Result<()> {
+    let config = Config::load()?;
+
+    match args.command {
+        IndexCommand::Build { rebuild } => build_index(&config, rebuild, format).await,
+        IndexCommand::Status => show_status(&config, format).await,
+    }
+}
+
+/// Open the index database that lives next to the cache database
+pub(crate) async fn open_index(config: &Config) -> Result<LocalIndex> {
+    let cache_db_path = config.cache.get_cache_db_path()?;
+    LocalIndex::open(LocalIndex::path_for(&cache_db_path)).await
+}
+
+/// Index every cached detail response
+async fn build_index(config: &Config, rebuild: bool, format: OutputFormat) -> Result<()> {
+    let cache = CacheStore::new(config.cache.to_cache_config()).await?;
+    let index = open_index(config).await?;
+    let report = index.build_from_cache(&cache, rebuild).await?;
+
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("{}", "로컬 색인 생성 완료 (Index built)".bold().cyan());
+    println!("  {} {}", "문서:".bold(), report.documents);
+    println!("  {} {}", "조문:".bold(), report.articles);
+    if report.skipped > 0 {
+        println!("  {} {}", "건너뜀:".bold().yellow(), report.skipped);
+    }
+    if report.documents == 0 {
+        println!(
+            "\n{}",
+            "캐시된 상세 정보가 없습니다. 'warp law <검색어>' 후 상세 조회로 문서를 가져오세요."
+                .dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Show index statistics
+async fn show_status(config: &Config, format: OutputFormat) -> Result<()> {
+    let index = open_index(config).await?;
+    let status = index.status().await?;
+
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+        return Ok(());
+    }
+
+    println!("{}", "로컬 색인 상태 (Index Status)".bold().cyan());
+    println!("{}", "=".repeat(50));
+    println!("  {} {}", "위치:".bold(), status.db_path.display());
+    println!("  {} {}", "문서:".bold(), status.documents);
+    println!("  {} {}", "조문:".bold(), status.articles);
+    println!(
+        "  {} {:.2} MB",
+        "크기:".bold(),
+        status.size as f64 / 1_048_576.0
+    );
+    match status.last_indexed {
+        Some(at) => println!(
+            "  {} {}",
+            "마지막 색인:".bold(),
+            at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S")
+        ),
+        None => println!("  {} -", "마지막 색인:".bold()),
+    }
+
+    if !status.by_api.is_empty() {
+        println!("\n{}", "API별 문서 현황:".bold());
+        for (api, count) in &status.by_api {
+            println!("  {}: {} 문서", api.to_uppercase(), count);
+        }
+    }
+
+    Ok(())
+}
@@ -1,13 +1,17 @@
 use crate::api::client::{ClientConfig, LegalApiClient};
-use crate::api::types::{ResponseType, UnifiedSearchRequest};
+use crate::api::types::{HistoryEntry, ResponseType, SearchScope, UnifiedSearchRequest};
 use crate::api::{ApiClientFactory, ApiType};
 use crate::cache::CacheStore;
-use crate::cli::args::{LawArgs, LawCommand};
+use crate::cli::args::{LawArgs, LawCommand, PagingArgs};
+use crate::cli::commands::stream;
 use crate::cli::OutputFormat;
 use crate::config::Config;
 use crate::error::{Result, WarpError};
 use crate::output;
+use crate::output::diff::{find_revision, LawDiff, Version};
+use crate::output::Formatter;
 use crate::progress::{messages, ApiStage, EnhancedApiProgress, ProgressManager};
+use clap::ValueEnum;
 use std::sync::Arc;
 
 /// Parameters for law search operation
@@ -15,28 +19,42 @@ struct SearchParams {
     query: String,
     page: u32,
     size: u32,
+    scope: SearchScope,
     law_type: Option<String>,
     department: Option<String>,
-    format: OutputFormat,
+    paging: PagingArgs,
+    formatter: Formatter,
+}
+
+/// Versions compared by `law diff`
+struct DiffVersions {
+    from: String,
+    to: String,
+    unified: bool,
 }
 
 /// Execute law command
 pub async fn execute(
     args: LawArgs,
-    format: OutputFormat,
+    formatter: Formatter,
     quiet: bool,
     verbose: bool,
     no_cache: bool,
+    offline: bool,
 ) -> Result<()> {
     // Create progress manager
     let progress_manager = Arc::new(ProgressManager::new(quiet, verbose));
 
     // Load configuration
     let config = Config::load()?;
-    let api_key = config.get_nlic_api_key().ok_or(WarpError::NoApiKey)?;
+    let offline = offline || config.cache.offline;
+    let api_key = config
+        .get_nlic_api_key()
+        .or_else(|| offline.then(String::new))
+        .ok_or(WarpError::NoApiKey)?;
 
-    // Create cache store if cache is enabled and not bypassed
-    let cache = if config.cache.enabled && !no_cache {
+    // Create cache store if cache is enabled and not bypassed; offline mode always needs it
+    let cache = if (config.cache.enabled && !no_cache) || offline {
         let cache_config = config.cache.to_cache_config();
         Some(Arc::new(CacheStore::new(cache_config).await?))
     } else {
@@ -48,10 +66,12 @@ pub async fn execute(
         api_key,
         cache,
         bypass_cache: no_cache,
+        offline,
         ..Default::default()
     };
 
-    let client = ApiClientFactory::create(ApiType::Nlic, client_config)?;
+    let client: Arc<dyn LegalApiClient> =
+        Arc::from(ApiClientFactory::create(ApiType::Nlic, client_config)?);
 
     // Extract common args before match
     let law_type = args.law_type.clone();
@@ -59,22 +79,46 @@ pub async fn execute(
 
     // Handle direct query or subcommand
     match args.command {
-        Some(LawCommand::Search { query, page, size }) => {
+        Some(LawCommand::Search {
+            query,
+            page,
+            size,
+            scope,
+            paging,
+        }) => {
             let params = SearchParams {
                 query,
                 page,
                 size,
+                scope,
                 law_type,
                 department,
-                format,
+                paging,
+                formatter,
             };
-            search_laws(client.as_ref(), params, progress_manager).await
+            search_laws(client, params, progress_manager).await
         }
         Some(LawCommand::Detail { id }) => {
-            get_law_detail(client.as_ref(), id, format, progress_manager).await
+            get_law_detail(client.as_ref(), id, &formatter, progress_manager).await
         }
         Some(LawCommand::History { id }) => {
-            get_law_history(client.as_ref(), id, format, progress_manager).await
+            get_law_history(client.as_ref(), id, &formatter, progress_manager).await
+        }
+        Some(LawCommand::Diff {
+            id,
+            from,
+            to,
+            unified,
+        }) => {
+            let versions = DiffVersions { from, to, unified };
+            diff_law(
+                client.as_ref(),
+                id,
+                versions,
+                formatter.format(),
+                progress_manager,
+            )
+            .await
         }
         None => {
             // Direct query without subcommand
@@ -83,11 +127,13 @@ pub async fn execute(
                     query,
                     page: args.page,
                     size: args.size,
+                    scope: args.scope,
                     law_type,
                     department,
-                    format,
+                    paging: args.paging,
+                    formatter,
                 };
-                search_laws(client.as_ref(), params, progress_manager).await
+                search_laws(client, params, progress_manager).await
             } else {
                 Err(WarpError::InvalidInput(
                     "No search query provided. Use 'warp law <query>' or 'warp law search <query>'"
@@ -99,7 +145,7 @@ pub async fn execute(
 }
 
 async fn search_laws(
-    client: &dyn LegalApiClient,
+    client: Arc<dyn LegalApiClient>,
     params: SearchParams,
     progress_manager: Arc<ProgressManager>,
 ) -> Result<()> {
@@ -114,11 +160,16 @@ async fn search_laws(
         page_no: params.page,
         page_size: params.size,
         response_type: ResponseType::Json,
+        search_scope: params.scope,
         law_type: params.law_type,
         department: params.department,
         ..Default::default()
     };
 
+    if params.paging.is_enabled() {
+        return stream::stream_search(client, request, &params.paging, &params.formatter).await;
+    }
+
     // Show enhanced progress with stages
     let mut progress = EnhancedApiProgress::new(progress_manager.clone(), "국가법령정보센터");
 
@@ -152,8 +203,7 @@ async fn search_laws(
         return Ok(());
     }
 
-    let output = output::format_search_response(&response, params.format)?;
-    println!("{}", output);
+    output::print_search_response(&response, &params.formatter)?;
 
     Ok(())
 }
@@ -161,7 +211,7 @@ async fn search_laws(
 async fn get_law_detail(
     client: &dyn LegalApiClient,
     id: String,
-    format: OutputFormat,
+    formatter: &Formatter,
     progress_manager: Arc<ProgressManager>,
 ) -> Result<()> {
     // Show enhanced progress for detail retrieval
@@ -177,15 +227,14 @@ async fn get_law_detail(
 
     progress.advance_stage(ApiStage::Parsing, "상세 정보 파싱 중");
     progress.complete_success("법령 상세 정보 조회 완료");
-    let output = output::format_law_detail(&detail, format)?;
-    println!("{}", output);
+    output::print_law_detail(&detail, ApiType::Nlic, formatter)?;
     Ok(())
 }
 
 async fn get_law_history(
     client: &dyn LegalApiClient,
     id: String,
-    format: OutputFormat,
+    formatter: &Formatter,
     progress_manager: Arc<ProgressManager>,
 ) -> Result<()> {
     // Show enhanced progress for history retrieval
@@ -201,7 +250,68 @@ async fn get_law_history(
 
     progress.advance_stage(ApiStage::Parsing, "개정 이력 파싱 중");
     progress.complete_success("법령 개정 이력 조회 완료");
-    let output = output::format_law_history(&history, format)?;
-    println!("{}", output);
+    output::print_law_history(&history, formatter)?;
     Ok(())
 }
+
+async fn diff_law(
+    client: &dyn LegalApiClient,
+    id: String,
+    versions: DiffVersions,
+    format: OutputFormat,
+    progress_manager: Arc<ProgressManager>,
+) -> Result<()> {
+    let mut progress = EnhancedApiProgress::new(progress_manager.clone(), "국가법령정보센터");
+
+    progress.advance_stage(
+        ApiStage::Connecting,
+        &format!("법령 개정 이력 연결 중 (ID: {})", id),
+    );
+    let history = client.get_history(&id).await?;
+    let from = find_revision(&history, &versions.from)?;
+    let to = find_revision(&history, &versions.to)?;
+    let (from_id, to_id) = (version_id(from)?, version_id(to)?);
+
+    progress.advance_stage(ApiStage::Searching, "두 버전의 조문 요청 중");
+    let (old, new) = tokio::try_join!(client.get_detail(from_id), client.get_detail(to_id))?;
+
+    progress.advance_stage(ApiStage::Parsing, "조문 비교 중");
+    let version = |entry: &HistoryEntry, id: &str| Version {
+        id: id.to_string(),
+        date: Some(entry.revision_date.clone()),
+        kind: Some(entry.revision_type.clone()),
+    };
+    let diff = LawDiff::between(&old, &new, version(from, from_id), version(to, to_id));
+    progress.complete_success("법령 신구 비교 완료");
+
+    let text = match format {
+        _ if versions.unified => diff.render_unified(),
+        OutputFormat::Table => diff.render_terminal(),
+        OutputFormat::Json => serde_json::to_string_pretty(&diff)?,
+        OutputFormat::Yaml => output::formatter::to_yaml(&diff)?,
+        OutputFormat::Markdown => diff.render_markdown(),
+        OutputFormat::Html | OutputFormat::HtmlSimple => diff.render_html(),
+        other => {
+            let name = other
+                .to_possible_value()
+                .map(|value| value.get_name().to_string())
+                .unwrap_or_default();
+            return Err(WarpError::InvalidInput(format!(
+                "law diff는 {} 형식을 지원하지 않습니다 (table, json, yaml, markdown, html 또는 --unified)",
+                name
+            )));
+        }
+    };
+    println!("{}", text);
+    Ok(())
+}
+
+/// ID that fetches the text of a revision
+fn version_id(entry: &HistoryEntry) -> Result<&str> {
+    entry.version_id.as_deref().ok_or_else(|| {
+        WarpError::InvalidInput(format!(
+            "{} {} 개정의 버전 ID가 연혁에 없어 본문을 가져올 수 없습니다",
+            entry.revision_date, entry.revision_type
+        ))
+    })
+}
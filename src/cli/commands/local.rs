@@ -0,0 +1,86 @@
+use crate::cli::args::{LocalArgs, LocalCommand};
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::index::{ArticleHit, SNIPPET_END, SNIPPET_START};
+use colored::*;
+
+/// Execute local (offline) search commands
+pub async fn execute(args: LocalArgs, format: OutputFormat) -> Result<()> {
+    let config = Config::load()?;
+
+    match args.command {
+        LocalCommand::Search { query, limit } => {
+            let index = super::index::open_index(&config).await?;
+            let hits = index.search(&query, limit).await?;
+
+            if matches!(format, OutputFormat::Json) {
+                println!("{}", serde_json::to_string_pretty(&hits)?);
+            } else {
+                print_hits(&query, &hits);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Print article-level hits with highlighted snippets
+fn print_hits(query: &str, hits: &[ArticleHit]) {
+    if hits.is_empty() {
+        println!("'{}'에 대한 로컬 검색 결과가 없습니다.", query);
+        println!(
+            "{}",
+            "'warp index status'로 색인 상태를 확인하거나 'warp index build'로 색인을 갱신하세요."
+                .dimmed()
+        );
+        return;
+    }
+
+    println!(
+        "{} ({}건)\n",
+        format!("로컬 검색: {}", query).bold().cyan(),
+        hits.len()
+    );
+
+    for (i, hit) in hits.iter().enumerate() {
+        let mut heading = hit.law_name.clone();
+        if !hit.article_number.is_empty() {
+            heading.push(' ');
+            heading.push_str(&hit.article_number);
+        }
+        if let Some(title) = &hit.article_title {
+            heading.push_str(&format!("({})", title));
+        }
+
+        println!(
+            "{}. {} {}",
+            i + 1,
+            heading.bold(),
+            format!("[{}:{}]", hit.api_type.to_uppercase(), hit.doc_id).dimmed()
+        );
+        println!("   {}\n", highlight(&hit.snippet));
+    }
+}
+
+/// Replace snippet markers with terminal highlighting
+fn highlight(snippet: &str) -> String {
+    let mut out = String::new();
+    let mut rest = snippet.replace('\n', " ");
+
+    while let Some(start) = rest.find(SNIPPET_START) {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + SNIPPET_START.len()..];
+        match after.find(SNIPPET_END) {
+            Some(end) => {
+                out.push_str(&after[..end].yellow().bold().to_string());
+                rest = after[end + SNIPPET_END.len()..].to_string();
+            }
+            None => {
+                rest = after.to_string();
+                break;
+            }
+        }
+    }
+    out.push_str(&rest);
+    out
+}
@@ -0,0 +1,14 @@
+use crate::cli::args::McpArgs;
+use crate::cli::commands::search::parse_source;
+use crate::cli::session::Session;
+use crate::config::Config;
+use crate::error::Result;
+use crate::mcp::{self, McpServer};
+
+/// Execute the MCP server on stdin and stdout
+pub async fn execute(args: McpArgs, no_cache: bool, offline: bool) -> Result<()> {
+    let config = Config::load()?;
+    let api_types = parse_source(&args.source);
+    let session = Session::open(&api_types, args.size, &config, no_cache, offline).await?;
+    mcp::run(McpServer::new(session)).await
+}
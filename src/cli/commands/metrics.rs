@@ -1,7 +1,8 @@
 use crate::cli::args::{MetricsArgs, MetricsCommand};
 use crate::cli::OutputFormat;
 use crate::error::{Result, WarpError};
-use crate::metrics::{get_global_metrics, MetricsWindow, PerformanceDashboard};
+use crate::metrics::{get_global_metrics, MetricsSnapshot, MetricsWindow, PerformanceDashboard};
+use crate::output::formatter::{to_toml, to_yaml};
 use std::io::{self, Write};
 use std::sync::Arc;
 use std::time::Duration;
@@ -445,7 +446,6 @@ async fn execute_report_command(
     format: &str,
 ) -> Result<()> {
     use chrono::prelude::*;
-    use serde_json::json;
 
     let snapshot = collector.get_snapshot().await;
     let timestamp = Local::now();
@@ -461,87 +461,16 @@ async fn execute_report_command(
         _ => None,
     };
 
-    match format.to_lowercase().as_str() {
-        "json" => {
-            // Enhanced JSON format with proper structure
-            let mut operations_json = serde_json::Map::new();
-
-            for (op_name, metrics) in &snapshot.operations {
-                let operation_data = json!({
-                    "total_requests": metrics.total_requests,
-                    "successful_requests": metrics.successful_requests,
-                    "failed_requests": metrics.failed_requests,
-                    "success_rate_percent": metrics.success_rate(),
-                    "error_rate_percent": metrics.error_rate(),
-                    "response_times": {
-                        "avg_ms": metrics.avg_duration.as_millis(),
-                        "min_ms": metrics.min_duration.as_millis(),
-                        "max_ms": metrics.max_duration.as_millis(),
-                        "p50_ms": metrics.p50_duration.as_millis(),
-                        "p95_ms": metrics.p95_duration.as_millis(),
-                        "p99_ms": metrics.p99_duration.as_millis()
-                    }
-                });
-                operations_json.insert(op_name.clone(), operation_data);
-            }
-
-            let mut cache_json = serde_json::Map::new();
-            for (api_name, cache_metrics) in &snapshot.cache {
-                let cache_data = json!({
-                    "hits": cache_metrics.hits,
-                    "misses": cache_metrics.misses,
-                    "hit_rate_percent": cache_metrics.hit_rate(),
-                    "storage_size_bytes": cache_metrics.storage_size,
-                    "entry_count": cache_metrics.entry_count
-                });
-                cache_json.insert(api_name.clone(), cache_data);
-            }
-
-            let mut pools_json = serde_json::Map::new();
-            for (pool_name, pool_metrics) in &snapshot.connection_pools {
-                let pool_data = json!({
-                    "active_connections": pool_metrics.active_connections,
-                    "idle_connections": pool_metrics.idle_connections,
-                    "total_connections": pool_metrics.total_connections,
-                    "utilization_percent": pool_metrics.utilization(),
-                    "connection_timeouts": pool_metrics.connection_timeouts,
-                    "timeout_rate_percent": pool_metrics.timeout_rate()
-                });
-                pools_json.insert(pool_name.clone(), pool_data);
-            }
-
-            // Calculate summary values
-            let total_requests: u64 = snapshot.operations.values().map(|m| m.total_requests).sum();
-            let total_successes: u64 = snapshot
-                .operations
-                .values()
-                .map(|m| m.successful_requests)
-                .sum();
-            let overall_success_rate = if total_requests > 0 {
-                (total_successes as f64 / total_requests as f64) * 100.0
-            } else {
-                0.0
+    let format = format.to_lowercase();
+    match format.as_str() {
+        "json" | "yaml" | "yml" | "toml" => {
+            let report = structured_report(&snapshot, &timestamp, date_range.as_ref(), &format);
+            let output = match format.as_str() {
+                "json" => serde_json::to_string_pretty(&report)?,
+                "toml" => to_toml(&report)?,
+                _ => to_yaml(&report)?,
             };
-
-            let report = json!({
-                "metadata": {
-                    "generated_at": timestamp.to_rfc3339(),
-                    "report_format": "json",
-                    "date_range": date_range,
-                    "uptime_seconds": snapshot.uptime.as_secs(),
-                    "memory_usage_bytes": snapshot.memory_usage
-                },
-                "summary": {
-                    "total_operations": snapshot.operations.len(),
-                    "total_requests": total_requests,
-                    "overall_success_rate": overall_success_rate
-                },
-                "operations": operations_json,
-                "cache": cache_json,
-                "connection_pools": pools_json
-            });
-
-            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+            println!("{}", output);
         }
 
         "csv" => {
@@ -784,6 +713,98 @@ async fn execute_report_command(
     Ok(())
 }
 
+/// Report as a structured value shared by the JSON, YAML and TOML formats
+pub(crate) fn structured_report(
+    snapshot: &MetricsSnapshot,
+    timestamp: &chrono::DateTime<chrono::Local>,
+    date_range: Option<&(String, String)>,
+    report_format: &str,
+) -> serde_json::Value {
+    use serde_json::json;
+
+    let mut operations_json = serde_json::Map::new();
+
+    for (op_name, metrics) in &snapshot.operations {
+        let operation_data = json!({
+            "total_requests": metrics.total_requests,
+            "successful_requests": metrics.successful_requests,
+            "failed_requests": metrics.failed_requests,
+            "success_rate_percent": metrics.success_rate(),
+            "error_rate_percent": metrics.error_rate(),
+            "response_times": {
+                "avg_ms": metrics.avg_duration.as_millis(),
+                "min_ms": metrics.min_duration.as_millis(),
+                "max_ms": metrics.max_duration.as_millis(),
+                "p50_ms": metrics.p50_duration.as_millis(),
+                "p95_ms": metrics.p95_duration.as_millis(),
+                "p99_ms": metrics.p99_duration.as_millis()
+            }
+        });
+        operations_json.insert(op_name.clone(), operation_data);
+    }
+
+    let mut cache_json = serde_json::Map::new();
+    for (api_name, cache_metrics) in &snapshot.cache {
+        let cache_data = json!({
+            "hits": cache_metrics.hits,
+            "misses": cache_metrics.misses,
+            "hit_rate_percent": cache_metrics.hit_rate(),
+            "storage_size_bytes": cache_metrics.storage_size,
+            "entry_count": cache_metrics.entry_count
+        });
+        cache_json.insert(api_name.clone(), cache_data);
+    }
+
+    let mut pools_json = serde_json::Map::new();
+    for (pool_name, pool_metrics) in &snapshot.connection_pools {
+        let pool_data = json!({
+            "active_connections": pool_metrics.active_connections,
+            "idle_connections": pool_metrics.idle_connections,
+            "total_connections": pool_metrics.total_connections,
+            "utilization_percent": pool_metrics.utilization(),
+            "connection_timeouts": pool_metrics.connection_timeouts,
+            "timeout_rate_percent": pool_metrics.timeout_rate()
+        });
+        pools_json.insert(pool_name.clone(), pool_data);
+    }
+
+    // Calculate summary values
+    let total_requests: u64 = snapshot.operations.values().map(|m| m.total_requests).sum();
+    let total_successes: u64 = snapshot
+        .operations
+        .values()
+        .map(|m| m.successful_requests)
+        .sum();
+    let overall_success_rate = if total_requests > 0 {
+        (total_successes as f64 / total_requests as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let mut metadata = json!({
+        "generated_at": timestamp.to_rfc3339(),
+        "report_format": report_format,
+        "uptime_seconds": snapshot.uptime.as_secs(),
+        "memory_usage_bytes": snapshot.memory_usage
+    });
+    // TOML has no null, so an absent range is left out rather than set to null
+    if let Some(range) = date_range {
+        metadata["date_range"] = json!(range);
+    }
+
+    json!({
+        "metadata": metadata,
+        "summary": {
+            "total_operations": snapshot.operations.len(),
+            "total_requests": total_requests,
+            "overall_success_rate": overall_success_rate
+        },
+        "operations": operations_json,
+        "cache": cache_json,
+        "connection_pools": pools_json
+    })
+}
+
 async fn execute_reset_command(
     collector: Arc<crate::metrics::MetricsCollector>,
     force: bool,
@@ -962,4 +983,25 @@ mod tests {
 
         assert!(parse_duration("invalid").is_err());
     }
+
+    #[tokio::test]
+    async fn test_structured_report_serializes_to_yaml_and_toml() {
+        let collector = crate::metrics::MetricsCollector::new();
+        collector.record_request_success("nlic_search", Duration::from_millis(120));
+        collector.record_cache_hit("nlic");
+        let snapshot = collector.get_snapshot().await;
+        let timestamp = chrono::Local::now();
+
+        let report = structured_report(&snapshot, &timestamp, None, "toml");
+        let parsed: toml::Value = toml::from_str(&to_toml(&report).unwrap()).unwrap();
+        assert_eq!(parsed["metadata"]["report_format"].as_str(), Some("toml"));
+        assert!(parsed["metadata"].get("date_range").is_none());
+        assert_eq!(parsed["summary"]["total_requests"].as_integer(), Some(1));
+
+        let range = ("2025-01-01".to_string(), "2025-01-31".to_string());
+        let report = structured_report(&snapshot, &timestamp, Some(&range), "yaml");
+        let yaml = to_yaml(&report).unwrap();
+        assert!(yaml.contains("nlic_search"));
+        assert!(yaml.contains("2025-01-31"));
+    }
 }
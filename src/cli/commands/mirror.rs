@@ -0,0 +1,345 @@
+use colored::*;
+use futures::StreamExt;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::api::client::LegalApiClient;
+use crate::api::parallel::{ParallelConfig, ParallelExecutor};
+use crate::api::streaming::{SearchResultStream, StreamConfig};
+use crate::api::types::UnifiedSearchRequest;
+use crate::api::ApiType;
+use crate::cli::args::MirrorArgs;
+use crate::cli::commands::search::{create_clients, date_bound};
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::error::{Result, WarpError};
+use crate::mirror::{Checkpoint, Fetched, MirrorSpec, CHECKPOINT_FILE};
+
+/// Largest page the APIs accept
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Wait after the API reports its rate limit
+const RATE_LIMIT_PAUSE: Duration = Duration::from_secs(60);
+
+/// Rate-limit pauses in a row before giving up (progress is kept)
+const MAX_RATE_LIMIT_PAUSES: u32 = 5;
+
+/// Outcome of a mirror run
+#[derive(Debug, Default, Serialize)]
+struct MirrorReport {
+    dir: PathBuf,
+    source: String,
+    query: String,
+    total_count: Option<u32>,
+    /// Pages walked by this run
+    pages: u32,
+    /// Documents written by this run
+    written: usize,
+    /// Documents already in the mirror
+    skipped: usize,
+    /// Documents that failed and will be retried by the next run
+    failed: Vec<String>,
+}
+
+/// Execute the mirror command
+pub async fn execute(args: MirrorArgs, format: OutputFormat, offline: bool) -> Result<()> {
+    let api_type = args
+        .api
+        .parse::<ApiType>()
+        .ok()
+        .filter(|t| *t != ApiType::All)
+        .ok_or_else(|| WarpError::InvalidInput(format!("알 수 없는 소스: {}", args.api)))?;
+    if !(1..=MAX_PAGE_SIZE).contains(&args.page_size) {
+        return Err(WarpError::InvalidInput(format!(
+            "--page-size는 1에서 {} 사이여야 합니다",
+            MAX_PAGE_SIZE
+        )));
+    }
+    let spec = MirrorSpec {
+        api_type,
+        query: args.query.trim().to_string(),
+        date_from: args
+            .from
+            .as_deref()
+            .map(|d| date_bound(d, false))
+            .transpose()?,
+        date_to: args
+            .to
+            .as_deref()
+            .map(|d| date_bound(d, true))
+            .transpose()?,
+        page_size: args.page_size,
+    };
+
+    let config = Config::load()?;
+    // Every document is fetched once and kept on disk; caching it too would only fill the cache
+    let client = create_clients(&[api_type], &config, true, offline)
+        .await?
+        .into_iter()
+        .next()
+        .map(|(_, client)| client)
+        .ok_or(WarpError::NoApiKey)?;
+
+    let checkpoint = Checkpoint::open(args.dir.join(CHECKPOINT_FILE)).await?;
+    let progress = checkpoint.begin(&spec).await?;
+    let quiet = matches!(format, OutputFormat::Json);
+    if progress.resumed && !quiet {
+        eprintln!(
+            "{} {}페이지부터 이어서 받습니다 (저장됨 {}, 재시도 {})",
+            "↻".cyan(),
+            progress.next_page,
+            progress.fetched,
+            progress.failed.len()
+        );
+    }
+
+    let mirror = Mirror {
+        client,
+        executor: ParallelExecutor::new(ParallelConfig {
+            max_concurrent: args.concurrency.max(1),
+            ..Default::default()
+        }),
+        checkpoint,
+        dir: args.dir.clone(),
+    };
+    let mut report = MirrorReport {
+        dir: args.dir.clone(),
+        source: api_type.as_str().to_string(),
+        query: spec.query.clone(),
+        total_count: progress.total_count,
+        ..Default::default()
+    };
+    let mut pauses = 0;
+
+    // Documents that failed last time come first
+    if !progress.failed.is_empty() {
+        let batch = mirror.fetch(progress.failed, None).await?;
+        report.add(&batch);
+        if batch.rate_limited {
+            pause(&mut pauses, quiet).await?;
+        }
+    }
+
+    let request = UnifiedSearchRequest {
+        query: spec.query.clone(),
+        page_size: spec.page_size,
+        date_from: spec.date_from.clone(),
+        date_to: spec.date_to.clone(),
+        ..Default::default()
+    };
+    let stream_config = StreamConfig {
+        page_size: spec.page_size,
+        page_delay: Duration::from_millis(args.delay),
+        ..Default::default()
+    };
+    let mut page_no = progress.next_page;
+
+    'walk: loop {
+        let mut pages = Box::pin(
+            SearchResultStream::new(
+                mirror.client.clone(),
+                request.clone(),
+                stream_config.clone(),
+            )
+            .starting_at(page_no)
+            .into_page_stream(),
+        );
+        while let Some(page) = pages.next().await {
+            let response = match page {
+                Ok(response) => response,
+                Err(WarpError::RateLimit) => {
+                    pause(&mut pauses, quiet).await?;
+                    continue 'walk;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let ids = response.items.iter().map(|item| item.id.clone()).collect();
+            let batch = mirror
+                .fetch(ids, Some((page_no, response.total_count)))
+                .await?;
+            report.add(&batch);
+            report.pages += 1;
+            report.total_count = Some(response.total_count);
+            if !quiet {
+                eprintln!(
+                    "  {} {}/{}: 저장 {}, 건너뜀 {}, 실패 {}",
+                    "페이지".dimmed(),
+                    page_no,
+                    response.total_count.div_ceil(spec.page_size).max(1),
+                    batch.written.len(),
+                    batch.skipped,
+                    batch.failed.len()
+                );
+            }
+            page_no += 1;
+
+            if batch.rate_limited {
+                pause(&mut pauses, quiet).await?;
+            } else {
+                pauses = 0;
+            }
+        }
+        break;
+    }
+    mirror.checkpoint.finish().await?;
+
+    print_report(&report, format)
+}
+
+/// One page (or the retried failures) of documents
+#[derive(Debug, Default)]
+struct Batch {
+    written: Vec<Fetched>,
+    skipped: usize,
+    failed: Vec<(String, String)>,
+    rate_limited: bool,
+}
+
+impl MirrorReport {
+    fn add(&mut self, batch: &Batch) {
+        self.written += batch.written.len();
+        self.skipped += batch.skipped;
+        let written: HashSet<&str> = batch.written.iter().map(|doc| doc.id.as_str()).collect();
+        self.failed.retain(|id| !written.contains(id.as_str()));
+        for (id, _) in &batch.failed {
+            if !self.failed.contains(id) {
+                self.failed.push(id.clone());
+            }
+        }
+    }
+}
+
+struct Mirror {
+    client: Arc<dyn LegalApiClient>,
+    executor: ParallelExecutor,
+    checkpoint: Checkpoint,
+    dir: PathBuf,
+}
+
+impl Mirror {
+    /// Fetch and write the documents not yet in the mirror, then checkpoint `page`
+    async fn fetch(&self, ids: Vec<String>, page: Option<(u32, u32)>) -> Result<Batch> {
+        let mut seen = HashSet::new();
+        let ids: Vec<String> = ids
+            .into_iter()
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+        let existing = self.checkpoint.fetched(ids.clone()).await?;
+        let wanted: Vec<String> = ids
+            .into_iter()
+            .filter(|id| !existing.contains(id))
+            .collect();
+
+        let mut batch = Batch {
+            skipped: existing.len(),
+            ..Default::default()
+        };
+        for (id, result) in self
+            .executor
+            .get_details_parallel(self.client.clone(), wanted)
+            .await?
+        {
+            match result {
+                Ok(detail) => {
+                    let file = document_file(&id);
+                    write_atomic(&self.dir.join(&file), &serde_json::to_vec_pretty(&detail)?)?;
+                    batch.written.push(Fetched {
+                        id,
+                        title: detail.law_name,
+                        file,
+                    });
+                }
+                Err(e) => {
+                    batch.rate_limited |= matches!(e, WarpError::RateLimit);
+                    batch.failed.push((id, e.to_string()));
+                }
+            }
+        }
+
+        self.checkpoint
+            .record(page, batch.written.clone(), batch.failed.clone())
+            .await?;
+        Ok(batch)
+    }
+}
+
+/// Wait out a rate limit, or give up after too many in a row
+async fn pause(pauses: &mut u32, quiet: bool) -> Result<()> {
+    *pauses += 1;
+    if *pauses > MAX_RATE_LIMIT_PAUSES {
+        return Err(WarpError::RateLimit);
+    }
+    if !quiet {
+        eprintln!(
+            "{} 요청 한도에 걸려 {}초 쉽니다 ({}/{})",
+            "⏳".yellow(),
+            RATE_LIMIT_PAUSE.as_secs(),
+            pauses,
+            MAX_RATE_LIMIT_PAUSES
+        );
+    }
+    tokio::time::sleep(RATE_LIMIT_PAUSE).await;
+    Ok(())
+}
+
+/// JSON file of a document, named after its ID
+fn document_file(id: &str) -> String {
+    let stem: String = id
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}.json", stem)
+}
+
+/// Write through a temporary file so an interruption never leaves half a document
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+fn print_report(report: &MirrorReport, format: OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(report)?);
+        return Ok(());
+    }
+
+    println!("{}", "미러 완료 (Mirror finished)".bold().cyan());
+    println!("  {} {}", "위치:".bold(), report.dir.display());
+    if let Some(total) = report.total_count {
+        println!("  {} {}", "검색 결과:".bold(), total);
+    }
+    println!("  {} {}", "페이지:".bold(), report.pages);
+    println!("  {} {}", "새로 저장:".bold(), report.written);
+    println!("  {} {}", "이미 있음:".bold(), report.skipped);
+    if !report.failed.is_empty() {
+        println!(
+            "  {} {} — 다시 실행하면 재시도합니다",
+            "실패:".bold().yellow(),
+            report.failed.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_file_is_safe() {
+        assert_eq!(document_file("228541"), "228541.json");
+        assert_eq!(document_file("../a b"), "___a_b.json");
+    }
+}
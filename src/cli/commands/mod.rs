@@ -1,12 +1,23 @@
 pub mod admrule;
+pub mod batch;
 pub mod cache;
 pub mod config;
+pub mod export;
 pub mod filter;
 pub mod help;
+pub mod index;
 pub mod interpretation;
 pub mod law;
+pub mod local;
+pub mod mcp;
 pub mod metrics;
+pub mod mirror;
 pub mod ordinance;
 pub mod precedent;
 pub mod search;
+pub mod serve;
+pub mod shell;
+pub mod stream;
+pub mod tui;
 pub mod version;
+pub mod watch;
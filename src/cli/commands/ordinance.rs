@@ -1,28 +1,34 @@
 use crate::api::client::{ClientConfig, LegalApiClient};
-use crate::api::types::{ResponseType, UnifiedSearchRequest};
+use crate::api::types::{ResponseType, SearchScope, UnifiedSearchRequest};
 use crate::api::{ApiClientFactory, ApiType};
 use crate::cache::CacheStore;
-use crate::cli::args::{OrdinanceArgs, OrdinanceCommand};
-use crate::cli::OutputFormat;
+use crate::cli::args::{OrdinanceArgs, OrdinanceCommand, PagingArgs};
+use crate::cli::commands::stream;
 use crate::config::Config;
 use crate::error::{Result, WarpError};
 use crate::output;
+use crate::output::Formatter;
 use std::sync::Arc;
 
 /// Execute ordinance command (자치법규)
 pub async fn execute(
     args: OrdinanceArgs,
-    format: OutputFormat,
+    formatter: Formatter,
     _quiet: bool,
     _verbose: bool,
     no_cache: bool,
+    offline: bool,
 ) -> Result<()> {
     // Load configuration
     let config = Config::load()?;
-    let api_key = config.get_elis_api_key().ok_or(WarpError::NoApiKey)?;
+    let offline = offline || config.cache.offline;
+    let api_key = config
+        .get_elis_api_key()
+        .or_else(|| offline.then(String::new))
+        .ok_or(WarpError::NoApiKey)?;
 
-    // Create cache store if cache is enabled and not bypassed
-    let cache = if config.cache.enabled && !no_cache {
+    // Create cache store if cache is enabled and not bypassed; offline mode always needs it
+    let cache = if (config.cache.enabled && !no_cache) || offline {
         let cache_config = config.cache.to_cache_config();
         Some(Arc::new(CacheStore::new(cache_config).await?))
     } else {
@@ -34,10 +40,12 @@ pub async fn execute(
         api_key,
         cache,
         bypass_cache: no_cache,
+        offline,
         ..Default::default()
     };
 
-    let client = ApiClientFactory::create(ApiType::Elis, client_config)?;
+    let client: Arc<dyn LegalApiClient> =
+        Arc::from(ApiClientFactory::create(ApiType::Elis, client_config)?);
 
     // Extract common args before match
     let region = args.region.clone();
@@ -45,23 +53,34 @@ pub async fn execute(
 
     // Handle direct query or subcommand
     match args.command {
-        Some(OrdinanceCommand::Search { query, page, size }) => {
-            search_ordinances(client.as_ref(), query, page, size, region, law_type, format).await
+        Some(OrdinanceCommand::Search {
+            query,
+            page,
+            size,
+            scope,
+            paging,
+        }) => {
+            search_ordinances(
+                client, query, page, size, scope, region, law_type, paging, &formatter,
+            )
+            .await
         }
         Some(OrdinanceCommand::Detail { id }) => {
-            get_ordinance_detail(client.as_ref(), id, format).await
+            get_ordinance_detail(client.as_ref(), id, &formatter).await
         }
         None => {
             // Direct query without subcommand
             if let Some(query) = args.query {
                 search_ordinances(
-                    client.as_ref(),
+                    client,
                     query,
                     args.page,
                     args.size,
+                    args.scope,
                     region,
                     law_type,
-                    format,
+                    args.paging,
+                    &formatter,
                 )
                 .await
             } else {
@@ -71,14 +90,17 @@ pub async fn execute(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn search_ordinances(
-    client: &dyn LegalApiClient,
+    client: Arc<dyn LegalApiClient>,
     query: String,
     page: u32,
     size: u32,
+    scope: SearchScope,
     region: Option<String>,
     law_type: Option<String>,
-    format: OutputFormat,
+    paging: PagingArgs,
+    formatter: &Formatter,
 ) -> Result<()> {
     if query.trim().is_empty() {
         return Err(WarpError::InvalidInput(
@@ -91,11 +113,16 @@ async fn search_ordinances(
         page_no: page,
         page_size: size,
         response_type: ResponseType::Json,
+        search_scope: scope,
         region,
         law_type,
         ..Default::default()
     };
 
+    if paging.is_enabled() {
+        return stream::stream_search(client, request, &paging, formatter).await;
+    }
+
     let response = client.search(request).await?;
 
     if response.items.is_empty() {
@@ -103,8 +130,7 @@ async fn search_ordinances(
         return Ok(());
     }
 
-    let output = output::format_search_response(&response, format)?;
-    println!("{}", output);
+    output::print_search_response(&response, formatter)?;
 
     Ok(())
 }
@@ -112,10 +138,9 @@ async fn search_ordinances(
 async fn get_ordinance_detail(
     client: &dyn LegalApiClient,
     id: String,
-    format: OutputFormat,
+    formatter: &Formatter,
 ) -> Result<()> {
     let detail = client.get_detail(&id).await?;
-    let output = output::format_law_detail(&detail, format)?;
-    println!("{}", output);
+    output::print_law_detail(&detail, ApiType::Elis, formatter)?;
     Ok(())
 }
@@ -400,8 +400,7 @@ async fn display_parallel_results(response: &SearchResponse, format: OutputForma
     println!();
 
     // Format and display results
-    let output = output::format_search_response(response, format)?;
-    println!("{}", output);
+    output::print_search_response(response, format)?;
 
     // Show optimization info
     println!();
@@ -1,12 +1,13 @@
 use crate::api::client::{ClientConfig, LegalApiClient};
-use crate::api::types::{ResponseType, UnifiedSearchRequest};
+use crate::api::types::{ResponseType, SearchScope, UnifiedSearchRequest};
 use crate::api::{ApiClientFactory, ApiType};
 use crate::cache::CacheStore;
-use crate::cli::args::{PrecedentArgs, PrecedentCommand};
-use crate::cli::OutputFormat;
+use crate::cli::args::{PagingArgs, PrecedentArgs, PrecedentCommand};
+use crate::cli::commands::stream;
 use crate::config::Config;
 use crate::error::{Result, WarpError};
 use crate::output;
+use crate::output::Formatter;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -15,27 +16,34 @@ struct SearchParams {
     query: String,
     page: u32,
     size: u32,
+    scope: SearchScope,
     court: Option<String>,
     case_type: Option<String>,
     date_from: Option<String>,
     date_to: Option<String>,
-    format: OutputFormat,
+    paging: PagingArgs,
+    formatter: Formatter,
 }
 
 /// Execute precedent command (판례)
 pub async fn execute(
     args: PrecedentArgs,
-    format: OutputFormat,
+    formatter: Formatter,
     _quiet: bool,
     _verbose: bool,
     no_cache: bool,
+    offline: bool,
 ) -> Result<()> {
     // Load configuration
     let config = Config::load()?;
-    let api_key = config.get_prec_api_key().ok_or(WarpError::NoApiKey)?;
+    let offline = offline || config.cache.offline;
+    let api_key = config
+        .get_prec_api_key()
+        .or_else(|| offline.then(String::new))
+        .ok_or(WarpError::NoApiKey)?;
 
-    // Create cache store if cache is enabled and not bypassed
-    let cache = if config.cache.enabled && !no_cache {
+    // Create cache store if cache is enabled and not bypassed; offline mode always needs it
+    let cache = if (config.cache.enabled && !no_cache) || offline {
         let cache_config = config.cache.to_cache_config();
         Some(Arc::new(CacheStore::new(cache_config).await?))
     } else {
@@ -47,10 +55,12 @@ pub async fn execute(
         api_key,
         cache,
         bypass_cache: no_cache,
+        offline,
         ..Default::default()
     };
 
-    let client = ApiClientFactory::create(ApiType::Prec, client_config)?;
+    let client: Arc<dyn LegalApiClient> =
+        Arc::from(ApiClientFactory::create(ApiType::Prec, client_config)?);
 
     // Extract common args
     let court = args.court.clone();
@@ -60,21 +70,29 @@ pub async fn execute(
 
     // Handle direct query or subcommand
     match args.command {
-        Some(PrecedentCommand::Search { query, page, size }) => {
+        Some(PrecedentCommand::Search {
+            query,
+            page,
+            size,
+            scope,
+            paging,
+        }) => {
             let params = SearchParams {
                 query,
                 page,
                 size,
+                scope,
                 court,
                 case_type,
                 date_from,
                 date_to,
-                format,
+                paging,
+                formatter,
             };
-            search_precedents(client.as_ref(), params).await
+            search_precedents(client, params).await
         }
         Some(PrecedentCommand::Detail { id }) => {
-            get_precedent_detail(client.as_ref(), id, format).await
+            get_precedent_detail(client.as_ref(), id, &formatter).await
         }
         None => {
             // Direct query without subcommand
@@ -83,13 +101,15 @@ pub async fn execute(
                     query,
                     page: args.page,
                     size: args.size,
+                    scope: args.scope,
                     court,
                     case_type,
                     date_from,
                     date_to,
-                    format,
+                    paging: args.paging,
+                    formatter,
                 };
-                search_precedents(client.as_ref(), params).await
+                search_precedents(client, params).await
             } else {
                 Err(WarpError::InvalidInput(
                     "No search query provided. Use 'warp precedent <query>' or 'warp precedent search <query>'".to_string()
@@ -99,7 +119,7 @@ pub async fn execute(
     }
 }
 
-async fn search_precedents(client: &dyn LegalApiClient, params: SearchParams) -> Result<()> {
+async fn search_precedents(client: Arc<dyn LegalApiClient>, params: SearchParams) -> Result<()> {
     if params.query.trim().is_empty() {
         return Err(WarpError::InvalidInput(
             "Search query cannot be empty".to_string(),
@@ -119,12 +139,17 @@ async fn search_precedents(client: &dyn LegalApiClient, params: SearchParams) ->
         page_no: params.page,
         page_size: params.size,
         response_type: ResponseType::Json,
+        search_scope: params.scope,
         date_from: params.date_from,
         date_to: params.date_to,
         extras,
         ..Default::default()
     };
 
+    if params.paging.is_enabled() {
+        return stream::stream_search(client, request, &params.paging, &params.formatter).await;
+    }
+
     let response = client.search(request).await?;
 
     if response.items.is_empty() {
@@ -132,8 +157,7 @@ async fn search_precedents(client: &dyn LegalApiClient, params: SearchParams) ->
         return Ok(());
     }
 
-    let output = output::format_search_response(&response, params.format)?;
-    println!("{}", output);
+    output::print_search_response(&response, &params.formatter)?;
 
     Ok(())
 }
@@ -141,10 +165,9 @@ async fn search_precedents(client: &dyn LegalApiClient, params: SearchParams) ->
 async fn get_precedent_detail(
     client: &dyn LegalApiClient,
     id: String,
-    format: OutputFormat,
+    formatter: &Formatter,
 ) -> Result<()> {
     let detail = client.get_detail(&id).await?;
-    let output = output::format_law_detail(&detail, format)?;
-    println!("{}", output);
+    output::print_law_detail(&detail, ApiType::Prec, formatter)?;
     Ok(())
 }
@@ -1,26 +1,34 @@
 use crate::api::client::{ClientConfig, LegalApiClient};
-use crate::api::parallel::{ParallelConfig, ParallelExecutor};
+use crate::api::parallel::{fetch_to_depth, ParallelConfig, ParallelExecutor};
+use crate::api::streaming::ParallelSearchStream;
 use crate::api::types::{ResponseType, SearchResponse, UnifiedSearchRequest};
 use crate::api::{ApiClientFactory, ApiType};
 use crate::cache::{CacheConfig, CacheStore};
 use crate::cli::args::SearchArgs;
-use crate::cli::OutputFormat;
+use crate::cli::commands::{stream, tui};
+use crate::cli::session::Session;
 use crate::config::Config;
 use crate::error::{Result, WarpError};
 use crate::output;
+use crate::output::Formatter;
 use crate::progress::{messages, ApiStage, EnhancedApiProgress, ProgressManager};
+use crate::search::{dedup_items, dedup_key, Bm25Scorer, RankFusion};
 use chrono::Utc;
 use futures::future::join_all;
+use futures::stream::{Stream, StreamExt};
+use regex::Regex;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
 /// Execute unified search command across multiple APIs
 pub async fn execute(
     args: SearchArgs,
-    format: OutputFormat,
+    formatter: Formatter,
     quiet: bool,
     verbose: bool,
     no_cache: bool,
+    offline: bool,
 ) -> Result<()> {
     // Create progress manager
     let progress_manager = Arc::new(ProgressManager::new(quiet, verbose));
@@ -32,6 +40,7 @@ pub async fn execute(
 
     // Load configuration
     let config = Config::load()?;
+    let offline = offline || config.cache.offline;
 
     // Determine which APIs to search
     let api_types = if let Some(apis) = &args.apis {
@@ -40,14 +49,51 @@ pub async fn execute(
         parse_source(&args.source)
     };
 
+    if args.interactive {
+        // Keep --preset, --law-type, --from/--to and the other filters in the browser
+        let args = apply_filter_preset(args, &config)?;
+        let request = create_search_request(&args)?;
+        let session = Session::open(&api_types, args.size, &config, no_cache, offline)
+            .await?
+            .with_base_request(request.clone())
+            .with_filters(ClientFilters::new(&args));
+        return tui::browse(Some(request.query), session).await;
+    }
+
     // Check if parallel search is requested
-    if args.parallel && api_types.len() > 1 {
-        return execute_parallel_search(args, format, quiet, verbose, api_types, config).await;
+    if args.parallel && api_types.len() > 1 && !args.paging.is_enabled() {
+        return execute_parallel_search(
+            args, formatter, quiet, verbose, offline, api_types, config,
+        )
+        .await;
     }
 
     // Apply filter preset if specified and create search request
     let args = apply_filter_preset(args, &config)?;
-    let request = create_search_request(&args);
+    let request = create_search_request(&args)?;
+    let depth = merge_depth(&args);
+
+    let clients = create_clients(&api_types, &config, no_cache, offline).await?;
+    if clients.is_empty() {
+        return Err(WarpError::NoApiKey);
+    }
+
+    // Page through every source, streaming results in arrival order
+    if args.paging.is_enabled() {
+        if args.min_score.is_some() {
+            return Err(WarpError::InvalidInput(
+                "--min-score는 --all/--limit과 함께 쓸 수 없습니다 (점수는 모든 결과를 모은 뒤에 계산됩니다)"
+                    .to_string(),
+            ));
+        }
+        let mut config = stream::stream_config(&request, &args.paging);
+        // Filtered and duplicate items do not count toward --limit; the
+        // sources are paged lazily, so no page is fetched past the limit
+        config.max_items = 0;
+        let items = ParallelSearchStream::new(clients, request, config).merge_fair();
+        let items = filter_stream(items, &args);
+        return stream::write_items(items, &args.paging, &formatter, "통합검색").await;
+    }
 
     // Execute searches in parallel
     let mut tasks = Vec::new();
@@ -59,75 +105,42 @@ pub async fn execute(
         &format!("{}개 API에서 '{}' 검색 중", total_apis, args.query),
     );
 
-    for api_type in api_types.into_iter() {
-        let api_key = match api_type {
-            ApiType::Nlic => config.get_nlic_api_key(),
-            ApiType::Elis => config.get_elis_api_key(),
-            ApiType::Prec => config.get_prec_api_key(),
-            ApiType::Admrul => config.get_admrul_api_key(),
-            ApiType::Expc => config.get_expc_api_key(),
-            ApiType::All => continue, // Skip, this is handled by selecting all APIs
-        };
-
-        if let Some(api_key) = api_key {
-            // Create cache store if cache is enabled and not bypassed
-            let cache = if config.cache.enabled && !no_cache {
-                let cache_config = config.cache.to_cache_config();
-                Some(Arc::new(CacheStore::new(cache_config).await?))
-            } else {
-                None
-            };
-
-            let client_config = ClientConfig {
-                api_key,
-                cache,
-                bypass_cache: no_cache,
-                ..Default::default()
-            };
-
-            if let Ok(client) = ApiClientFactory::create(api_type, client_config) {
-                let req = request.clone();
-                let client: Arc<Box<dyn LegalApiClient>> = Arc::from(client);
-                let pm = progress_manager.clone();
-                let api_name = api_type.display_name().to_string();
-
-                tasks.push(tokio::spawn(async move {
-                    // Create enhanced progress for each API
-                    let mut api_progress = EnhancedApiProgress::new(pm.clone(), &api_name);
-
-                    // Stage 1: Connecting
-                    api_progress.advance_stage(ApiStage::Connecting, "API 서버 연결 중");
-
-                    // Stage 2: Searching
-                    api_progress.advance_stage(ApiStage::Searching, "검색 요청 전송 중");
-
-                    let result = client.search(req).await;
-
-                    // Stage 3: Parsing
-                    api_progress.advance_stage(ApiStage::Parsing, "응답 데이터 파싱 중");
-
-                    match &result {
-                        Ok(response) => {
-                            let completion_msg = messages::search_complete_with_time(
-                                &api_name,
-                                response.items.len(),
-                                api_progress.elapsed().as_millis() as u64,
-                            );
-                            api_progress.complete_success(&completion_msg);
-                        }
-                        Err(e) => {
-                            api_progress.complete_error(&format!("검색 실패: {}", e));
-                        }
-                    }
-
-                    (api_type, result)
-                }));
+    for (api_type, client) in clients {
+        let req = request.clone();
+        let pm = progress_manager.clone();
+        let api_name = api_type.display_name().to_string();
+
+        tasks.push(tokio::spawn(async move {
+            // Create enhanced progress for each API
+            let mut api_progress = EnhancedApiProgress::new(pm.clone(), &api_name);
+
+            // Stage 1: Connecting
+            api_progress.advance_stage(ApiStage::Connecting, "API 서버 연결 중");
+
+            // Stage 2: Searching
+            api_progress.advance_stage(ApiStage::Searching, "검색 요청 전송 중");
+
+            let result = fetch_to_depth(client.as_ref(), req, depth).await;
+
+            // Stage 3: Parsing
+            api_progress.advance_stage(ApiStage::Parsing, "응답 데이터 파싱 중");
+
+            match &result {
+                Ok(response) => {
+                    let completion_msg = messages::search_complete_with_time(
+                        &api_name,
+                        response.items.len(),
+                        api_progress.elapsed().as_millis() as u64,
+                    );
+                    api_progress.complete_success(&completion_msg);
+                }
+                Err(e) => {
+                    api_progress.complete_error(&format!("검색 실패: {}", e));
+                }
             }
-        }
-    }
 
-    if tasks.is_empty() {
-        return Err(WarpError::NoApiKey);
+            (api_type, result)
+        }));
     }
 
     // Collect results
@@ -182,11 +195,11 @@ pub async fn execute(
     }
 
     // Merge responses with post-processing
-    let merged_response = merge_responses_with_filtering(all_responses, &args);
+    let merged_response =
+        merge_responses_with_filtering(all_responses, &args, &config.rank_fusion());
 
     // Format and output
-    let output = output::format_search_response(&merged_response, format)?;
-    println!("{}", output);
+    output::print_search_response(&merged_response, &formatter)?;
 
     // Report any errors
     if !errors.is_empty() {
@@ -199,8 +212,57 @@ pub async fn execute(
     Ok(())
 }
 
+/// Create clients for the APIs that have a key configured (all of them offline)
+///
+/// `offline` is the `--offline` flag; the `cache.offline` setting turns
+/// offline mode on as well.
+pub(crate) async fn create_clients(
+    api_types: &[ApiType],
+    config: &Config,
+    no_cache: bool,
+    offline: bool,
+) -> Result<Vec<(ApiType, Arc<dyn LegalApiClient>)>> {
+    let offline = offline || config.cache.offline;
+
+    // Create cache store if cache is enabled and not bypassed; offline mode always needs it
+    let cache = if (config.cache.enabled && !no_cache) || offline {
+        let cache_config = config.cache.to_cache_config();
+        Some(Arc::new(CacheStore::new(cache_config).await?))
+    } else {
+        None
+    };
+
+    let mut clients = Vec::new();
+    for &api_type in api_types {
+        let api_key = match api_type {
+            ApiType::Nlic => config.get_nlic_api_key(),
+            ApiType::Elis => config.get_elis_api_key(),
+            ApiType::Prec => config.get_prec_api_key(),
+            ApiType::Admrul => config.get_admrul_api_key(),
+            ApiType::Expc => config.get_expc_api_key(),
+            ApiType::All => continue, // Skip, this is handled by selecting all APIs
+        };
+
+        if let Some(api_key) = api_key.or_else(|| offline.then(String::new)) {
+            let client_config = ClientConfig {
+                api_key,
+                cache: cache.clone(),
+                bypass_cache: no_cache,
+                offline,
+                ..Default::default()
+            };
+
+            if let Ok(client) = ApiClientFactory::create(api_type, client_config) {
+                clients.push((api_type, Arc::from(client)));
+            }
+        }
+    }
+
+    Ok(clients)
+}
+
 /// Parse source string to determine which APIs to search
-fn parse_source(source: &str) -> Vec<ApiType> {
+pub(crate) fn parse_source(source: &str) -> Vec<ApiType> {
     match source.to_lowercase().as_str() {
         "all" | "" => vec![
             ApiType::Nlic,
@@ -224,21 +286,81 @@ fn parse_source(source: &str) -> Vec<ApiType> {
     }
 }
 
+/// "2010" → "20100101" (or "20101231" for the end of a range); YYYYMMDD as is
+pub(crate) fn date_bound(value: &str, end: bool) -> Result<String> {
+    let digits: String = value.chars().filter(|c| *c != '-' && *c != '.').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(WarpError::InvalidInput(format!(
+            "날짜는 YYYY 또는 YYYYMMDD 형식이어야 합니다: {}",
+            value
+        )));
+    }
+    match digits.len() {
+        4 if end => Ok(format!("{}1231", digits)),
+        4 => Ok(format!("{}0101", digits)),
+        8 => Ok(digits),
+        _ => Err(WarpError::InvalidInput(format!(
+            "날짜는 YYYY 또는 YYYYMMDD 형식이어야 합니다: {}",
+            value
+        ))),
+    }
+}
+
+/// Number of top results every source must contribute to fill the requested page
+///
+/// `--page`/`--size` address the combined ranked list, so any single source
+/// may supply the whole window up to the end of the requested page.
+fn merge_depth(args: &SearchArgs) -> u32 {
+    args.page.max(1).saturating_mul(args.size.max(1))
+}
+
 /// Merge multiple search responses with advanced filtering and processing
+///
+/// Every response holds the top results of one source. They are scored,
+/// fused into one ranking, deduplicated, filtered and sorted as one list, and
+/// the page requested with `--page`/`--size` is cut from that list.
 fn merge_responses_with_filtering(
     responses: Vec<(ApiType, SearchResponse)>,
     args: &SearchArgs,
+    fusion: &RankFusion,
 ) -> SearchResponse {
     let mut all_items = Vec::new();
+    let mut cursors = Vec::new();
+    let mut source_total = 0u32;
+    let stale = responses.iter().any(|(_, response)| response.stale);
+
+    for (api_type, response) in responses {
+        source_total = source_total.saturating_add(response.total_count);
+        cursors.extend(response.cursors.into_iter().map(|mut cursor| {
+            cursor.source = api_type.display_name().to_string();
+            cursor
+        }));
 
-    for (api_type, mut response) in responses {
         // Add source info to each item
-        for item in &mut response.items {
+        for mut item in response.items {
             item.source = api_type.display_name().to_string();
+            all_items.push((api_type, item));
         }
-
-        all_items.extend(response.items);
     }
+    let fetched = all_items.len() as u32;
+
+    // Score every item with BM25 so --min-score compares results from
+    // different APIs on the same scale
+    let (api_types, mut scored): (Vec<_>, Vec<_>) = all_items.into_iter().unzip();
+    Bm25Scorer::default().score_items(&args.query, &mut scored);
+
+    // Rank each source's results by BM25, then interleave the sources by
+    // reciprocal-rank fusion so the configured source weights apply
+    let mut ranked: Vec<_> = api_types.into_iter().zip(scored).collect();
+    ranked.sort_by(|(_, a), (_, b)| b.score.unwrap_or(0.0).total_cmp(&a.score.unwrap_or(0.0)));
+    let ranked = fusion.fuse(ranked);
+
+    // Fold the same document returned by several sources or revisions
+    let all_items = if args.no_dedup {
+        ranked.into_iter().map(|(_, item)| item).collect()
+    } else {
+        dedup_items(ranked)
+    };
 
     // Apply client-side filters
     let filtered_items = apply_client_side_filters(all_items, args);
@@ -246,13 +368,29 @@ fn merge_responses_with_filtering(
     // Sort results based on specified order
     let sorted_items = sort_search_results(filtered_items, &args.sort);
 
+    // The combined total is exact once every source is exhausted; until then
+    // it discounts the duplicates and filtered items seen so far
+    let dropped = fetched.saturating_sub(sorted_items.len() as u32);
+    let total_count = source_total.saturating_sub(dropped);
+
+    let page_size = args.size.max(1);
+    let page_no = args.page.max(1);
+    let offset = (page_no - 1).saturating_mul(page_size) as usize;
+    let items = sorted_items
+        .into_iter()
+        .skip(offset)
+        .take(page_size as usize)
+        .collect();
+
     SearchResponse {
-        total_count: sorted_items.len() as u32, // Update count after filtering
-        page_no: 1,                             // Always 1 for merged results
-        page_size: sorted_items.len() as u32,
-        items: sorted_items,
+        total_count,
+        page_no,
+        page_size,
+        items,
         source: "통합검색".to_string(),
         timestamp: Utc::now(),
+        stale,
+        cursors,
     }
 }
 
@@ -261,72 +399,108 @@ fn apply_client_side_filters(
     mut items: Vec<crate::api::types::SearchItem>,
     args: &SearchArgs,
 ) -> Vec<crate::api::types::SearchItem> {
-    use regex::Regex;
+    let filter = ClientFilters::new(args).for_query(&args.query);
+    items.retain(|item| filter.matches(item));
+    items
+}
 
-    // Apply regex search if enabled
-    if args.regex {
-        if let Ok(regex) = Regex::new(&args.query) {
-            items.retain(|item| {
-                regex.is_match(&item.title)
-                    || item.summary.as_ref().is_some_and(|s| regex.is_match(s))
-            });
+/// Filters applied to results after they arrive, since the APIs cannot
+/// (`--regex`, `--min-score`, `--no-dedup`, and several `--law-type` or
+/// `--department` values)
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClientFilters {
+    /// `--regex`: the query is a pattern over title and summary
+    regex: bool,
+    /// `--min-score`; scores are set during merge
+    min_score: Option<f32>,
+    /// Comma-separated `--law-type` values, any of which must match
+    law_types: Option<Vec<String>>,
+    /// Comma-separated `--department` values, any of which must match
+    departments: Option<Vec<String>>,
+    /// `--no-dedup`: keep the same document from several sources
+    pub(crate) no_dedup: bool,
+}
+
+impl ClientFilters {
+    pub(crate) fn new(args: &SearchArgs) -> Self {
+        let split = |values: &Option<String>| {
+            values
+                .as_ref()
+                .map(|values| values.split(',').map(|s| s.trim().to_string()).collect())
+        };
+        Self {
+            regex: args.regex,
+            min_score: args.min_score,
+            law_types: split(&args.law_type),
+            departments: split(&args.department),
+            no_dedup: args.no_dedup,
         }
     }
 
-    // Apply title-only search if enabled
-    if args.title_only {
-        let query_lower = args.query.to_lowercase();
-        items.retain(|item| item.title.to_lowercase().contains(&query_lower));
+    /// Filter for the results of `query`
+    pub(crate) fn for_query(&self, query: &str) -> ItemFilter {
+        ItemFilter {
+            regex: self.regex.then(|| Regex::new(query).ok()).flatten(),
+            filters: self.clone(),
+        }
     }
+}
 
-    // Apply minimum score filter if specified
-    if let Some(min_score) = args.min_score {
-        // For now, we'll use a simple relevance heuristic based on query match
-        let query_terms: Vec<&str> = args.query.split_whitespace().collect();
-        items.retain(|item| {
-            let title_lower = item.title.to_lowercase();
-            let summary_lower = item
-                .summary
-                .as_ref()
-                .map(|s| s.to_lowercase())
-                .unwrap_or_default();
-
-            let match_count = query_terms
-                .iter()
-                .filter(|term| {
-                    let term_lower = term.to_lowercase();
-                    title_lower.contains(&term_lower) || summary_lower.contains(&term_lower)
-                })
-                .count();
-
-            let relevance_score = match_count as f32 / query_terms.len() as f32;
-            relevance_score >= min_score
-        });
-    }
+/// Client-side filters of one search, checked item by item
+pub(crate) struct ItemFilter {
+    regex: Option<Regex>,
+    filters: ClientFilters,
+}
 
-    // Apply law type filter (additional filtering for comma-separated values)
-    if let Some(ref law_types) = args.law_type {
-        let allowed_types: Vec<&str> = law_types.split(',').map(|s| s.trim()).collect();
-        items.retain(|item| {
-            item.law_type
-                .as_ref()
-                .is_some_and(|lt| allowed_types.iter().any(|allowed| lt.contains(allowed)))
-        });
-    }
-
-    // Apply department filter (additional filtering for comma-separated values)
-    if let Some(ref departments) = args.department {
-        let allowed_departments: Vec<&str> = departments.split(',').map(|s| s.trim()).collect();
-        items.retain(|item| {
-            item.department.as_ref().is_some_and(|dept| {
-                allowed_departments
-                    .iter()
-                    .any(|allowed| dept.contains(allowed))
+impl ItemFilter {
+    pub(crate) fn matches(&self, item: &crate::api::types::SearchItem) -> bool {
+        let any_of = |allowed: &Option<Vec<String>>, value: &Option<String>| {
+            allowed.as_ref().is_none_or(|allowed| {
+                value
+                    .as_ref()
+                    .is_some_and(|v| allowed.iter().any(|a| v.contains(a.as_str())))
             })
-        });
+        };
+
+        self.regex.as_ref().is_none_or(|regex| {
+            regex.is_match(&item.title) || item.summary.as_ref().is_some_and(|s| regex.is_match(s))
+        }) && self
+            .filters
+            .min_score
+            .is_none_or(|min_score| item.score.unwrap_or(0.0) >= min_score)
+            && any_of(&self.filters.law_types, &item.law_type)
+            && any_of(&self.filters.departments, &item.department)
     }
+}
 
-    items
+/// Client-side filters and running deduplication for streamed unified results
+///
+/// Streamed items are written as they arrive, so the first occurrence of a
+/// document is kept as is instead of being merged with later duplicates.
+fn filter_stream<S>(
+    items: S,
+    args: &SearchArgs,
+) -> impl Stream<Item = Result<crate::api::types::SearchItem>>
+where
+    S: Stream<Item = Result<crate::api::types::SearchItem>>,
+{
+    let filter = ClientFilters::new(args).for_query(&args.query);
+    let dedup = !args.no_dedup;
+    let mut seen = HashSet::new();
+    items.filter(move |result| {
+        let keep = match result {
+            Ok(item) => {
+                filter.matches(item)
+                    && (!dedup
+                        || seen.insert(match ApiType::from_display_name(&item.source) {
+                            Some(api_type) => dedup_key(api_type, item),
+                            None => format!("id:{}:{}", item.source, item.id),
+                        }))
+            }
+            Err(_) => true,
+        };
+        futures::future::ready(keep)
+    })
 }
 
 /// Sort search results based on specified sort order
@@ -355,12 +529,8 @@ fn sort_search_results(
         "title_desc" => {
             items.sort_by(|a, b| b.title.cmp(&a.title));
         }
-        "relevance" => {
-            // Keep original order for relevance (APIs return results by relevance)
-            // Could implement more sophisticated relevance scoring here
-        }
         _ => {
-            // Default to relevance for unknown sort orders
+            // Relevance (also the default): items are already in fused rank order
         }
     }
 
@@ -387,9 +557,10 @@ fn parse_apis(apis: &str) -> Vec<ApiType> {
 /// Execute parallel search with advanced optimization options
 async fn execute_parallel_search(
     args: SearchArgs,
-    format: OutputFormat,
+    formatter: Formatter,
     quiet: bool,
     verbose: bool,
+    offline: bool,
     api_types: Vec<ApiType>,
     config: Config,
 ) -> Result<()> {
@@ -413,8 +584,12 @@ async fn execute_parallel_search(
         println!("🔗 최대 동시 연결: {}개", args.max_concurrent);
     }
 
-    // Create enhanced cache if requested
-    let cache_store = if args.no_cache {
+    // Create enhanced cache if requested; offline mode reads the configured cache
+    let cache_store = if offline {
+        Some(Arc::new(
+            CacheStore::new(config.cache.to_cache_config()).await?,
+        ))
+    } else if args.no_cache {
         None
     } else if let Some(tier) = args.cache_tier {
         Some(create_enhanced_cache(tier).await?)
@@ -432,12 +607,13 @@ async fn execute_parallel_search(
 
     // Apply filter preset if specified and create search request
     let args = apply_filter_preset(args, &config)?;
-    let request = create_search_request(&args);
+    let request = create_search_request(&args)?;
 
     // Create API clients with optimization
     let mut clients = Vec::new();
     for api_type in &api_types {
-        let client_config = create_optimized_client_config(api_type, &config, cache_store.clone())?;
+        let client_config =
+            create_optimized_client_config(api_type, &config, cache_store.clone(), offline)?;
         let client = ApiClientFactory::create(*api_type, client_config)?;
         clients.push((*api_type, Arc::from(client)));
     }
@@ -448,7 +624,9 @@ async fn execute_parallel_search(
 
     progress_manager.create_multi_api_progress(api_types.len() as u64, &args.query);
 
-    let result = executor.search_parallel(clients, request).await;
+    let result = executor
+        .search_parallel_to_depth(clients, request, merge_depth(&args))
+        .await;
     let execution_time = start_time.elapsed();
 
     match result {
@@ -461,10 +639,13 @@ async fn execute_parallel_search(
                 );
             }
 
-            let merged_response = merge_responses_with_filtering(parallel_result.successes, &args);
-            let formatted_output = output::format_search_response(&merged_response, format)?;
+            let merged_response = merge_responses_with_filtering(
+                parallel_result.successes,
+                &args,
+                &config.rank_fusion(),
+            );
             if !quiet {
-                println!("{}", formatted_output);
+                output::print_search_response(&merged_response, &formatter)?;
             }
             Ok(())
         }
@@ -507,6 +688,7 @@ fn create_optimized_client_config(
     api_type: &ApiType,
     config: &Config,
     cache: Option<Arc<CacheStore>>,
+    offline: bool,
 ) -> Result<ClientConfig> {
     let api_key_name = match api_type {
         ApiType::Nlic => "law.nlic.key",
@@ -520,6 +702,7 @@ fn create_optimized_client_config(
     let api_key = config
         .get_api_key(api_key_name)
         .or_else(|| config.get_api_key("law.key"))
+        .or_else(|| offline.then(String::new))
         .ok_or_else(|| {
             WarpError::InvalidInput(format!("API key for {} not found", api_type.display_name()))
         })?;
@@ -533,23 +716,17 @@ fn create_optimized_client_config(
         cache,
         bypass_cache: false,
         benchmark_mode: false,
+        offline,
     })
 }
 
 /// Create UnifiedSearchRequest with filters from SearchArgs
-fn create_search_request(args: &SearchArgs) -> UnifiedSearchRequest {
-    use crate::api::types::SortOrder;
+fn create_search_request(args: &SearchArgs) -> Result<UnifiedSearchRequest> {
+    use crate::api::types::{SearchScope, SortOrder};
     use std::collections::HashMap;
 
-    // Determine sort order
-    let sort_order = match args.sort.as_str() {
-        "date_asc" => Some(SortOrder::DateAsc),
-        "date_desc" => Some(SortOrder::DateDesc),
-        "title_asc" => Some(SortOrder::TitleAsc),
-        "title_desc" => Some(SortOrder::TitleDesc),
-        "relevance" => Some(SortOrder::Relevance),
-        _ => Some(SortOrder::Relevance), // Default to relevance
-    };
+    // Determine sort order, defaulting to relevance
+    let sort_order = Some(args.sort.parse().unwrap_or(SortOrder::Relevance));
 
     // Prepare extra parameters for advanced filtering
     let mut extras = HashMap::new();
@@ -559,11 +736,6 @@ fn create_search_request(args: &SearchArgs) -> UnifiedSearchRequest {
         extras.insert("regex_search".to_string(), "true".to_string());
     }
 
-    // Add title-only search mode if enabled
-    if args.title_only {
-        extras.insert("title_only".to_string(), "true".to_string());
-    }
-
     // Add minimum score filter if specified
     if let Some(min_score) = args.min_score {
         extras.insert("min_score".to_string(), min_score.to_string());
@@ -595,10 +767,19 @@ fn create_search_request(args: &SearchArgs) -> UnifiedSearchRequest {
             Some(end_date.format("%Y%m%d").to_string()),
         )
     } else {
-        (args.from.clone(), args.to.clone())
+        (
+            args.from
+                .as_deref()
+                .map(|d| date_bound(d, false))
+                .transpose()?,
+            args.to
+                .as_deref()
+                .map(|d| date_bound(d, true))
+                .transpose()?,
+        )
     };
 
-    UnifiedSearchRequest {
+    Ok(UnifiedSearchRequest {
         query: args.query.clone(),
         page_no: args.page,
         page_size: args.size,
@@ -609,8 +790,13 @@ fn create_search_request(args: &SearchArgs) -> UnifiedSearchRequest {
         date_from,
         date_to,
         sort: sort_order,
+        search_scope: if args.title_only {
+            SearchScope::Title
+        } else {
+            args.scope.unwrap_or_default()
+        },
         extras,
-    }
+    })
 }
 
 /// Apply filter preset to SearchArgs if specified
@@ -659,8 +845,9 @@ fn apply_filter_preset(mut args: SearchArgs, config: &Config) -> Result<SearchAr
             args.regex = preset.regex;
         }
 
-        if !args.title_only {
+        if !args.title_only && args.scope.is_none() {
             args.title_only = preset.title_only;
+            args.scope = preset.search_scope;
         }
 
         if args.min_score.is_none() {
@@ -670,3 +857,160 @@ fn apply_filter_preset(mut args: SearchArgs, config: &Config) -> Result<SearchAr
 
     Ok(args)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{SearchItem, SourceCursor};
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_date_bound_expands_years() {
+        assert_eq!(date_bound("2010", false).unwrap(), "20100101");
+        assert_eq!(date_bound("2024", true).unwrap(), "20241231");
+        assert_eq!(date_bound("2024-05-01", true).unwrap(), "20240501");
+        assert!(date_bound("201", false).is_err());
+        assert!(date_bound("작년", false).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_clients_honors_offline_setting() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.cache.cache_dir = Some(dir.path().to_path_buf());
+        config.cache.offline = true;
+
+        // No API keys: only offline clients can be created, and they never
+        // reach the network
+        let clients = create_clients(&[ApiType::Nlic, ApiType::Prec], &config, false, false)
+            .await
+            .unwrap();
+        assert_eq!(clients.len(), 2);
+        let request = UnifiedSearchRequest {
+            query: "민법".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            clients[0].1.search(request).await,
+            Err(WarpError::Offline(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_paging_stream_applies_regex_and_dedup() {
+        let streamed = |api_type: ApiType, title: &str| {
+            let mut item = response(api_type, &[title], 1).items.remove(0);
+            item.source = api_type.display_name().to_string();
+            Ok(item)
+        };
+        let items = futures::stream::iter(vec![
+            streamed(ApiType::Nlic, "민법"),
+            streamed(ApiType::Nlic, "형법"),
+            streamed(ApiType::Nlic, "민 법"),
+            streamed(ApiType::Nlic, "민사소송법"),
+        ]);
+
+        let args = search_args(&["^민", "--regex", "--all"]);
+        let kept: Vec<_> = filter_stream(items, &args)
+            .map(|item| item.unwrap().title)
+            .collect()
+            .await;
+        assert_eq!(kept, vec!["민법", "민사소송법"]);
+    }
+
+    #[test]
+    fn test_search_request_normalizes_dates() {
+        let request = create_search_request(&search_args(&[
+            "민법",
+            "--from",
+            "2024-01-01",
+            "--to",
+            "2024",
+        ]))
+        .unwrap();
+        assert_eq!(request.date_from.as_deref(), Some("20240101"));
+        assert_eq!(request.date_to.as_deref(), Some("20241231"));
+        assert_eq!(
+            request.drf_date_range().as_deref(),
+            Some("20240101~20241231")
+        );
+
+        let err = create_search_request(&search_args(&["민법", "--from", "2024-1"])).unwrap_err();
+        assert!(matches!(err, WarpError::InvalidInput(_)));
+    }
+
+    fn search_args(argv: &[&str]) -> SearchArgs {
+        let argv = ["warp", "search"].iter().chain(argv);
+        match Cli::parse_from(argv).command {
+            Commands::Search(args) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    fn response(api_type: ApiType, titles: &[&str], total_count: u32) -> SearchResponse {
+        let mut cursor = SourceCursor::new(api_type.display_name());
+        cursor.total_count = total_count;
+        cursor.fetched = titles.len() as u32;
+        cursor.next_page = 2;
+        SearchResponse {
+            total_count,
+            page_no: 1,
+            page_size: titles.len() as u32,
+            items: titles
+                .iter()
+                .map(|title| SearchItem {
+                    id: title.to_string(),
+                    title: title.to_string(),
+                    law_no: None,
+                    law_type: None,
+                    department: None,
+                    enforcement_date: None,
+                    revision_date: None,
+                    summary: None,
+                    source: String::new(),
+                    metadata: HashMap::new(),
+                    score: None,
+                    sources: Vec::new(),
+                })
+                .collect(),
+            source: api_type.as_str().to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: vec![cursor],
+        }
+    }
+
+    #[test]
+    fn test_merge_pages_over_combined_list() {
+        let responses = || {
+            vec![
+                (
+                    ApiType::Nlic,
+                    response(ApiType::Nlic, &["가법", "나법", "다법", "라법"], 40),
+                ),
+                (
+                    ApiType::Expc,
+                    response(ApiType::Expc, &["마해석", "가법", "바해석", "사해석"], 10),
+                ),
+            ]
+        };
+
+        let args = search_args(&["법", "--page", "2", "--size", "3", "--sort", "title_asc"]);
+        assert_eq!(merge_depth(&args), 6);
+
+        let merged = merge_responses_with_filtering(responses(), &args, &RankFusion::default());
+        let titles: Vec<_> = merged.items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["라법", "마해석", "바해석"]);
+        assert_eq!((merged.page_no, merged.page_size), (2, 3));
+        // 50 reported by the sources, minus the one duplicate seen
+        assert_eq!(merged.total_count, 49);
+        assert_eq!(merged.cursors.len(), 2);
+
+        let args = search_args(&["법", "--page", "3", "--size", "3", "--no-dedup"]);
+        let merged = merge_responses_with_filtering(responses(), &args, &RankFusion::default());
+        assert_eq!(merged.items.len(), 2);
+        assert_eq!(merged.total_count, 50);
+    }
+}
@@ -0,0 +1,38 @@
+use colored::*;
+use tokio::net::TcpListener;
+
+use crate::cli::args::ServeArgs;
+use crate::cli::commands::search::parse_source;
+use crate::cli::session::Session;
+use crate::config::Config;
+use crate::error::Result;
+use crate::server;
+
+/// Endpoints listed on start
+const ENDPOINTS: &[(&str, &str)] = &[
+    ("GET /search?q=<검색어>", "통합 검색 (source, size, fields)"),
+    ("GET /detail/<ID>", "상세 정보 (source, 기본 nlic)"),
+    ("GET /history/<ID>", "개정 연혁 (source, 기본 nlic)"),
+    ("GET /metrics", "성능 지표"),
+    ("GET /health", "상태와 사용 가능한 소스"),
+];
+
+/// Execute the local API server
+pub async fn execute(args: ServeArgs, no_cache: bool, offline: bool) -> Result<()> {
+    let config = Config::load()?;
+    let api_types = parse_source(&args.source);
+    let session = Session::open(&api_types, args.size, &config, no_cache, offline).await?;
+
+    let listener = TcpListener::bind((args.host.as_str(), args.port)).await?;
+    let addr = listener.local_addr()?;
+    println!("{} http://{}", "🌐 로컬 API 서버:".bold().cyan(), addr);
+    for (endpoint, description) in ENDPOINTS {
+        println!("  {:<28}{}", endpoint, description.dimmed());
+    }
+    println!("{}", "Ctrl-C로 종료".dimmed());
+
+    server::serve(listener, session, async {
+        let _ = tokio::signal::ctrl_c().await;
+    })
+    .await
+}
@@ -0,0 +1,32 @@
+use crate::cli::args::ShellArgs;
+use crate::cli::commands::index::open_index;
+use crate::cli::commands::search::parse_source;
+use crate::cli::session::Session;
+use crate::config::Config;
+use crate::error::Result;
+use crate::output::Formatter;
+use crate::shell::{self, Shell};
+
+/// History file of the shell, kept in the configuration directory
+const HISTORY_FILE: &str = "shell_history";
+
+/// Execute the interactive shell
+pub async fn execute(
+    args: ShellArgs,
+    formatter: Formatter,
+    no_cache: bool,
+    offline: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+    let api_types = parse_source(&args.source);
+    let session = Session::open(&api_types, args.size, &config, no_cache, offline).await?;
+
+    // Laws in the local index complete from the first prompt on
+    let names = match open_index(&config).await {
+        Ok(index) => index.law_names().await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+
+    let history = Config::config_path()?.join(HISTORY_FILE);
+    shell::run(Shell::new(session, formatter, names), &history).await
+}
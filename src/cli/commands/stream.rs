@@ -0,0 +1,122 @@
+use crate::api::client::LegalApiClient;
+use crate::api::streaming::{SearchResultStream, StreamConfig};
+use crate::api::types::{SearchItem, SearchResponse, UnifiedSearchRequest};
+use crate::cli::args::PagingArgs;
+use crate::cli::OutputFormat;
+use crate::error::Result;
+use crate::output;
+use crate::output::formatter::to_json_line;
+use crate::output::Formatter;
+use chrono::Utc;
+use futures::stream::{Stream, StreamExt};
+use std::io::Write;
+use std::sync::Arc;
+
+/// Largest page the APIs accept
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Stream configuration for `--all`/`--limit`
+pub(crate) fn stream_config(request: &UnifiedSearchRequest, paging: &PagingArgs) -> StreamConfig {
+    StreamConfig {
+        page_size: request.page_size.clamp(1, MAX_PAGE_SIZE),
+        max_items: paging.limit.unwrap_or(0),
+        ..Default::default()
+    }
+}
+
+/// Page through every result of one source and print them
+pub(crate) async fn stream_search(
+    client: Arc<dyn LegalApiClient>,
+    request: UnifiedSearchRequest,
+    paging: &PagingArgs,
+    formatter: &Formatter,
+) -> Result<()> {
+    let source = client.api_type().display_name().to_string();
+    let config = stream_config(&request, paging);
+    let items = SearchResultStream::new(client, request, config).into_item_stream();
+    write_items(items, paging, formatter, &source).await
+}
+
+/// Print streamed search results
+///
+/// With `--format jsonl` every item is written as soon as it arrives, so
+/// memory use does not grow with the number of results. Other formats and
+/// `--template` need the whole list and render it once the stream ends.
+pub(crate) async fn write_items<S>(
+    items: S,
+    paging: &PagingArgs,
+    formatter: &Formatter,
+    source: &str,
+) -> Result<()>
+where
+    S: Stream<Item = Result<SearchItem>>,
+{
+    let limit = paging.limit.map_or(usize::MAX, |n| n as usize);
+    let mut items = Box::pin(items.take(limit));
+
+    if matches!(formatter.format(), OutputFormat::Jsonl) && formatter.template().is_none() {
+        let stdout = std::io::stdout();
+        while let Some(item) = items.next().await {
+            let item = item?;
+            let line = match formatter.fields() {
+                Some(fields) => to_json_line(&fields.project_item(&item)?)?,
+                None => to_json_line(&item)?,
+            };
+            if let Err(e) = writeln!(stdout.lock(), "{}", line) {
+                // The reader went away (e.g. `| head`); stop quietly
+                if e.kind() == std::io::ErrorKind::BrokenPipe {
+                    return Ok(());
+                }
+                return Err(e.into());
+            }
+        }
+        return Ok(());
+    }
+
+    let mut collected = Vec::new();
+    while let Some(item) = items.next().await {
+        collected.push(item?);
+    }
+
+    if collected.is_empty() {
+        println!("No results found for your search query.");
+        return Ok(());
+    }
+
+    let count = collected.len() as u32;
+    let response = SearchResponse {
+        total_count: count,
+        page_no: 1,
+        page_size: count,
+        items: collected,
+        source: source.to_string(),
+        timestamp: Utc::now(),
+        stale: false,
+        cursors: Vec::new(),
+    };
+    output::print_search_response(&response, formatter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_config_caps_page_size_and_applies_limit() {
+        let request = UnifiedSearchRequest {
+            page_size: 500,
+            ..Default::default()
+        };
+        let paging = PagingArgs {
+            all: false,
+            limit: Some(250),
+        };
+
+        let config = stream_config(&request, &paging);
+        assert_eq!(config.page_size, MAX_PAGE_SIZE);
+        assert_eq!(config.max_items, 250);
+
+        let config = stream_config(&request, &PagingArgs::default());
+        assert_eq!(config.max_items, 0);
+    }
+}
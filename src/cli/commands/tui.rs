@@ -0,0 +1,19 @@
+use crate::cli::args::TuiArgs;
+use crate::cli::commands::search::parse_source;
+use crate::cli::session::Session;
+use crate::config::Config;
+use crate::error::Result;
+use crate::tui;
+
+/// Execute the interactive browser
+pub async fn execute(args: TuiArgs, no_cache: bool, offline: bool) -> Result<()> {
+    let config = Config::load()?;
+    let api_types = parse_source(&args.source);
+    let session = Session::open(&api_types, args.size, &config, no_cache, offline).await?;
+    browse(args.query, session).await
+}
+
+/// Open the browser on a session, starting with `query` when given
+pub(crate) async fn browse(query: Option<String>, session: Session) -> Result<()> {
+    tui::run(session, query.filter(|q| !q.trim().is_empty())).await
+}
@@ -0,0 +1,344 @@
+use colored::*;
+use comfy_table::{Cell, Color, Table};
+use futures::future::join_all;
+use std::process::ExitCode;
+
+use crate::api::client::LegalApiClient;
+use crate::api::types::SearchItem;
+use crate::api::ApiType;
+use crate::cli::args::{WatchArgs, WatchCommand};
+use crate::cli::session::Session;
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::error::{Result, WarpError};
+use crate::watch::{Change, Revision, Watch, WatchList, WATCH_DB_FILE};
+
+/// Candidates listed when a name matches several laws
+const MAX_CANDIDATES: usize = 5;
+
+/// Exit status of `watch check` when a watched law changed (errors exit with 1)
+pub const CHANGED_EXIT_CODE: u8 = 2;
+
+/// Execute watch list commands
+pub async fn execute(
+    args: WatchArgs,
+    format: OutputFormat,
+    no_cache: bool,
+    offline: bool,
+) -> Result<ExitCode> {
+    let config = Config::load()?;
+    let list = open_watch_list().await?;
+
+    match args.command {
+        WatchCommand::Add { law, source } => {
+            let api_type = source
+                .parse::<ApiType>()
+                .ok()
+                .filter(|t| *t != ApiType::All)
+                .ok_or_else(|| WarpError::InvalidInput(format!("알 수 없는 소스: {}", source)))?;
+            add(&list, &law, api_type, &config, no_cache, offline).await?;
+        }
+        WatchCommand::Remove { law } => {
+            if list.remove(&law).await? == 0 {
+                return Err(WarpError::NotFound(format!("감시 목록의 '{}'", law)));
+            }
+            println!("{} {}", "감시 해제:".bold(), law);
+        }
+        WatchCommand::List => show_list(&list, format).await?,
+        WatchCommand::Check => {
+            let (changes, failed) = check(&list, &config, format, offline).await?;
+            return check_status(changes.len(), failed);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Exit status of `watch check`: [`CHANGED_EXIT_CODE`] when a law changed,
+/// otherwise an error when some watches could not be checked
+fn check_status(changed: usize, failed: usize) -> Result<ExitCode> {
+    if changed > 0 {
+        return Ok(ExitCode::from(CHANGED_EXIT_CODE));
+    }
+    if failed > 0 {
+        return Err(WarpError::Other(format!(
+            "감시 중인 법령 {}건을 확인하지 못했습니다",
+            failed
+        )));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Open the watch list in the configuration directory
+async fn open_watch_list() -> Result<WatchList> {
+    WatchList::open(Config::config_path()?.join(WATCH_DB_FILE)).await
+}
+
+async fn add(
+    list: &WatchList,
+    law: &str,
+    api_type: ApiType,
+    config: &Config,
+    no_cache: bool,
+    offline: bool,
+) -> Result<()> {
+    let session = Session::open(&[api_type], 20, config, no_cache, offline).await?;
+    let client = session.client(api_type)?;
+
+    let (law_id, law_name) = if law.chars().all(|c| c.is_ascii_digit()) {
+        let detail = client.get_detail(law).await?;
+        (law.to_string(), detail.law_name)
+    } else {
+        let items = session.search_source(api_type, law).await?;
+        let item = find_law(&items, law)?;
+        (item.id.clone(), item.title.clone())
+    };
+
+    let revision = current_revision(client, &law_id).await?;
+    if !list.add(api_type, &law_id, &law_name, &revision).await? {
+        println!("{} {} ({})", "이미 감시 중:".yellow(), law_name, law_id);
+        return Ok(());
+    }
+    println!(
+        "{} {} ({}) — 최근 개정 {}",
+        "감시 시작:".bold().green(),
+        law_name.bold(),
+        law_id,
+        describe(&revision)
+    );
+    Ok(())
+}
+
+/// The search result named exactly `law`, or the only result
+fn find_law<'a>(items: &'a [SearchItem], law: &str) -> Result<&'a SearchItem> {
+    let squash = |s: &str| s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    if let Some(item) = items.iter().find(|item| squash(&item.title) == squash(law)) {
+        return Ok(item);
+    }
+    match items {
+        [] => Err(WarpError::NotFound(format!("'{}' 법령", law))),
+        [item] => Ok(item),
+        _ => {
+            let candidates: Vec<String> = items
+                .iter()
+                .take(MAX_CANDIDATES)
+                .map(|item| format!("{} ({})", item.title, item.id))
+                .collect();
+            Err(WarpError::InvalidInput(format!(
+                "'{}'와 정확히 일치하는 법령이 없습니다. 후보: {} — 법령명 전체나 ID로 지정하세요",
+                law,
+                candidates.join(", ")
+            )))
+        }
+    }
+}
+
+/// Latest revision from the history, or the detail's revision date when the
+/// source keeps no history or lists none for the law
+async fn current_revision(client: &dyn LegalApiClient, law_id: &str) -> Result<Revision> {
+    if client.api_type().has_history() {
+        let history = client.get_history(law_id).await?;
+        if let Some(revision) = Revision::from_history(&history) {
+            return Ok(revision);
+        }
+    }
+    let detail = client.get_detail(law_id).await?;
+    Revision::from_detail(&detail)
+        .ok_or_else(|| WarpError::NotFound(format!("{}의 개정 정보", detail.law_name)))
+}
+
+async fn show_list(list: &WatchList, format: OutputFormat) -> Result<()> {
+    let watches = list.list().await?;
+
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(&watches)?);
+        return Ok(());
+    }
+    if watches.is_empty() {
+        println!(
+            "{}",
+            "감시 중인 법령이 없습니다. 'warp watch add <법령명>'으로 추가하세요.".dimmed()
+        );
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("법령명").fg(Color::Cyan),
+        Cell::new("ID").fg(Color::Cyan),
+        Cell::new("소스").fg(Color::Cyan),
+        Cell::new("최근 개정").fg(Color::Cyan),
+        Cell::new("마지막 확인").fg(Color::Cyan),
+    ]);
+    for watch in &watches {
+        table.add_row(vec![
+            Cell::new(&watch.law_name),
+            Cell::new(&watch.law_id),
+            Cell::new(&watch.api_type),
+            Cell::new(describe(&watch.revision)),
+            Cell::new(
+                watch
+                    .checked_at
+                    .map(|at| {
+                        at.with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+        ]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+/// Compare every watched law with its current revision
+///
+/// Returns the changes and the number of watches that could not be checked.
+async fn check(
+    list: &WatchList,
+    config: &Config,
+    format: OutputFormat,
+    offline: bool,
+) -> Result<(Vec<Change>, usize)> {
+    let watches = list.list().await?;
+    if watches.is_empty() {
+        println!(
+            "{}",
+            "감시 중인 법령이 없습니다. 'warp watch add <법령명>'으로 추가하세요.".dimmed()
+        );
+        return Ok((Vec::new(), 0));
+    }
+
+    let mut api_types: Vec<ApiType> = Vec::new();
+    for api_type in watches.iter().filter_map(|w| w.api_type.parse().ok()) {
+        if !api_types.contains(&api_type) {
+            api_types.push(api_type);
+        }
+    }
+    // Always ask the APIs; a cached history could hide a day-old revision
+    let session = Session::open(&api_types, 1, config, true, offline).await?;
+
+    let results = join_all(watches.iter().map(|watch| async {
+        let api_type = watch
+            .api_type
+            .parse::<ApiType>()
+            .map_err(WarpError::InvalidInput)?;
+        let revision = current_revision(session.client(api_type)?, &watch.law_id).await?;
+        list.record(watch, &revision).await
+    }))
+    .await;
+
+    let mut changes = Vec::new();
+    let mut failures: Vec<(&Watch, WarpError)> = Vec::new();
+    for (watch, result) in watches.iter().zip(results) {
+        match result {
+            Ok(Some(change)) => changes.push(change),
+            Ok(None) => {}
+            Err(e) => failures.push((watch, e)),
+        }
+    }
+    if failures.len() == watches.len() {
+        if let Some((_, e)) = failures.pop() {
+            return Err(e);
+        }
+    }
+
+    if matches!(format, OutputFormat::Json) {
+        let failed: Vec<_> = failures
+            .iter()
+            .map(|(watch, e)| {
+                serde_json::json!({
+                    "law_id": watch.law_id,
+                    "law_name": watch.law_name,
+                    "error": e.to_string(),
+                })
+            })
+            .collect();
+        let report = serde_json::json!({
+            "checked": watches.len(),
+            "changed": changes,
+            "failed": failed,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok((changes, failures.len()));
+    }
+
+    for (watch, e) in &failures {
+        eprintln!("{} {}: {}", "⚠️ 확인 실패".yellow(), watch.law_name, e);
+    }
+    if changes.is_empty() {
+        println!(
+            "{} {}개 법령 모두 변경 없음",
+            "✓".green(),
+            watches.len() - failures.len()
+        );
+        return Ok((changes, failures.len()));
+    }
+    println!(
+        "{} {}개 법령이 개정되었습니다",
+        "🔔".bold(),
+        changes.len().to_string().bold()
+    );
+    for change in &changes {
+        println!(
+            "  {} ({}): {} → {}",
+            change.law_name.bold(),
+            change.law_id,
+            describe(&change.previous),
+            describe(&change.current).green()
+        );
+    }
+    Ok((changes, failures.len()))
+}
+
+/// "20230526 타법개정"
+fn describe(revision: &Revision) -> String {
+    match &revision.kind {
+        Some(kind) => format!("{} {}", revision.date, kind),
+        None => revision.date.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn item(id: &str, title: &str) -> SearchItem {
+        SearchItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: String::new(),
+            metadata: HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_find_law_prefers_exact_name() {
+        let items = vec![
+            item("011357", "개인정보 보호법 시행령"),
+            item("011356", "개인정보 보호법"),
+        ];
+        assert_eq!(find_law(&items, "개인정보보호법").unwrap().id, "011356");
+        assert_eq!(find_law(&items[..1], "개인정보").unwrap().id, "011357");
+
+        let err = find_law(&items, "개인정보").unwrap_err().to_string();
+        assert!(err.contains("개인정보 보호법 (011356)"));
+        assert!(find_law(&[], "민법").is_err());
+    }
+
+    #[test]
+    fn test_check_status() {
+        assert!(check_status(0, 0).unwrap() == ExitCode::SUCCESS);
+        assert!(check_status(2, 1).unwrap() == ExitCode::from(CHANGED_EXIT_CODE));
+        assert!(check_status(0, 1).is_err());
+    }
+}
@@ -1,10 +1,12 @@
 pub mod args;
 pub mod commands;
+pub mod session;
 pub mod shell_detect;
 
 use clap::{Parser, Subcommand};
 use clap_complete::Shell;
 use rust_i18n::t;
+use std::process::ExitCode;
 
 /// Korean Legal Information CLI
 #[derive(Parser, Debug)]
@@ -28,10 +30,22 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_cache: bool,
 
+    /// Serve only from cache without calling the APIs (expired entries included)
+    #[arg(long, global = true)]
+    pub offline: bool,
+
     /// Output format
     #[arg(short, long, global = true, value_enum, default_value = "table")]
     pub format: OutputFormat,
 
+    /// Output only these fields, in order (e.g. id,title,metadata.detail_link)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "FIELDS")]
+    pub fields: Option<Vec<String>>,
+
+    /// Render output through a template file or a bundled template (memo, search, history)
+    #[arg(long, global = true, value_name = "FILE")]
+    pub template: Option<String>,
+
     /// Set language (ko, en)
     #[arg(long, global = true)]
     pub lang: Option<String>,
@@ -40,16 +54,24 @@ pub struct Cli {
     pub command: Commands,
 }
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum OutputFormat {
     /// Table format (default)
     Table,
     /// JSON format
     Json,
+    /// JSON Lines: one search result per line, written as results arrive
+    Jsonl,
+    /// YAML format
+    Yaml,
+    /// TOML format
+    Toml,
     /// Markdown format
     Markdown,
     /// CSV format
     Csv,
+    /// Excel workbook (binary; redirect stdout to a .xlsx file)
+    Xlsx,
     /// HTML format
     Html,
     /// Simple HTML format
@@ -89,6 +111,36 @@ pub enum Commands {
     /// Manage cache
     Cache(args::CacheArgs),
 
+    /// Manage the local full-text index of fetched documents
+    Index(args::IndexArgs),
+
+    /// Watch laws and report when they are revised
+    Watch(args::WatchArgs),
+
+    /// Search the local index offline
+    Local(args::LocalArgs),
+
+    /// Export laws to other tools
+    Export(args::ExportArgs),
+
+    /// Download every document of a search as JSON, resuming where the last run stopped
+    Mirror(args::MirrorArgs),
+
+    /// Run many searches from a file and combine their results
+    Batch(args::BatchArgs),
+
+    /// Browse search results, details and history in a full-screen interface
+    Tui(args::TuiArgs),
+
+    /// Run lookups in an interactive shell that keeps clients and cache open
+    Shell(args::ShellArgs),
+
+    /// Serve search, detail and history as a local JSON HTTP API
+    Serve(args::ServeArgs),
+
+    /// Offer lookups as tools to AI assistants over the Model Context Protocol (stdio)
+    Mcp(args::McpArgs),
+
     /// Performance metrics and monitoring
     #[command(alias = "m")]
     Metrics(args::MetricsArgs),
@@ -139,7 +191,10 @@ impl Cli {
     }
 
     /// Run the CLI application
-    pub async fn run() -> crate::error::Result<()> {
+    ///
+    /// Returns the exit status of a successful run; `watch check` reports
+    /// revised laws with its own status.
+    pub async fn run() -> crate::error::Result<ExitCode> {
         let cli = Self::parse();
 
         // Initialize locale
@@ -154,38 +209,121 @@ impl Cli {
                 .init();
         }
 
+        let mut output = crate::output::Formatter::new(cli.format);
+        if let Some(fields) = &cli.fields {
+            match crate::output::fields::FieldSelection::new(fields) {
+                Ok(selection) => output = output.with_fields(selection),
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    return Err(e);
+                }
+            }
+        }
+
+        if let Some(spec) = &cli.template {
+            match crate::output::template::Template::load(spec) {
+                Ok(template) => output = output.with_template(template),
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    return Err(e);
+                }
+            }
+        }
+
+        let mut status = ExitCode::SUCCESS;
         let result = match cli.command {
             Commands::Law(args) => {
-                commands::law::execute(args, cli.format, cli.quiet, cli.verbose, cli.no_cache).await
+                commands::law::execute(
+                    args,
+                    output,
+                    cli.quiet,
+                    cli.verbose,
+                    cli.no_cache,
+                    cli.offline,
+                )
+                .await
             }
             Commands::Ordinance(args) => {
-                commands::ordinance::execute(args, cli.format, cli.quiet, cli.verbose, cli.no_cache)
-                    .await
+                commands::ordinance::execute(
+                    args,
+                    output,
+                    cli.quiet,
+                    cli.verbose,
+                    cli.no_cache,
+                    cli.offline,
+                )
+                .await
             }
             Commands::Precedent(args) => {
-                commands::precedent::execute(args, cli.format, cli.quiet, cli.verbose, cli.no_cache)
-                    .await
+                commands::precedent::execute(
+                    args,
+                    output,
+                    cli.quiet,
+                    cli.verbose,
+                    cli.no_cache,
+                    cli.offline,
+                )
+                .await
             }
             Commands::Admrule(args) => {
-                commands::admrule::execute(args, cli.format, cli.quiet, cli.verbose, cli.no_cache)
-                    .await
+                commands::admrule::execute(
+                    args,
+                    output,
+                    cli.quiet,
+                    cli.verbose,
+                    cli.no_cache,
+                    cli.offline,
+                )
+                .await
             }
             Commands::Interpretation(args) => {
                 commands::interpretation::execute(
                     args,
-                    cli.format,
+                    output,
                     cli.quiet,
                     cli.verbose,
                     cli.no_cache,
+                    cli.offline,
                 )
                 .await
             }
             Commands::Search(args) => {
-                commands::search::execute(args, cli.format, cli.quiet, cli.verbose, cli.no_cache)
-                    .await
+                commands::search::execute(
+                    args,
+                    output,
+                    cli.quiet,
+                    cli.verbose,
+                    cli.no_cache,
+                    cli.offline,
+                )
+                .await
             }
             Commands::Config(args) => commands::config::execute(args).await,
             Commands::Cache(args) => commands::cache::execute(args).await,
+            Commands::Index(args) => commands::index::execute(args, cli.format).await,
+            Commands::Local(args) => commands::local::execute(args, cli.format).await,
+            Commands::Watch(args) => {
+                commands::watch::execute(args, cli.format, cli.no_cache, cli.offline)
+                    .await
+                    .map(|code| status = code)
+            }
+            Commands::Export(args) => {
+                commands::export::execute(args, cli.format, cli.no_cache, cli.offline).await
+            }
+            Commands::Mirror(args) => {
+                commands::mirror::execute(args, cli.format, cli.offline).await
+            }
+            Commands::Batch(args) => {
+                commands::batch::execute(args, cli.format, cli.no_cache, cli.offline).await
+            }
+            Commands::Tui(args) => commands::tui::execute(args, cli.no_cache, cli.offline).await,
+            Commands::Shell(args) => {
+                commands::shell::execute(args, output, cli.no_cache, cli.offline).await
+            }
+            Commands::Serve(args) => {
+                commands::serve::execute(args, cli.no_cache, cli.offline).await
+            }
+            Commands::Mcp(args) => commands::mcp::execute(args, cli.no_cache, cli.offline).await,
             Commands::Metrics(args) => {
                 commands::metrics::execute(args, cli.format, cli.quiet, cli.verbose).await
             }
@@ -222,7 +360,7 @@ impl Cli {
                                         eprintln!("  warp completions zsh");
                                         eprintln!("  warp completions fish");
                                         eprintln!("  warp completions powershell");
-                                        return Ok(());
+                                        return Ok(ExitCode::SUCCESS);
                                     }
                                 }
                             } else {
@@ -233,7 +371,7 @@ impl Cli {
                                 eprintln!("  warp completions zsh");
                                 eprintln!("  warp completions fish");
                                 eprintln!("  warp completions powershell");
-                                return Ok(());
+                                return Ok(ExitCode::SUCCESS);
                             }
                         }
                         None => {
@@ -243,7 +381,7 @@ impl Cli {
                             eprintln!("  warp completions zsh");
                             eprintln!("  warp completions fish");
                             eprintln!("  warp completions powershell");
-                            return Ok(());
+                            return Ok(ExitCode::SUCCESS);
                         }
                     }
                 };
@@ -253,9 +391,12 @@ impl Cli {
             }
         };
 
+        // Let background refreshes of stale cache entries land before exiting
+        crate::cache::wait_for_refreshes(std::time::Duration::from_secs(10)).await;
+
         // Handle errors with better messaging
         match result {
-            Ok(()) => Ok(()),
+            Ok(()) => Ok(status),
             Err(e) => {
                 use crate::error::WarpError;
 
@@ -268,13 +409,8 @@ impl Cli {
                 }
 
                 // Add verbose suggestion for certain errors
-                match &e {
-                    WarpError::Parse(_) | WarpError::ApiError { .. } => {
-                        if !cli.verbose {
-                            eprintln!("\n💡 {}", t!("verbose_hint"));
-                        }
-                    }
-                    _ => {}
+                if matches!(&e, WarpError::Parse(_) | WarpError::ApiError { .. }) && !cli.verbose {
+                    eprintln!("\n💡 {}", t!("verbose_hint"));
                 }
 
                 Err(e)
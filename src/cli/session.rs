@@ -0,0 +1,359 @@
+use chrono::Utc;
+use futures::future::join_all;
+use std::sync::Arc;
+
+use crate::api::client::LegalApiClient;
+use crate::api::types::{
+    LawDetail, LawHistory, SearchItem, SearchResponse, SourceCursor, UnifiedSearchRequest,
+};
+use crate::api::ApiType;
+use crate::cli::commands::search::{create_clients, ClientFilters};
+use crate::config::Config;
+use crate::error::{Result, WarpError};
+use crate::search::{dedup_items, Bm25Scorer, RankFusion};
+
+/// Clients kept open across the commands of an interactive session
+///
+/// `warp tui`, `warp shell` and `warp serve` create the clients and the cache
+/// store once, so every lookup after the first reuses them.
+pub struct Session {
+    clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
+    fusion: RankFusion,
+    page_size: u32,
+    /// Filters and options every search starts from
+    base_request: UnifiedSearchRequest,
+    /// Filters applied to the results of every search
+    filters: ClientFilters,
+}
+
+impl Session {
+    pub fn new(
+        clients: Vec<(ApiType, Arc<dyn LegalApiClient>)>,
+        fusion: RankFusion,
+        page_size: u32,
+    ) -> Self {
+        Self {
+            clients,
+            fusion,
+            page_size: page_size.max(1),
+            base_request: UnifiedSearchRequest::default(),
+            filters: ClientFilters::default(),
+        }
+    }
+
+    /// Search with the filters of `request` (law type, dates, sort, ...)
+    ///
+    /// Its query and page size are replaced by those of each search.
+    pub fn with_base_request(mut self, request: UnifiedSearchRequest) -> Self {
+        self.base_request = request;
+        self
+    }
+
+    /// Filter the results of every search like `warp search` (`--regex`,
+    /// `--min-score`, `--no-dedup`, ...)
+    pub(crate) fn with_filters(mut self, filters: ClientFilters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Create clients for the given sources, sharing the regular cache
+    pub async fn open(
+        api_types: &[ApiType],
+        page_size: u32,
+        config: &Config,
+        no_cache: bool,
+        offline: bool,
+    ) -> Result<Self> {
+        let clients = create_clients(api_types, config, no_cache, offline).await?;
+        if clients.is_empty() {
+            return Err(WarpError::NoApiKey);
+        }
+        Ok(Self::new(clients, config.rank_fusion(), page_size))
+    }
+
+    /// Sources with a client, in search order
+    pub fn sources(&self) -> Vec<ApiType> {
+        self.clients.iter().map(|(t, _)| *t).collect()
+    }
+
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    pub fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = page_size.max(1);
+    }
+
+    /// Search every source and rank the results like `warp search`
+    pub async fn search(&self, query: &str) -> Result<Vec<SearchItem>> {
+        self.search_sources(&[], query, self.page_size).await
+    }
+
+    /// Search a single source
+    pub async fn search_source(&self, api_type: ApiType, query: &str) -> Result<Vec<SearchItem>> {
+        self.search_sources(&[api_type], query, self.page_size)
+            .await
+    }
+
+    /// Search the given sources (every source when empty), `page_size` results each
+    pub async fn search_sources(
+        &self,
+        api_types: &[ApiType],
+        query: &str,
+        page_size: u32,
+    ) -> Result<Vec<SearchItem>> {
+        let request = UnifiedSearchRequest {
+            query: query.to_string(),
+            page_no: 1,
+            page_size: page_size.max(1),
+            ..self.base_request.clone()
+        };
+        Ok(self.search_request(api_types, request).await?.items)
+    }
+
+    /// Run `request` on the given sources (every source when empty)
+    ///
+    /// Each source returns its `page_no`th page; the pages are ranked into one
+    /// list, whose total is the sum of the totals the sources reported.
+    pub async fn search_request(
+        &self,
+        api_types: &[ApiType],
+        request: UnifiedSearchRequest,
+    ) -> Result<SearchResponse> {
+        for &api_type in api_types {
+            self.client(api_type)?;
+        }
+        let clients = self
+            .clients
+            .iter()
+            .filter(|(t, _)| api_types.is_empty() || api_types.contains(t));
+        let results = join_all(clients.map(|(api_type, client)| {
+            let request = request.clone();
+            async move { (*api_type, client.search(request).await) }
+        }))
+        .await;
+
+        let mut items = Vec::new();
+        let mut cursors = Vec::new();
+        let mut stale = false;
+        let mut first_error = None;
+        for (api_type, result) in results {
+            match result {
+                Ok(response) => {
+                    let mut cursor = SourceCursor::new(api_type.display_name());
+                    cursor.total_count = response.total_count;
+                    cursor.fetched = response.items.len() as u32;
+                    cursor.next_page = request.page_no.saturating_add(1);
+                    cursors.push(cursor);
+                    stale |= response.stale;
+                    items.extend(response.items.into_iter().map(|mut item| {
+                        item.source = api_type.display_name().to_string();
+                        (api_type, item)
+                    }));
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        // Only fail when no source answered
+        if let (true, Some(e)) = (cursors.is_empty(), first_error) {
+            return Err(e);
+        }
+
+        let (sources, mut scored): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        Bm25Scorer::default().score_items(&request.query, &mut scored);
+        let mut ranked: Vec<_> = sources.into_iter().zip(scored).collect();
+        ranked.sort_by(|(_, a), (_, b)| b.score.unwrap_or(0.0).total_cmp(&a.score.unwrap_or(0.0)));
+        let ranked = self.fusion.fuse(ranked);
+        let mut items = if self.filters.no_dedup {
+            ranked.into_iter().map(|(_, item)| item).collect()
+        } else {
+            dedup_items(ranked)
+        };
+        let filter = self.filters.for_query(&request.query);
+        items.retain(|item| filter.matches(item));
+
+        Ok(SearchResponse {
+            total_count: cursors.iter().fold(0u32, |total, cursor| {
+                total.saturating_add(cursor.total_count)
+            }),
+            page_no: request.page_no,
+            page_size: request.page_size,
+            items,
+            source: match api_types {
+                [api_type] => api_type.display_name(),
+                _ => ApiType::All.display_name(),
+            }
+            .to_string(),
+            timestamp: Utc::now(),
+            stale,
+            cursors,
+        })
+    }
+
+    pub async fn detail(&self, item: &SearchItem) -> Result<LawDetail> {
+        self.client(self.source_of(item)?)?
+            .get_detail(&item.id)
+            .await
+    }
+
+    pub async fn history(&self, item: &SearchItem) -> Result<LawHistory> {
+        self.client(self.source_of(item)?)?
+            .get_history(&item.id)
+            .await
+    }
+
+    /// Client for a source, if it is configured
+    pub fn client(&self, api_type: ApiType) -> Result<&dyn LegalApiClient> {
+        self.clients
+            .iter()
+            .find(|(t, _)| *t == api_type)
+            .map(|(_, client)| client.as_ref())
+            .ok_or_else(|| {
+                WarpError::InvalidInput(format!(
+                    "'{}' 출처의 클라이언트가 없습니다",
+                    api_type.display_name()
+                ))
+            })
+    }
+
+    /// Source an item came from
+    pub fn source_of(&self, item: &SearchItem) -> Result<ApiType> {
+        self.clients
+            .iter()
+            .map(|(t, _)| *t)
+            .find(|t| t.display_name() == item.source)
+            .ok_or_else(|| {
+                WarpError::InvalidInput(format!("'{}' 출처의 클라이언트가 없습니다", item.source))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Remembers the last request it was sent and answers with `titles`
+    #[derive(Default)]
+    struct RecordingClient {
+        last: Mutex<Option<UnifiedSearchRequest>>,
+        titles: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    impl LegalApiClient for RecordingClient {
+        async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+            *self.last.lock().unwrap() = Some(request);
+            Ok(SearchResponse {
+                total_count: self.titles.len() as u32,
+                page_no: 1,
+                page_size: 10,
+                items: self
+                    .titles
+                    .iter()
+                    .map(|title| SearchItem {
+                        id: title.to_string(),
+                        title: title.to_string(),
+                        law_no: None,
+                        law_type: None,
+                        department: None,
+                        enforcement_date: None,
+                        revision_date: None,
+                        summary: None,
+                        source: "Mock".to_string(),
+                        metadata: Default::default(),
+                        score: None,
+                        sources: Vec::new(),
+                    })
+                    .collect(),
+                source: "Mock".to_string(),
+                timestamp: Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
+            })
+        }
+
+        async fn get_detail(&self, id: &str) -> Result<LawDetail> {
+            Err(WarpError::NotFound(id.to_string()))
+        }
+
+        async fn get_history(&self, id: &str) -> Result<LawHistory> {
+            Err(WarpError::NotFound(id.to_string()))
+        }
+
+        fn api_type(&self) -> ApiType {
+            ApiType::Nlic
+        }
+        fn base_url(&self) -> &str {
+            "http://mock"
+        }
+        fn is_configured(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_keeps_base_request_filters() {
+        let client = Arc::new(RecordingClient::default());
+        let session = Session::new(
+            vec![(ApiType::Nlic, client.clone())],
+            RankFusion::default(),
+            7,
+        )
+        .with_base_request(UnifiedSearchRequest {
+            query: "민법".to_string(),
+            page_no: 3,
+            law_type: Some("법률".to_string()),
+            date_from: Some("20200101".to_string()),
+            date_to: Some("20201231".to_string()),
+            ..Default::default()
+        });
+
+        session.search("상법").await.unwrap();
+        let sent = client.last.lock().unwrap().take().unwrap();
+        assert_eq!(
+            (sent.query.as_str(), sent.page_no, sent.page_size),
+            ("상법", 1, 7)
+        );
+        assert_eq!(sent.law_type.as_deref(), Some("법률"));
+        assert_eq!(sent.date_from.as_deref(), Some("20200101"));
+        assert_eq!(sent.date_to.as_deref(), Some("20201231"));
+    }
+
+    #[tokio::test]
+    async fn test_search_applies_client_filters() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let client = Arc::new(RecordingClient {
+            titles: vec!["민법", "민법", "형법"],
+            ..Default::default()
+        });
+        let session = |argv: &[&str]| {
+            let argv = ["warp", "search"].iter().chain(argv);
+            let Commands::Search(args) = Cli::parse_from(argv).command else {
+                unreachable!()
+            };
+            Session::new(
+                vec![(ApiType::Nlic, client.clone())],
+                RankFusion::default(),
+                10,
+            )
+            .with_filters(ClientFilters::new(&args))
+        };
+
+        let titles = |items: Vec<SearchItem>| -> Vec<String> {
+            items.into_iter().map(|item| item.title).collect()
+        };
+        let deduped = session(&["법"]).search("법").await.unwrap();
+        assert_eq!(titles(deduped).len(), 2);
+        let matched = session(&["^민", "--regex", "--no-dedup"])
+            .search("^민")
+            .await
+            .unwrap();
+        assert_eq!(titles(matched), vec!["민법", "민법"]);
+    }
+}
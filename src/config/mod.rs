@@ -1,4 +1,7 @@
+use crate::api::types::SearchScope;
+use crate::api::ApiType;
 use crate::error::{Result, WarpError};
+use crate::search::RankFusion;
 use chrono::{Duration, Utc};
 use dirs;
 use serde::{Deserialize, Serialize};
@@ -60,6 +63,10 @@ pub struct ApiConfig {
     /// API-specific key
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
+
+    /// Weight of this source when ranking merged search results (default: 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
 }
 
 /// Cache configuration
@@ -80,6 +87,15 @@ pub struct CacheConfig {
     /// Cache directory path (default: user's cache directory + "warp")
     #[serde(default = "default_cache_dir", skip_serializing_if = "Option::is_none")]
     pub cache_dir: Option<PathBuf>,
+
+    /// Seconds after expiry an entry is still served while being refreshed
+    /// in the background (default: 604800 = 7 days)
+    #[serde(default = "default_cache_stale_seconds")]
+    pub stale_seconds: u64,
+
+    /// Serve only from cache and never call the APIs (default: false)
+    #[serde(default)]
+    pub offline: bool,
 }
 
 impl Default for CacheConfig {
@@ -89,6 +105,8 @@ impl Default for CacheConfig {
             ttl_seconds: default_cache_ttl(),
             max_size_mb: default_cache_max_size(),
             cache_dir: default_cache_dir(),
+            stale_seconds: default_cache_stale_seconds(),
+            offline: false,
         }
     }
 }
@@ -106,6 +124,10 @@ fn default_cache_max_size() -> u64 {
     100 // 100 MB
 }
 
+fn default_cache_stale_seconds() -> u64 {
+    604800 // 7 days in seconds
+}
+
 fn default_cache_dir() -> Option<PathBuf> {
     dirs::cache_dir().map(|dir| dir.join("pyhub-warp"))
 }
@@ -178,6 +200,10 @@ pub struct FilterPreset {
     #[serde(default)]
     pub title_only: bool,
 
+    /// Search scope (title, body)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_scope: Option<SearchScope>,
+
     /// Minimum score
     pub min_score: Option<f32>,
 
@@ -239,6 +265,7 @@ impl CacheConfig {
             max_size: self.max_size_mb * 1024 * 1024, // Convert MB to bytes
             default_ttl: Duration::seconds(self.ttl_seconds as i64),
             db_path,
+            stale_window: Duration::seconds(self.stale_seconds as i64),
         }
     }
 
@@ -449,6 +476,40 @@ impl Config {
         }
     }
 
+    /// Per-source configuration
+    fn api_config(&self, api_type: ApiType) -> Option<&ApiConfig> {
+        match api_type {
+            ApiType::Nlic => Some(&self.law.nlic),
+            ApiType::Elis => Some(&self.law.elis),
+            ApiType::Prec => Some(&self.law.prec),
+            ApiType::Admrul => Some(&self.law.admrul),
+            ApiType::Expc => Some(&self.law.expc),
+            ApiType::All => None,
+        }
+    }
+
+    /// Ranking weights of the sources that configure one
+    pub fn source_weights(&self) -> HashMap<ApiType, f32> {
+        [
+            ApiType::Nlic,
+            ApiType::Elis,
+            ApiType::Prec,
+            ApiType::Admrul,
+            ApiType::Expc,
+        ]
+        .into_iter()
+        .filter_map(|api_type| {
+            let weight = self.api_config(api_type)?.weight?;
+            Some((api_type, weight))
+        })
+        .collect()
+    }
+
+    /// Rank fusion for merged search results with the configured source weights
+    pub fn rank_fusion(&self) -> RankFusion {
+        RankFusion::default().with_weights(self.source_weights())
+    }
+
     /// Set a configuration value by key path
     pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
         match key {
@@ -470,6 +531,11 @@ impl Config {
             "law.expc.key" => {
                 self.law.expc.key = Some(value.to_string());
             }
+            "law.nlic.weight" => self.law.nlic.weight = Some(parse_weight(value)?),
+            "law.elis.weight" => self.law.elis.weight = Some(parse_weight(value)?),
+            "law.prec.weight" => self.law.prec.weight = Some(parse_weight(value)?),
+            "law.admrul.weight" => self.law.admrul.weight = Some(parse_weight(value)?),
+            "law.expc.weight" => self.law.expc.weight = Some(parse_weight(value)?),
             "cache.enabled" => {
                 self.cache.enabled = value
                     .parse::<bool>()
@@ -488,6 +554,16 @@ impl Config {
             "cache.cache_dir" => {
                 self.cache.cache_dir = Some(PathBuf::from(value));
             }
+            "cache.stale_seconds" => {
+                self.cache.stale_seconds = value.parse::<u64>().map_err(|_| {
+                    WarpError::Config(format!("Invalid stale seconds value: {}", value))
+                })?;
+            }
+            "cache.offline" => {
+                self.cache.offline = value
+                    .parse::<bool>()
+                    .map_err(|_| WarpError::Config(format!("Invalid boolean value: {}", value)))?;
+            }
             _ => {
                 return Err(WarpError::Config(format!(
                     "Unknown configuration key: {}",
@@ -509,6 +585,11 @@ impl Config {
             "law.prec.key" => self.law.prec.key.clone(),
             "law.admrul.key" => self.law.admrul.key.clone(),
             "law.expc.key" => self.law.expc.key.clone(),
+            "law.nlic.weight" => self.law.nlic.weight.map(|w| w.to_string()),
+            "law.elis.weight" => self.law.elis.weight.map(|w| w.to_string()),
+            "law.prec.weight" => self.law.prec.weight.map(|w| w.to_string()),
+            "law.admrul.weight" => self.law.admrul.weight.map(|w| w.to_string()),
+            "law.expc.weight" => self.law.expc.weight.map(|w| w.to_string()),
             "cache.enabled" => Some(self.cache.enabled.to_string()),
             "cache.ttl_seconds" => Some(self.cache.ttl_seconds.to_string()),
             "cache.max_size_mb" => Some(self.cache.max_size_mb.to_string()),
@@ -517,11 +598,22 @@ impl Config {
                 .cache_dir
                 .as_ref()
                 .map(|p| p.display().to_string()),
+            "cache.stale_seconds" => Some(self.cache.stale_seconds.to_string()),
+            "cache.offline" => Some(self.cache.offline.to_string()),
             _ => None,
         }
     }
 }
 
+/// Parse a source ranking weight (a non-negative number)
+fn parse_weight(value: &str) -> Result<f32> {
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|w| w.is_finite() && *w >= 0.0)
+        .ok_or_else(|| WarpError::Config(format!("Invalid weight value: {}", value)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -589,12 +681,15 @@ cache_dir = "/custom/cache/path"
             ttl_seconds: 3600,
             max_size_mb: 50,
             cache_dir: Some(PathBuf::from("/tmp/test")),
+            stale_seconds: 600,
+            offline: false,
         };
 
         let cache_config = config_cache.to_cache_config();
 
         assert_eq!(cache_config.max_size, 50 * 1024 * 1024); // 50MB in bytes
         assert_eq!(cache_config.default_ttl.num_seconds(), 3600);
+        assert_eq!(cache_config.stale_window.num_seconds(), 600);
     }
 
     #[test]
@@ -605,8 +700,10 @@ cache_dir = "/custom/cache/path"
         config.set("cache.enabled", "false").unwrap();
         config.set("cache.ttl_seconds", "7200").unwrap();
         config.set("cache.max_size_mb", "250").unwrap();
+        config.set("cache.offline", "true").unwrap();
 
         assert!(!config.cache.enabled);
+        assert!(config.cache.offline);
         assert_eq!(config.cache.ttl_seconds, 7200);
         assert_eq!(config.cache.max_size_mb, 250);
 
@@ -614,6 +711,28 @@ cache_dir = "/custom/cache/path"
         assert_eq!(config.get("cache.enabled"), Some("false".to_string()));
         assert_eq!(config.get("cache.ttl_seconds"), Some("7200".to_string()));
         assert_eq!(config.get("cache.max_size_mb"), Some("250".to_string()));
+        assert_eq!(config.get("cache.offline"), Some("true".to_string()));
+    }
+
+    #[test]
+    fn test_source_weights_from_toml() {
+        let toml_str = r#"
+[law.prec]
+weight = 2.0
+
+[law.elis]
+weight = 0.5
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let weights = config.source_weights();
+
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights[&ApiType::Prec], 2.0);
+        assert_eq!(config.rank_fusion().weight(ApiType::Elis), 0.5);
+        assert_eq!(config.rank_fusion().weight(ApiType::Nlic), 1.0);
+        assert_eq!(config.get("law.prec.weight"), Some("2".to_string()));
+        assert!(parse_weight("-1").is_err());
     }
 
     #[test]
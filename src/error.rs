@@ -27,6 +27,15 @@ pub enum WarpError {
     #[error("📊 CSV 처리 오류가 발생했습니다")]
     Csv(#[from] csv::Error),
 
+    #[error("📊 XLSX 생성 오류: {0}")]
+    Xlsx(#[from] rust_xlsxwriter::XlsxError),
+
+    #[error("📝 템플릿 오류: {0}")]
+    Template(#[from] minijinja::Error),
+
+    #[error("⌨️ 입력 오류: {0}")]
+    Readline(#[from] rustyline::error::ReadlineError),
+
     #[error("🗄️ 데이터베이스 오류가 발생했습니다")]
     Database(#[from] rusqlite::Error),
 
@@ -53,6 +62,9 @@ pub enum WarpError {
     #[error("🚨 서버 오류: {0}")]
     ServerError(String),
 
+    #[error("📴 오프라인 모드에서 사용할 수 없습니다: {0}")]
+    Offline(String),
+
     #[error("🔐 인증 실패: {0}")]
     #[allow(dead_code)]
     AuthenticationFailed(String),
@@ -115,6 +127,14 @@ impl WarpError {
                  • 디스크 공간이 충분한지 확인하세요"
                     .to_string(),
             ),
+            Self::Offline(_) => Some(
+                "💡 해결 방법:\n\
+                 • 오프라인 모드에서는 캐시에 저장된 항목만 조회할 수 있습니다\n\
+                 • 온라인 상태에서 같은 명령을 한 번 실행하면 캐시에 저장됩니다\n\
+                 • warp local search 명령으로 색인된 문서를 검색해보세요\n\
+                 • --offline 옵션이나 cache.offline 설정을 해제하면 API를 호출합니다"
+                    .to_string(),
+            ),
             Self::AuthenticationFailed(_) => Some(
                 "💡 해결 방법:\n\
                  • API 키가 올바른지 확인해주세요\n\
@@ -0,0 +1,754 @@
+//! Local full-text index of fetched documents
+//!
+//! Every detail fetched through the API clients is cached as an opaque JSON
+//! blob. [`LocalIndex`] keeps a separate SQLite database next to the cache DB
+//! that stores those details article by article in an FTS5 table, so laws that
+//! were already fetched can be searched without hitting the API at all.
+
+use crate::api::types::LawDetail;
+use crate::api::ApiType;
+use crate::cache::CacheStore;
+use crate::error::{Result, WarpError};
+use chrono::{DateTime, Utc};
+use log::debug;
+use rusqlite::{params, params_from_iter, Connection};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// File name of the index database, stored next to the cache database
+pub const INDEX_DB_FILE: &str = "index.db";
+
+/// Marker inserted before a matched term in snippets
+pub const SNIPPET_START: &str = "«";
+/// Marker inserted after a matched term in snippets
+pub const SNIPPET_END: &str = "»";
+
+/// Approximate number of tokens FTS5 keeps around a match in snippets
+const SNIPPET_TOKENS: i64 = 24;
+/// Number of characters kept around a match in fallback snippets
+const SNIPPET_RADIUS: usize = 40;
+/// The trigram tokenizer cannot match terms shorter than this
+const MIN_MATCH_CHARS: usize = 3;
+
+/// APIs whose detail responses are cached and can be indexed
+const INDEXABLE_APIS: [ApiType; 5] = [
+    ApiType::Nlic,
+    ApiType::Elis,
+    ApiType::Prec,
+    ApiType::Admrul,
+    ApiType::Expc,
+];
+
+/// Summary of an index build
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BuildReport {
+    /// Documents written to the index
+    pub documents: u64,
+    /// Articles written to the index
+    pub articles: u64,
+    /// Cached details that could not be decoded
+    pub skipped: u64,
+}
+
+/// Current state of the index database
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexStatus {
+    pub db_path: PathBuf,
+    pub documents: u64,
+    pub articles: u64,
+    /// Document count per API type
+    pub by_api: BTreeMap<String, u64>,
+    pub last_indexed: Option<DateTime<Utc>>,
+    /// Database file size in bytes
+    pub size: u64,
+}
+
+/// A single article matching a local search
+#[derive(Debug, Clone, Serialize)]
+pub struct ArticleHit {
+    pub api_type: String,
+    /// Identifier the detail was fetched with
+    pub doc_id: String,
+    pub law_name: String,
+    pub article_number: String,
+    pub article_title: Option<String>,
+    /// Excerpt with matches wrapped in [`SNIPPET_START`] / [`SNIPPET_END`]
+    pub snippet: String,
+    /// Relevance score, higher is better
+    pub score: f64,
+}
+
+/// SQLite FTS5 index of fetched law details
+#[derive(Debug, Clone)]
+pub struct LocalIndex {
+    db_path: PathBuf,
+}
+
+impl LocalIndex {
+    /// Path of the index database that lives alongside the given cache database
+    pub fn path_for(cache_db_path: &Path) -> PathBuf {
+        cache_db_path.with_file_name(INDEX_DB_FILE)
+    }
+
+    /// Open the index database, creating the schema if needed
+    pub async fn open<P: AsRef<Path>>(db_path: P) -> Result<Self> {
+        let db_path = db_path.as_ref().to_path_buf();
+        let path = db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let is_new_file = !path.exists();
+            let conn = Self::connect(&path)?;
+
+            if is_new_file {
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    let permissions = std::fs::Permissions::from_mode(0o600);
+                    std::fs::set_permissions(&path, permissions).map_err(|e| {
+                        WarpError::Other(format!("Failed to set index database permissions: {}", e))
+                    })?;
+                }
+            }
+
+            Self::initialize_schema(&conn)
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn index initialization: {}", e)))??;
+
+        Ok(Self { db_path })
+    }
+
+    /// Path of the index database
+    pub fn db_path(&self) -> &Path {
+        &self.db_path
+    }
+
+    fn connect(path: &Path) -> Result<Connection> {
+        Connection::open(path)
+            .map_err(|e| WarpError::Other(format!("Failed to open index database: {}", e)))
+    }
+
+    fn initialize_schema(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS documents (
+                doc_key TEXT PRIMARY KEY,
+                api_type TEXT NOT NULL,
+                doc_id TEXT NOT NULL,
+                law_name TEXT NOT NULL,
+                law_no TEXT,
+                law_type TEXT,
+                department TEXT,
+                enforcement_date TEXT,
+                article_count INTEGER NOT NULL,
+                indexed_at TEXT NOT NULL
+            );
+            CREATE VIRTUAL TABLE IF NOT EXISTS article_fts USING fts5(
+                doc_key UNINDEXED,
+                article_number UNINDEXED,
+                article_title,
+                content,
+                law_name,
+                tokenize = 'trigram'
+            );
+            "#,
+        )
+        .map_err(|e| WarpError::Other(format!("Failed to create index schema: {}", e)))
+    }
+
+    /// Index a single detail, replacing any previous version of it
+    pub async fn index_detail(
+        &self,
+        api_type: ApiType,
+        id: &str,
+        detail: &LawDetail,
+    ) -> Result<u64> {
+        let report = self
+            .index_details(vec![(api_type, id.to_string(), detail.clone())])
+            .await?;
+        Ok(report.articles)
+    }
+
+    /// Index details in a single transaction, replacing previous versions
+    pub async fn index_details(
+        &self,
+        docs: Vec<(ApiType, String, LawDetail)>,
+    ) -> Result<BuildReport> {
+        let db_path = self.db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<BuildReport> {
+            let mut conn = Self::connect(&db_path)?;
+            let tx = conn.transaction().map_err(|e| {
+                WarpError::Other(format!("Failed to start index transaction: {}", e))
+            })?;
+
+            let mut report = BuildReport::default();
+            let now = Utc::now().to_rfc3339();
+
+            for (api_type, id, detail) in &docs {
+                let doc_key = format!("{}:{}", api_type.as_str(), id);
+                let rows = article_rows(detail);
+
+                tx.execute(
+                    "DELETE FROM article_fts WHERE doc_key = ?1",
+                    params![doc_key],
+                )
+                .map_err(|e| WarpError::Other(format!("Failed to remove old articles: {}", e)))?;
+                tx.execute(
+                    r#"
+                    INSERT OR REPLACE INTO documents
+                    (doc_key, api_type, doc_id, law_name, law_no, law_type, department,
+                     enforcement_date, article_count, indexed_at)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+                    "#,
+                    params![
+                        doc_key,
+                        api_type.as_str(),
+                        id,
+                        detail.law_name,
+                        detail.law_no,
+                        detail.law_type,
+                        detail.department,
+                        detail.enforcement_date,
+                        rows.len() as i64,
+                        now,
+                    ],
+                )
+                .map_err(|e| WarpError::Other(format!("Failed to index document: {}", e)))?;
+
+                for (number, title, text) in &rows {
+                    tx.execute(
+                        r#"
+                        INSERT INTO article_fts
+                        (doc_key, article_number, article_title, content, law_name)
+                        VALUES (?1, ?2, ?3, ?4, ?5)
+                        "#,
+                        params![doc_key, number, title, text, detail.law_name],
+                    )
+                    .map_err(|e| WarpError::Other(format!("Failed to index article: {}", e)))?;
+                }
+
+                report.documents += 1;
+                report.articles += rows.len() as u64;
+            }
+
+            tx.commit()
+                .map_err(|e| WarpError::Other(format!("Failed to commit index: {}", e)))?;
+            Ok(report)
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn index operation: {}", e)))?
+    }
+
+    /// Index every cached detail response
+    pub async fn build_from_cache(&self, cache: &CacheStore, rebuild: bool) -> Result<BuildReport> {
+        let mut docs = Vec::new();
+        let mut skipped = 0;
+
+        for api_type in INDEXABLE_APIS {
+            let prefix = format!("{}:detail:", api_type.as_str());
+            for entry in cache.entries_for_api(api_type).await? {
+                let Some(id) = entry.key.strip_prefix(&prefix) else {
+                    continue;
+                };
+                match serde_json::from_slice::<LawDetail>(&entry.data) {
+                    Ok(detail) => docs.push((api_type, id.to_string(), detail)),
+                    Err(e) => {
+                        debug!("Skipping undecodable cache entry {}: {}", entry.key, e);
+                        skipped += 1;
+                    }
+                }
+            }
+        }
+
+        if rebuild {
+            self.clear().await?;
+        }
+
+        let mut report = self.index_details(docs).await?;
+        report.skipped = skipped;
+        Ok(report)
+    }
+
+    /// Remove every document from the index
+    pub async fn clear(&self) -> Result<()> {
+        let db_path = self.db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let conn = Self::connect(&db_path)?;
+            conn.execute_batch("DELETE FROM article_fts; DELETE FROM documents;")
+                .map_err(|e| WarpError::Other(format!("Failed to clear index: {}", e)))
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn clear operation: {}", e)))?
+    }
+
+    /// Get document and article counts of the index
+    pub async fn status(&self) -> Result<IndexStatus> {
+        let db_path = self.db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<IndexStatus> {
+            let conn = Self::connect(&db_path)?;
+            let query_err = |e: rusqlite::Error| {
+                WarpError::Other(format!("Failed to read index status: {}", e))
+            };
+
+            let documents: i64 = conn
+                .query_row("SELECT COUNT(*) FROM documents", [], |row| row.get(0))
+                .map_err(query_err)?;
+            let articles: i64 = conn
+                .query_row("SELECT COUNT(*) FROM article_fts", [], |row| row.get(0))
+                .map_err(query_err)?;
+            let last_indexed: Option<String> = conn
+                .query_row("SELECT MAX(indexed_at) FROM documents", [], |row| {
+                    row.get(0)
+                })
+                .map_err(query_err)?;
+
+            let mut stmt = conn
+                .prepare("SELECT api_type, COUNT(*) FROM documents GROUP BY api_type")
+                .map_err(query_err)?;
+            let by_api = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64))
+                })
+                .map_err(query_err)?
+                .collect::<std::result::Result<BTreeMap<_, _>, _>>()
+                .map_err(query_err)?;
+
+            Ok(IndexStatus {
+                size: std::fs::metadata(&db_path).map(|m| m.len()).unwrap_or(0),
+                db_path,
+                documents: documents as u64,
+                articles: articles as u64,
+                by_api,
+                last_indexed: last_indexed
+                    .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                    .map(|dt| dt.with_timezone(&Utc)),
+            })
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn status operation: {}", e)))?
+    }
+
+    /// Names of the indexed laws, sorted
+    pub async fn law_names(&self) -> Result<Vec<String>> {
+        let db_path = self.db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<Vec<String>> {
+            let conn = Self::connect(&db_path)?;
+            let query_err =
+                |e: rusqlite::Error| WarpError::Other(format!("Failed to read law names: {}", e));
+
+            let mut stmt = conn
+                .prepare("SELECT DISTINCT law_name FROM documents ORDER BY law_name")
+                .map_err(query_err)?;
+            let names = stmt
+                .query_map([], |row| row.get(0))
+                .map_err(query_err)?
+                .collect::<std::result::Result<Vec<String>, _>>()
+                .map_err(query_err)?;
+            Ok(names)
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn law name query: {}", e)))?
+    }
+
+    /// Search indexed articles
+    ///
+    /// Every whitespace-separated term must appear in the article title,
+    /// content or law name. Terms of three or more characters go through the
+    /// FTS5 trigram index ranked by bm25; shorter terms (e.g. `민법`) cannot be
+    /// matched by trigrams and fall back to a substring scan.
+    pub async fn search(&self, query: &str, limit: usize) -> Result<Vec<ArticleHit>> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_string).collect();
+        if terms.is_empty() {
+            return Err(WarpError::InvalidInput(
+                "Search query cannot be empty".to_string(),
+            ));
+        }
+
+        let db_path = self.db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<Vec<ArticleHit>> {
+            let conn = Self::connect(&db_path)?;
+            if terms.iter().all(|t| t.chars().count() >= MIN_MATCH_CHARS) {
+                Self::search_fts(&conn, &terms, limit)
+            } else {
+                Self::search_substring(&conn, &terms, limit)
+            }
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn search operation: {}", e)))?
+    }
+
+    fn search_fts(conn: &Connection, terms: &[String], limit: usize) -> Result<Vec<ArticleHit>> {
+        let fts_query = terms
+            .iter()
+            .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let query_err =
+            |e: rusqlite::Error| WarpError::Other(format!("Failed to search index: {}", e));
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT d.api_type, d.doc_id, h.law_name, h.article_number, h.article_title,
+                       h.snip, h.rank
+                FROM (
+                    SELECT doc_key, law_name, article_number, article_title,
+                           snippet(article_fts, 3, ?2, ?3, '…', ?4) AS snip,
+                           bm25(article_fts, 0.0, 0.0, 4.0, 1.0, 2.0) AS rank
+                    FROM article_fts
+                    WHERE article_fts MATCH ?1
+                    ORDER BY rank
+                    LIMIT ?5
+                ) h
+                JOIN documents d ON d.doc_key = h.doc_key
+                ORDER BY h.rank
+                "#,
+            )
+            .map_err(query_err)?;
+
+        let hits = stmt
+            .query_map(
+                params![
+                    fts_query,
+                    SNIPPET_START,
+                    SNIPPET_END,
+                    SNIPPET_TOKENS,
+                    limit as i64
+                ],
+                |row| {
+                    Ok(ArticleHit {
+                        api_type: row.get(0)?,
+                        doc_id: row.get(1)?,
+                        law_name: row.get(2)?,
+                        article_number: row.get(3)?,
+                        article_title: non_empty(row.get(4)?),
+                        snippet: row.get(5)?,
+                        // bm25() is negative with better matches being smaller
+                        score: -row.get::<_, f64>(6)?,
+                    })
+                },
+            )
+            .map_err(query_err)?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(query_err)?;
+
+        Ok(hits)
+    }
+
+    fn search_substring(
+        conn: &Connection,
+        terms: &[String],
+        limit: usize,
+    ) -> Result<Vec<ArticleHit>> {
+        let query_err =
+            |e: rusqlite::Error| WarpError::Other(format!("Failed to search index: {}", e));
+
+        let conditions = (1..=terms.len())
+            .map(|i| {
+                format!(
+                    "(f.article_title LIKE ?{i} ESCAPE '\\' OR f.content LIKE ?{i} ESCAPE '\\' \
+                     OR f.law_name LIKE ?{i} ESCAPE '\\')"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let sql = format!(
+            r#"
+            SELECT d.api_type, d.doc_id, f.law_name, f.article_number, f.article_title, f.content
+            FROM article_fts f
+            JOIN documents d ON d.doc_key = f.doc_key
+            WHERE {}
+            "#,
+            conditions
+        );
+        let patterns = terms.iter().map(|t| format!("%{}%", escape_like(t)));
+
+        let mut stmt = conn.prepare(&sql).map_err(query_err)?;
+        let mut hits = stmt
+            .query_map(params_from_iter(patterns), |row| {
+                let title: Option<String> = non_empty(row.get(4)?);
+                let law_name: String = row.get(2)?;
+                let content: String = row.get(5)?;
+                let score = terms
+                    .iter()
+                    .map(|t| {
+                        let in_title = title
+                            .as_deref()
+                            .map_or(0, |s| s.matches(t.as_str()).count());
+                        4 * in_title
+                            + content.matches(t.as_str()).count()
+                            + 2 * law_name.matches(t.as_str()).count()
+                    })
+                    .sum::<usize>() as f64;
+
+                Ok(ArticleHit {
+                    api_type: row.get(0)?,
+                    doc_id: row.get(1)?,
+                    article_number: row.get(3)?,
+                    snippet: make_snippet(&content, terms),
+                    law_name,
+                    article_title: title,
+                    score,
+                })
+            })
+            .map_err(query_err)?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(query_err)?;
+
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit);
+        Ok(hits)
+    }
+}
+
+/// Split a detail into `(number, title, text)` rows, one per article
+///
+/// Details without articles (precedents, interpretations) are indexed as a
+/// single row holding their full content.
+fn article_rows(detail: &LawDetail) -> Vec<(String, String, String)> {
+    if detail.articles.is_empty() {
+        if detail.content.trim().is_empty() {
+            return Vec::new();
+        }
+        return vec![(String::new(), String::new(), detail.content.clone())];
+    }
+
+    detail
+        .articles
+        .iter()
+        .map(|article| {
+            let mut text = article.content.clone();
+            for paragraph in &article.paragraphs {
+                for part in std::iter::once(&paragraph.content).chain(paragraph.items.iter()) {
+                    if !part.is_empty() && !text.contains(part.as_str()) {
+                        text.push('\n');
+                        text.push_str(part);
+                    }
+                }
+            }
+            (
+                article.number.clone(),
+                article.title.clone().unwrap_or_default(),
+                text,
+            )
+        })
+        .collect()
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|s| !s.is_empty())
+}
+
+fn escape_like(term: &str) -> String {
+    term.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Cut an excerpt around the first matching term and mark every match in it
+fn make_snippet(text: &str, terms: &[String]) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let first = terms
+        .iter()
+        .filter_map(|t| text.find(t.as_str()))
+        .min()
+        .map(|byte| text[..byte].chars().count())
+        .unwrap_or(0);
+
+    let start = first.saturating_sub(SNIPPET_RADIUS);
+    let end = (first + SNIPPET_RADIUS).min(chars.len());
+    let mut excerpt: String = chars[start..end].iter().collect();
+    excerpt = excerpt.replace('\n', " ");
+
+    for term in terms {
+        excerpt = excerpt.replace(
+            term.as_str(),
+            &format!("{}{}{}", SNIPPET_START, term, SNIPPET_END),
+        );
+    }
+
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < chars.len() { "…" } else { "" };
+    format!("{}{}{}", prefix, excerpt, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{Article, Paragraph};
+    use crate::cache::CacheConfig;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn sample_detail() -> LawDetail {
+        LawDetail {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            law_no: Some("제19098호".to_string()),
+            law_type: Some("법률".to_string()),
+            department: Some("법무부".to_string()),
+            enforcement_date: Some("20230628".to_string()),
+            revision_date: None,
+            content: String::new(),
+            articles: vec![
+                Article {
+                    number: "제1조".to_string(),
+                    title: Some("법원".to_string()),
+                    content: "민사에 관하여 법률에 규정이 없으면 관습법에 의하고 관습법이 없으면 조리에 의한다.".to_string(),
+                    paragraphs: vec![],
+                },
+                Article {
+                    number: "제3조".to_string(),
+                    title: Some("권리능력의 존속기간".to_string()),
+                    content: "사람은 생존한 동안 권리와 의무의 주체가 된다.".to_string(),
+                    paragraphs: vec![Paragraph {
+                        number: "1".to_string(),
+                        content: "태아는 손해배상의 청구권에 관하여는 이미 출생한 것으로 본다.".to_string(),
+                        items: vec![],
+                    }],
+                },
+            ],
+            attachments: vec![],
+            related_laws: vec![],
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        }
+    }
+
+    async fn open_index(dir: &TempDir) -> LocalIndex {
+        LocalIndex::open(dir.path().join(INDEX_DB_FILE))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_index_and_search_articles() {
+        let dir = TempDir::new().unwrap();
+        let index = open_index(&dir).await;
+
+        let articles = index
+            .index_detail(ApiType::Nlic, "265307", &sample_detail())
+            .await
+            .unwrap();
+        assert_eq!(articles, 2);
+
+        let hits = index.search("관습법", 10).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].api_type, "nlic");
+        assert_eq!(hits[0].doc_id, "265307");
+        assert_eq!(hits[0].article_number, "제1조");
+        assert_eq!(hits[0].article_title.as_deref(), Some("법원"));
+        assert!(hits[0].snippet.contains("«관습법»"));
+        assert!(hits[0].score > 0.0);
+
+        // Paragraph text is indexed with its article
+        let hits = index.search("손해배상", 10).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].article_number, "제3조");
+    }
+
+    #[tokio::test]
+    async fn test_short_terms_fall_back_to_substring_search() {
+        let dir = TempDir::new().unwrap();
+        let index = open_index(&dir).await;
+        index
+            .index_detail(ApiType::Nlic, "265307", &sample_detail())
+            .await
+            .unwrap();
+
+        let hits = index.search("태아", 10).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].article_number, "제3조");
+        assert!(hits[0].snippet.contains("«태아»"));
+
+        // All terms must match
+        assert!(index.search("태아 관습법", 10).await.unwrap().is_empty());
+        // Matches on the law name count for every article
+        assert_eq!(index.search("민법", 10).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_reindex_replaces_document() {
+        let dir = TempDir::new().unwrap();
+        let index = open_index(&dir).await;
+        let detail = sample_detail();
+
+        index
+            .index_detail(ApiType::Nlic, "265307", &detail)
+            .await
+            .unwrap();
+        index
+            .index_detail(ApiType::Nlic, "265307", &detail)
+            .await
+            .unwrap();
+
+        let status = index.status().await.unwrap();
+        assert_eq!(status.documents, 1);
+        assert_eq!(status.articles, 2);
+        assert_eq!(status.by_api.get("nlic"), Some(&1));
+        assert!(status.last_indexed.is_some());
+        assert_eq!(index.law_names().await.unwrap(), vec!["민법"]);
+
+        index.clear().await.unwrap();
+        let status = index.status().await.unwrap();
+        assert_eq!(status.documents, 0);
+        assert_eq!(status.articles, 0);
+    }
+
+    #[tokio::test]
+    async fn test_build_from_cache() {
+        let dir = TempDir::new().unwrap();
+        let cache = CacheStore::new(CacheConfig {
+            db_path: dir.path().join("cache.db"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let detail = serde_json::to_vec(&sample_detail()).unwrap();
+        cache
+            .put("nlic:detail:265307", detail, ApiType::Nlic, None)
+            .await
+            .unwrap();
+        cache
+            .put(
+                "nlic:detail:broken",
+                b"not json".to_vec(),
+                ApiType::Nlic,
+                None,
+            )
+            .await
+            .unwrap();
+        cache
+            .put("nlic:search:abc", b"{}".to_vec(), ApiType::Nlic, None)
+            .await
+            .unwrap();
+
+        let index = LocalIndex::open(LocalIndex::path_for(&dir.path().join("cache.db")))
+            .await
+            .unwrap();
+        let report = index.build_from_cache(&cache, true).await.unwrap();
+
+        assert_eq!(report.documents, 1);
+        assert_eq!(report.articles, 2);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(index.db_path(), dir.path().join(INDEX_DB_FILE));
+    }
+
+    #[test]
+    fn test_make_snippet_marks_terms() {
+        let text = "가".repeat(100) + "계약의 해제" + &"나".repeat(100);
+        let snippet = make_snippet(&text, &["계약".to_string()]);
+
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("«계약»의 해제"));
+    }
+}
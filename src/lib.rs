@@ -15,6 +15,7 @@
 //! ## Quick Start
 //!
 //! ```no_run
+//! use warp::api::client::ClientConfig;
 //! use warp::api::{ApiClientFactory, ApiType};
 //! use warp::api::types::UnifiedSearchRequest;
 //! use warp::config::Config;
@@ -27,7 +28,10 @@
 //!     // Create API client
 //!     let client = ApiClientFactory::create(
 //!         ApiType::Nlic,
-//!         config.to_client_config()
+//!         ClientConfig {
+//!             api_key: config.get_nlic_api_key().unwrap_or_default(),
+//!             ..Default::default()
+//!         },
 //!     )?;
 //!
 //!     // Search for laws
@@ -37,7 +41,7 @@
 //!     };
 //!
 //!     let response = client.search(request).await?;
-//!     println!("Found {} results", response.total_count.unwrap_or(0));
+//!     println!("Found {} results", response.total_count);
 //!
 //!     Ok(())
 //! }
@@ -57,9 +61,17 @@ pub mod cache;
 pub mod cli;
 pub mod config;
 pub mod error;
+pub mod index;
+pub mod mcp;
 pub mod metrics;
+pub mod mirror;
 pub mod output;
 pub mod progress;
+pub mod search;
+pub mod server;
+pub mod shell;
+pub mod tui;
+pub mod watch;
 
 // Initialize i18n system
 rust_i18n::i18n!("locales", fallback = "en");
@@ -1,7 +1,11 @@
+use std::process::ExitCode;
 use warp::cli;
-use warp::error::Result;
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    cli::Cli::run().await
+async fn main() -> ExitCode {
+    // Cli::run has already reported the error
+    match cli::Cli::run().await {
+        Ok(status) => status,
+        Err(_) => ExitCode::FAILURE,
+    }
 }
@@ -0,0 +1,345 @@
+//! Model Context Protocol server over stdio
+//!
+//! Messages are JSON-RPC 2.0, one per line on stdin and stdout. Only the
+//! tools capability is offered; logs go to stderr so stdout stays clean.
+
+pub mod tools;
+
+use serde_json::{json, Map, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use crate::cli::session::Session;
+use crate::error::Result;
+
+/// Protocol revisions understood, newest first
+const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Answers MCP requests with lookups through one session
+pub struct McpServer {
+    session: Session,
+}
+
+impl McpServer {
+    pub fn new(session: Session) -> Self {
+        Self { session }
+    }
+
+    /// Answer one message; notifications get no answer
+    pub async fn handle(&self, message: Value) -> Option<Value> {
+        let Some(message) = message.as_object() else {
+            return Some(error(
+                Value::Null,
+                INVALID_REQUEST,
+                "Expected a JSON object",
+            ));
+        };
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to requests we never send
+            return id.map(|id| error(id, INVALID_REQUEST, "Missing method"));
+        };
+        let id = id?;
+        let params = match message.get("params") {
+            None | Some(Value::Null) => Map::new(),
+            Some(Value::Object(params)) => params.clone(),
+            Some(_) => return Some(error(id, INVALID_PARAMS, "params must be an object")),
+        };
+
+        let result = match method {
+            "initialize" => Ok(self.initialize(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({"tools": tools::definitions()})),
+            "tools/call" => self.call_tool(&params).await,
+            other => Err((METHOD_NOT_FOUND, format!("Method not found: {}", other))),
+        };
+        Some(match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err((code, message)) => error(id, code, &message),
+        })
+    }
+
+    fn initialize(&self, params: &Map<String, Value>) -> Value {
+        let requested = params.get("protocolVersion").and_then(Value::as_str);
+        let version = requested
+            .and_then(|v| PROTOCOL_VERSIONS.iter().find(|&&known| known == v))
+            .unwrap_or(&PROTOCOL_VERSIONS[0]);
+        json!({
+            "protocolVersion": version,
+            "capabilities": {"tools": {}},
+            "serverInfo": {"name": "warp", "version": env!("CARGO_PKG_VERSION")},
+            "instructions": "Korean legal information: search laws and precedents, then fetch details, single articles or revision history by ID.",
+        })
+    }
+
+    /// Run a tool; its failures are reported in the result so the model sees them
+    async fn call_tool(
+        &self,
+        params: &Map<String, Value>,
+    ) -> std::result::Result<Value, (i64, String)> {
+        let name = params
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or((INVALID_PARAMS, "Missing tool name".to_string()))?;
+        if !tools::exists(name) {
+            return Err((INVALID_PARAMS, format!("Unknown tool: {}", name)));
+        }
+        let args = match params.get("arguments") {
+            None | Some(Value::Null) => Map::new(),
+            Some(Value::Object(args)) => args.clone(),
+            Some(_) => return Err((INVALID_PARAMS, "arguments must be an object".to_string())),
+        };
+
+        Ok(match tools::call(&self.session, name, &args).await {
+            Ok(value) => json!({
+                "content": [{"type": "text", "text": value.to_string()}],
+                "structuredContent": value,
+                "isError": false,
+            }),
+            Err(e) => {
+                let mut text = e.to_string();
+                if let Some(hint) = e.hint() {
+                    text = format!("{}\n{}", text, hint);
+                }
+                json!({
+                    "content": [{"type": "text", "text": text}],
+                    "isError": true,
+                })
+            }
+        })
+    }
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message},
+    })
+}
+
+/// Serve requests from stdin until it closes
+pub async fn run(server: McpServer) -> Result<()> {
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => server.handle(message).await,
+            Err(e) => Some(error(Value::Null, PARSE_ERROR, &e.to_string())),
+        };
+        if let Some(reply) = reply {
+            stdout.write_all(format!("{}\n", reply).as_bytes()).await?;
+            stdout.flush().await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::LegalApiClient;
+    use crate::api::types::{
+        Article, LawDetail, LawHistory, SearchItem, SearchResponse, UnifiedSearchRequest,
+    };
+    use crate::api::ApiType;
+    use crate::error::WarpError;
+    use crate::search::RankFusion;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    struct MockClient;
+
+    #[async_trait]
+    impl LegalApiClient for MockClient {
+        async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+            let item = SearchItem {
+                id: "001706".to_string(),
+                title: request.query.clone(),
+                law_no: None,
+                law_type: None,
+                department: None,
+                enforcement_date: None,
+                revision_date: None,
+                summary: None,
+                source: String::new(),
+                metadata: HashMap::new(),
+                score: None,
+                sources: Vec::new(),
+            };
+            Ok(SearchResponse {
+                total_count: 1,
+                page_no: 1,
+                page_size: request.page_size,
+                items: vec![item],
+                source: "Mock".to_string(),
+                timestamp: Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
+            })
+        }
+
+        async fn get_detail(&self, id: &str) -> Result<LawDetail> {
+            let article = |number: &str, content: &str| Article {
+                number: number.to_string(),
+                title: None,
+                content: content.to_string(),
+                paragraphs: Vec::new(),
+            };
+            Ok(LawDetail {
+                law_id: id.to_string(),
+                law_name: "민법".to_string(),
+                law_no: None,
+                law_type: None,
+                department: None,
+                enforcement_date: None,
+                revision_date: None,
+                content: String::new(),
+                articles: vec![
+                    article("1", "민사에 관하여 법률에 규정이 없으면 관습법에 의하고"),
+                    article("3", "사람은 생존한 동안 권리와 의무의 주체가 된다."),
+                ],
+                attachments: Vec::new(),
+                related_laws: Vec::new(),
+                metadata: HashMap::new(),
+                fetched_at: None,
+                stale: false,
+            })
+        }
+
+        async fn get_history(&self, id: &str) -> Result<LawHistory> {
+            Err(WarpError::NotFound(id.to_string()))
+        }
+
+        fn api_type(&self) -> ApiType {
+            ApiType::Nlic
+        }
+        fn base_url(&self) -> &str {
+            "http://mock"
+        }
+        fn is_configured(&self) -> bool {
+            true
+        }
+    }
+
+    fn server() -> McpServer {
+        McpServer::new(Session::new(
+            vec![(ApiType::Nlic, Arc::new(MockClient))],
+            RankFusion::default(),
+            5,
+        ))
+    }
+
+    async fn call(server: &McpServer, name: &str, arguments: Value) -> Value {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "method": "tools/call",
+            "params": {"name": name, "arguments": arguments},
+        });
+        server.handle(request).await.unwrap()["result"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_initialize_and_list_tools() {
+        let server = server();
+        let reply = server
+            .handle(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {"protocolVersion": "2024-11-05", "capabilities": {}},
+            }))
+            .await
+            .unwrap();
+        assert_eq!(reply["id"], 1);
+        assert_eq!(reply["result"]["protocolVersion"], "2024-11-05");
+        assert!(reply["result"]["capabilities"]["tools"].is_object());
+
+        let notification = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+        assert!(server.handle(notification).await.is_none());
+
+        let reply = server
+            .handle(json!({"jsonrpc": "2.0", "id": "a", "method": "tools/list"}))
+            .await
+            .unwrap();
+        let names: Vec<&str> = reply["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "search_laws",
+                "search_precedents",
+                "get_law_detail",
+                "get_article",
+                "get_history"
+            ]
+        );
+
+        let reply = server
+            .handle(json!({"jsonrpc": "2.0", "id": 2, "method": "resources/list"}))
+            .await
+            .unwrap();
+        assert_eq!(reply["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_tool_calls() {
+        let server = server();
+
+        let result = call(&server, "search_laws", json!({"query": "민법"})).await;
+        assert_eq!(result["isError"], false);
+        assert_eq!(result["structuredContent"]["items"][0]["id"], "001706");
+
+        let result = call(
+            &server,
+            "get_article",
+            json!({"id": "001706", "article": "제3조"}),
+        )
+        .await;
+        assert_eq!(result["structuredContent"]["law_name"], "민법");
+        assert_eq!(result["structuredContent"]["article"]["number"], "3");
+
+        // Tool failures are results the model can read, not protocol errors
+        let result = call(
+            &server,
+            "get_article",
+            json!({"id": "001706", "article": "99"}),
+        )
+        .await;
+        assert_eq!(result["isError"], true);
+        let result = call(&server, "search_precedents", json!({"query": "명예훼손"})).await;
+        assert_eq!(result["isError"], true);
+        let result = call(&server, "search_laws", json!({})).await;
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("query"));
+
+        let reply = server
+            .handle(json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "tools/call",
+                "params": {"name": "delete_laws"},
+            }))
+            .await
+            .unwrap();
+        assert_eq!(reply["error"]["code"], INVALID_PARAMS);
+    }
+}
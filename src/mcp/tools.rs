@@ -0,0 +1,237 @@
+use serde_json::{json, Map, Value};
+
+use crate::api::types::LawDetail;
+use crate::api::ApiType;
+use crate::cli::commands::search::parse_source;
+use crate::cli::session::Session;
+use crate::error::{Result, WarpError};
+use crate::output::vault::article_label;
+
+/// Tools offered to the client: name, description and input schema
+pub fn definitions() -> Vec<Value> {
+    let size = json!({
+        "type": "integer",
+        "minimum": 1,
+        "maximum": 100,
+        "description": "Results per source (default: the server's --size)",
+    });
+    let id = json!({
+        "type": "string",
+        "description": "Document ID from a search result, e.g. \"001706\"",
+    });
+    let source = json!({
+        "type": "string",
+        "enum": ["nlic", "elis", "prec", "admrul", "expc"],
+        "description": "Source the ID belongs to (default: nlic, national laws)",
+    });
+
+    vec![
+        json!({
+            "name": "search_laws",
+            "description": "Search Korean laws and regulations by keyword. Returns ranked results with IDs for get_law_detail, get_article and get_history.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string", "description": "Keywords in Korean, e.g. \"개인정보 보호법\""},
+                    "source": {
+                        "type": "string",
+                        "description": "nlic (national laws, default), elis (local ordinances), admrul (administrative rules), expc (interpretations), all, or a comma-separated list",
+                    },
+                    "size": size,
+                },
+                "required": ["query"],
+            },
+        }),
+        json!({
+            "name": "search_precedents",
+            "description": "Search Korean court precedents by keyword.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string", "description": "Keywords in Korean, e.g. \"명예훼손 공연성\""},
+                    "size": size,
+                },
+                "required": ["query"],
+            },
+        }),
+        json!({
+            "name": "get_law_detail",
+            "description": "Full text of a law or precedent, with its articles, dates and department.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {"id": id, "source": source},
+                "required": ["id"],
+            },
+        }),
+        json!({
+            "name": "get_article",
+            "description": "A single article of a law, e.g. 제3조 or 제10조의2.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": id,
+                    "article": {"type": "string", "description": "Article number: \"3\", \"제3조\" or \"10의2\""},
+                    "source": source,
+                },
+                "required": ["id", "article"],
+            },
+        }),
+        json!({
+            "name": "get_history",
+            "description": "Revision history of a law.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {"id": id, "source": source},
+                "required": ["id"],
+            },
+        }),
+    ]
+}
+
+/// Whether `name` is one of the tools
+pub fn exists(name: &str) -> bool {
+    definitions().iter().any(|tool| tool["name"] == name)
+}
+
+/// Run a tool and return its structured result
+pub async fn call(session: &Session, name: &str, args: &Map<String, Value>) -> Result<Value> {
+    let value = match name {
+        "search_laws" => {
+            let api_types = match optional(args, "source")? {
+                Some(source) => sources(source)?,
+                None => vec![ApiType::Nlic],
+            };
+            search(session, &api_types, args).await?
+        }
+        "search_precedents" => search(session, &[ApiType::Prec], args).await?,
+        "get_law_detail" => {
+            let detail = detail(session, args).await?;
+            serde_json::to_value(&detail)?
+        }
+        "get_article" => {
+            let wanted = normalize(required(args, "article")?);
+            let detail = detail(session, args).await?;
+            let article = detail
+                .articles
+                .iter()
+                .find(|article| normalize(&article.number) == wanted)
+                .ok_or_else(|| {
+                    WarpError::NotFound(format!("{} {}", detail.law_name, article_label(&wanted)))
+                })?;
+            json!({
+                "law_id": detail.law_id,
+                "law_name": detail.law_name,
+                "article": article,
+            })
+        }
+        "get_history" => {
+            let api_type = source(args)?;
+            let history = session
+                .client(api_type)?
+                .get_history(required(args, "id")?)
+                .await?;
+            serde_json::to_value(&history)?
+        }
+        other => {
+            return Err(WarpError::InvalidInput(format!(
+                "알 수 없는 도구: {}",
+                other
+            )))
+        }
+    };
+    Ok(value)
+}
+
+async fn search(
+    session: &Session,
+    api_types: &[ApiType],
+    args: &Map<String, Value>,
+) -> Result<Value> {
+    let query = required(args, "query")?;
+    let size = match args.get("size") {
+        None | Some(Value::Null) => session.page_size(),
+        Some(size) => size
+            .as_u64()
+            .filter(|&n| (1..=100).contains(&n))
+            .ok_or_else(|| {
+                WarpError::InvalidInput("size는 1에서 100 사이의 정수여야 합니다".to_string())
+            })? as u32,
+    };
+    let items = session.search_sources(api_types, query, size).await?;
+    Ok(json!({
+        "total_count": items.len(),
+        "items": items,
+    }))
+}
+
+async fn detail(session: &Session, args: &Map<String, Value>) -> Result<LawDetail> {
+    let api_type = source(args)?;
+    session
+        .client(api_type)?
+        .get_detail(required(args, "id")?)
+        .await
+}
+
+/// Single source of an ID; national laws by default
+fn source(args: &Map<String, Value>) -> Result<ApiType> {
+    match optional(args, "source")? {
+        None => Ok(ApiType::Nlic),
+        Some(source) => source
+            .parse::<ApiType>()
+            .ok()
+            .filter(|t| *t != ApiType::All)
+            .ok_or_else(|| WarpError::InvalidInput(format!("알 수 없는 소스: {}", source))),
+    }
+}
+
+fn sources(source: &str) -> Result<Vec<ApiType>> {
+    let api_types = parse_source(source);
+    if api_types.is_empty() {
+        return Err(WarpError::InvalidInput(format!(
+            "알 수 없는 소스: {}",
+            source
+        )));
+    }
+    Ok(api_types)
+}
+
+fn required<'a>(args: &'a Map<String, Value>, name: &str) -> Result<&'a str> {
+    optional(args, name)?
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| WarpError::InvalidInput(format!("'{}' 인자가 필요합니다", name)))
+}
+
+fn optional<'a>(args: &'a Map<String, Value>, name: &str) -> Result<Option<&'a str>> {
+    match args.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.trim())),
+        Some(_) => Err(WarpError::InvalidInput(format!(
+            "'{}' 인자는 문자열이어야 합니다",
+            name
+        ))),
+    }
+}
+
+/// "제3조", "3", "제 3 조" → "3"; "제10조의2" → "10의2"
+fn normalize(number: &str) -> String {
+    let number: String = number.chars().filter(|c| !c.is_whitespace()).collect();
+    let number = number.strip_prefix('제').unwrap_or(&number);
+    match number.split_once('조') {
+        Some((main, branch)) => format!("{}{}", main, branch),
+        None => number.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_article_numbers() {
+        assert_eq!(normalize("제3조"), "3");
+        assert_eq!(normalize(" 3 "), "3");
+        assert_eq!(normalize("제10조의2"), "10의2");
+        assert_eq!(normalize("10의2"), "10의2");
+        assert_eq!(article_label(&normalize("제 10 조의 2")), "제10조의2");
+    }
+}
@@ -269,7 +269,7 @@ impl MetricsCollector {
             .map(|(k, v)| (k.clone(), v.to_operation_metrics()))
             .collect();
 
-        ops.sort_by(|a, b| b.1.total_requests.cmp(&a.1.total_requests));
+        ops.sort_by_key(|op| std::cmp::Reverse(op.1.total_requests));
         ops.truncate(limit);
         ops
     }
@@ -282,7 +282,7 @@ impl MetricsCollector {
             .map(|(k, v)| (k.clone(), v.to_operation_metrics()))
             .collect();
 
-        ops.sort_by(|a, b| b.1.avg_duration.cmp(&a.1.avg_duration));
+        ops.sort_by_key(|op| std::cmp::Reverse(op.1.avg_duration));
         ops.truncate(limit);
         ops
     }
@@ -0,0 +1,372 @@
+//! Checkpoints of bulk mirrors of a source
+//!
+//! `warp mirror` pages through every result of a search and saves each
+//! document as JSON. [`Checkpoint`] keeps its progress in a SQLite database
+//! next to the documents: the next page to fetch, the documents already
+//! written and the ones that failed, so an interrupted mirror resumes where it
+//! stopped and a finished one only fetches what is new.
+
+use crate::api::ApiType;
+use crate::error::{Result, WarpError};
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// File name of the checkpoint database, stored in the mirror directory
+pub const CHECKPOINT_FILE: &str = ".warp-mirror.db";
+
+/// What is mirrored; a directory holds the mirror of one spec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorSpec {
+    pub api_type: ApiType,
+    pub query: String,
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+    /// Page offsets depend on it, so it cannot change between runs
+    pub page_size: u32,
+}
+
+/// Progress of a mirror when a run starts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Progress {
+    /// Next page to fetch (1-based)
+    pub next_page: u32,
+    /// Total reported by the source at the last page
+    pub total_count: Option<u32>,
+    /// Documents written so far
+    pub fetched: usize,
+    /// Documents whose detail failed and will be retried
+    pub failed: Vec<String>,
+    /// Whether an earlier run of this mirror exists
+    pub resumed: bool,
+}
+
+/// A document written to the mirror
+#[derive(Debug, Clone)]
+pub struct Fetched {
+    pub id: String,
+    pub title: String,
+    pub file: String,
+}
+
+/// SQLite checkpoint of one mirror directory
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    db_path: PathBuf,
+}
+
+impl Checkpoint {
+    /// Open the checkpoint database, creating the schema if needed
+    pub async fn open<P: AsRef<Path>>(db_path: P) -> Result<Self> {
+        let db_path = db_path.as_ref().to_path_buf();
+        let path = db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let conn = Self::connect(&path)?;
+            conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS mirror (
+                    id INTEGER PRIMARY KEY CHECK (id = 1),
+                    api_type TEXT NOT NULL,
+                    query TEXT NOT NULL,
+                    date_from TEXT,
+                    date_to TEXT,
+                    page_size INTEGER NOT NULL,
+                    next_page INTEGER NOT NULL,
+                    total_count INTEGER,
+                    started_at TEXT NOT NULL,
+                    completed_at TEXT
+                );
+                CREATE TABLE IF NOT EXISTS documents (
+                    id TEXT PRIMARY KEY,
+                    title TEXT NOT NULL,
+                    file TEXT NOT NULL,
+                    fetched_at TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS failures (
+                    id TEXT PRIMARY KEY,
+                    error TEXT NOT NULL,
+                    attempts INTEGER NOT NULL,
+                    failed_at TEXT NOT NULL
+                );
+                "#,
+            )
+            .map_err(|e| WarpError::Other(format!("Failed to create mirror schema: {}", e)))
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn mirror initialization: {}", e)))??;
+
+        Ok(Self { db_path })
+    }
+
+    fn connect(path: &Path) -> Result<Connection> {
+        Connection::open(path)
+            .map_err(|e| WarpError::Other(format!("Failed to open mirror checkpoint: {}", e)))
+    }
+
+    /// Run `f` on a connection off the async runtime
+    async fn with_conn<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T> + Send + 'static,
+    {
+        let db_path = self.db_path.clone();
+        tokio::task::spawn_blocking(move || -> Result<T> {
+            let mut conn = Self::connect(&db_path)?;
+            f(&mut conn)
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn mirror operation: {}", e)))?
+    }
+
+    /// Start or resume the mirror of `spec`
+    ///
+    /// A finished mirror starts again from the first page; documents already
+    /// written are skipped, so only new ones are fetched.
+    pub async fn begin(&self, spec: &MirrorSpec) -> Result<Progress> {
+        let spec = spec.clone();
+        self.with_conn(move |conn| {
+            let stored = conn
+                .query_row(
+                    r#"
+                    SELECT api_type, query, date_from, date_to, page_size,
+                           next_page, total_count, completed_at
+                    FROM mirror WHERE id = 1
+                    "#,
+                    [],
+                    |row| {
+                        Ok((
+                            (
+                                row.get::<_, String>(0)?,
+                                row.get::<_, String>(1)?,
+                                row.get::<_, Option<String>>(2)?,
+                                row.get::<_, Option<String>>(3)?,
+                                row.get::<_, u32>(4)?,
+                            ),
+                            row.get::<_, u32>(5)?,
+                            row.get::<_, Option<u32>>(6)?,
+                            row.get::<_, Option<String>>(7)?,
+                        ))
+                    },
+                )
+                .optional()?;
+
+            let (next_page, total_count, resumed) = match stored {
+                None => {
+                    conn.execute(
+                        r#"
+                        INSERT INTO mirror
+                        (id, api_type, query, date_from, date_to, page_size, next_page, started_at)
+                        VALUES (1, ?1, ?2, ?3, ?4, ?5, 1, ?6)
+                        "#,
+                        params![
+                            spec.api_type.as_str(),
+                            spec.query,
+                            spec.date_from,
+                            spec.date_to,
+                            spec.page_size,
+                            Utc::now().to_rfc3339(),
+                        ],
+                    )?;
+                    (1, None, false)
+                }
+                Some((stored, next_page, total_count, completed_at)) => {
+                    let wanted = (
+                        spec.api_type.as_str().to_string(),
+                        spec.query.clone(),
+                        spec.date_from.clone(),
+                        spec.date_to.clone(),
+                        spec.page_size,
+                    );
+                    if stored != wanted {
+                        return Err(WarpError::InvalidInput(format!(
+                            "이 디렉터리는 다른 조건의 미러입니다 (소스 {}, 검색어 '{}', 기간 {}~{}, 페이지 크기 {}). \
+                             같은 조건으로 실행하거나 다른 디렉터리를 지정하세요",
+                            stored.0,
+                            stored.1,
+                            stored.2.as_deref().unwrap_or(""),
+                            stored.3.as_deref().unwrap_or(""),
+                            stored.4
+                        )));
+                    }
+                    if completed_at.is_some() {
+                        conn.execute(
+                            "UPDATE mirror SET next_page = 1, completed_at = NULL WHERE id = 1",
+                            [],
+                        )?;
+                        (1, total_count, true)
+                    } else {
+                        (next_page, total_count, true)
+                    }
+                }
+            };
+
+            let fetched: usize =
+                conn.query_row("SELECT COUNT(*) FROM documents", [], |row| row.get(0))?;
+            let mut stmt = conn.prepare("SELECT id FROM failures ORDER BY failed_at, id")?;
+            let failed = stmt
+                .query_map([], |row| row.get(0))?
+                .collect::<rusqlite::Result<Vec<String>>>()?;
+
+            Ok(Progress {
+                next_page,
+                total_count,
+                fetched,
+                failed,
+                resumed,
+            })
+        })
+        .await
+    }
+
+    /// Which of `ids` were already written
+    pub async fn fetched(&self, ids: Vec<String>) -> Result<HashSet<String>> {
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare("SELECT 1 FROM documents WHERE id = ?1")?;
+            let mut fetched = HashSet::new();
+            for id in ids {
+                if stmt.exists(params![id])? {
+                    fetched.insert(id);
+                }
+            }
+            Ok(fetched)
+        })
+        .await
+    }
+
+    /// Record written and failed documents, and the page they came from
+    ///
+    /// With `page`, the next run continues after it. Both happen in one
+    /// transaction, so an interruption never skips a page.
+    pub async fn record(
+        &self,
+        page: Option<(u32, u32)>,
+        fetched: Vec<Fetched>,
+        failed: Vec<(String, String)>,
+    ) -> Result<()> {
+        self.with_conn(move |conn| {
+            let now = Utc::now().to_rfc3339();
+            let tx = conn.transaction()?;
+            for doc in &fetched {
+                tx.execute(
+                    "INSERT OR REPLACE INTO documents (id, title, file, fetched_at) VALUES (?1, ?2, ?3, ?4)",
+                    params![doc.id, doc.title, doc.file, now],
+                )?;
+                tx.execute("DELETE FROM failures WHERE id = ?1", params![doc.id])?;
+            }
+            for (id, error) in &failed {
+                tx.execute(
+                    r#"
+                    INSERT INTO failures (id, error, attempts, failed_at) VALUES (?1, ?2, 1, ?3)
+                    ON CONFLICT(id) DO UPDATE SET
+                        error = excluded.error,
+                        attempts = attempts + 1,
+                        failed_at = excluded.failed_at
+                    "#,
+                    params![id, error, now],
+                )?;
+            }
+            if let Some((page, total_count)) = page {
+                tx.execute(
+                    "UPDATE mirror SET next_page = ?1, total_count = ?2 WHERE id = 1",
+                    params![page + 1, total_count],
+                )?;
+            }
+            tx.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Mark every page as walked
+    pub async fn finish(&self) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                "UPDATE mirror SET completed_at = ?1 WHERE id = 1",
+                params![Utc::now().to_rfc3339()],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn spec(query: &str) -> MirrorSpec {
+        MirrorSpec {
+            api_type: ApiType::Prec,
+            query: query.to_string(),
+            date_from: Some("20100101".to_string()),
+            date_to: Some("20241231".to_string()),
+            page_size: 100,
+        }
+    }
+
+    fn doc(id: &str) -> Fetched {
+        Fetched {
+            id: id.to_string(),
+            title: format!("사건 {}", id),
+            file: format!("{}.json", id),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_resumes_after_last_recorded_page() {
+        let dir = TempDir::new().unwrap();
+        let checkpoint = Checkpoint::open(dir.path().join(CHECKPOINT_FILE))
+            .await
+            .unwrap();
+
+        let progress = checkpoint.begin(&spec("명예훼손")).await.unwrap();
+        assert_eq!((progress.next_page, progress.resumed), (1, false));
+
+        checkpoint
+            .record(
+                Some((1, 250)),
+                vec![doc("1"), doc("2")],
+                vec![("3".to_string(), "timeout".to_string())],
+            )
+            .await
+            .unwrap();
+        checkpoint
+            .record(Some((2, 250)), vec![doc("4")], Vec::new())
+            .await
+            .unwrap();
+
+        let progress = checkpoint.begin(&spec("명예훼손")).await.unwrap();
+        assert_eq!(progress.next_page, 3);
+        assert_eq!(progress.total_count, Some(250));
+        assert_eq!(progress.fetched, 3);
+        assert_eq!(progress.failed, vec!["3".to_string()]);
+        assert!(progress.resumed);
+
+        let fetched = checkpoint
+            .fetched(vec!["1".to_string(), "3".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(fetched, HashSet::from(["1".to_string()]));
+
+        // A retried failure leaves the failure list; a finished mirror restarts at page 1
+        checkpoint
+            .record(None, vec![doc("3")], Vec::new())
+            .await
+            .unwrap();
+        checkpoint.finish().await.unwrap();
+        let progress = checkpoint.begin(&spec("명예훼손")).await.unwrap();
+        assert_eq!(progress.next_page, 1);
+        assert_eq!(progress.fetched, 4);
+        assert!(progress.failed.is_empty());
+
+        let err = checkpoint.begin(&spec("모욕")).await.unwrap_err();
+        assert!(err.to_string().contains("다른 조건"));
+    }
+}
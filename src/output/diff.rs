@@ -0,0 +1,717 @@
+//! Article-aligned comparison of two versions of a law
+//!
+//! Articles are matched by number, so a revision that inserts 제3조의2 shows
+//! up as one added article rather than as every later article shifting.
+//! Changed articles carry a word-level diff; Korean text is compared by
+//! 어절 (space-separated words), which keeps particles with their nouns.
+
+use colored::*;
+use serde::Serialize;
+use similar::{Algorithm, ChangeTag, TextDiff};
+use std::collections::HashMap;
+
+use crate::api::types::{Article, HistoryEntry, LawDetail, LawHistory};
+use crate::error::{Result, WarpError};
+use crate::output::formatter::escape_html;
+use crate::output::report::division_heading;
+use crate::output::vault::article_label;
+
+/// Lines of context around each hunk of a unified diff
+const UNIFIED_CONTEXT: usize = 3;
+
+const STYLE: &str = r#"
+body { font-family: 'Malgun Gothic', 'Apple SD Gothic Neo', 'Noto Sans KR', sans-serif;
+       max-width: 860px; margin: 0 auto; padding: 24px; line-height: 1.7; color: #222; }
+h1 { color: #1f4e79; margin-bottom: 4px; }
+.versions { color: #666; margin-top: 0; }
+section { margin: 16px 0; border-left: 3px solid #d0d7de; padding-left: 12px; }
+section.added { border-color: #1a7f37; } section.removed { border-color: #cf222e; }
+section.changed { border-color: #9a6700; }
+h2 { font-size: 1.05em; margin: 0 0 4px; }
+.kind { font-weight: normal; color: #666; }
+p { margin: 4px 0; white-space: pre-wrap; }
+ins { background: #dafbe1; text-decoration: none; }
+del { background: #ffebe9; color: #82071e; }
+"#;
+
+/// One side of the comparison
+#[derive(Debug, Clone, Serialize)]
+pub struct Version {
+    /// ID the version was fetched with
+    pub id: String,
+    /// Promulgation date of the revision
+    pub date: Option<String>,
+    /// Kind of revision, e.g. 일부개정
+    pub kind: Option<String>,
+}
+
+impl Version {
+    /// "20230526 일부개정"
+    pub fn label(&self) -> String {
+        let parts: Vec<&str> = [self.date.as_deref(), self.kind.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .collect();
+        if parts.is_empty() {
+            return self.id.clone();
+        }
+        parts.join(" ")
+    }
+}
+
+/// How an article differs between the versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Added => "신설",
+            Self::Removed => "삭제",
+            Self::Changed => "변경",
+        }
+    }
+}
+
+/// A run of words that is kept, inserted or deleted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Segment {
+    /// `equal`, `insert` or `delete`
+    pub op: &'static str,
+    pub text: String,
+}
+
+/// An article that differs between the versions
+#[derive(Debug, Clone, Serialize)]
+pub struct ArticleDiff {
+    /// Article number as in the newer version, e.g. 제3조
+    pub number: String,
+    pub title: Option<String>,
+    pub kind: ChangeKind,
+    /// Text in the older version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    /// Text in the newer version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+    /// Word-level changes from `old` to `new`, for changed articles
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<Segment>,
+}
+
+/// Differences between two versions of a law
+#[derive(Debug, Clone, Serialize)]
+pub struct LawDiff {
+    pub law_name: String,
+    pub from: Version,
+    pub to: Version,
+    pub articles: Vec<ArticleDiff>,
+    /// Full text of both versions, for the unified diff
+    #[serde(skip)]
+    texts: (String, String),
+}
+
+impl LawDiff {
+    /// Compare `old` with `new`, article by article
+    pub fn between(old: &LawDetail, new: &LawDetail, from: Version, to: Version) -> Self {
+        let old_articles = articles(old);
+        let new_articles = articles(new);
+        let old_index: HashMap<String, usize> = old_articles
+            .iter()
+            .enumerate()
+            .map(|(i, article)| (article_key(&article.number), i))
+            .collect();
+        let new_keys: std::collections::HashSet<String> = new_articles
+            .iter()
+            .map(|article| article_key(&article.number))
+            .collect();
+
+        let mut diffs = Vec::new();
+        // Removed articles are listed where they stood in the older version
+        let mut next_old = 0;
+        let mut emit_removed_until = |end: usize, diffs: &mut Vec<ArticleDiff>| {
+            while next_old < end {
+                let article = old_articles[next_old];
+                if !new_keys.contains(&article_key(&article.number)) {
+                    diffs.push(ArticleDiff {
+                        number: article.number.clone(),
+                        title: title(article),
+                        kind: ChangeKind::Removed,
+                        old: Some(article_text(article)),
+                        new: None,
+                        segments: Vec::new(),
+                    });
+                }
+                next_old += 1;
+            }
+        };
+
+        for article in &new_articles {
+            match old_index.get(&article_key(&article.number)) {
+                Some(&i) => {
+                    emit_removed_until(i, &mut diffs);
+                    let (before, after) = (article_text(old_articles[i]), article_text(article));
+                    if before != after {
+                        diffs.push(ArticleDiff {
+                            number: article.number.clone(),
+                            title: title(article),
+                            kind: ChangeKind::Changed,
+                            segments: word_diff(&before, &after),
+                            old: Some(before),
+                            new: Some(after),
+                        });
+                    }
+                }
+                None => diffs.push(ArticleDiff {
+                    number: article.number.clone(),
+                    title: title(article),
+                    kind: ChangeKind::Added,
+                    old: None,
+                    new: Some(article_text(article)),
+                    segments: Vec::new(),
+                }),
+            }
+        }
+        emit_removed_until(old_articles.len(), &mut diffs);
+
+        Self {
+            law_name: new.law_name.clone(),
+            from,
+            to,
+            articles: diffs,
+            texts: (full_text(old), full_text(new)),
+        }
+    }
+
+    /// Number of added, removed and changed articles
+    pub fn counts(&self) -> (usize, usize, usize) {
+        let count = |kind| self.articles.iter().filter(|a| a.kind == kind).count();
+        (
+            count(ChangeKind::Added),
+            count(ChangeKind::Removed),
+            count(ChangeKind::Changed),
+        )
+    }
+
+    fn summary(&self) -> String {
+        let (added, removed, changed) = self.counts();
+        format!("신설 {} · 삭제 {} · 변경 {}", added, removed, changed)
+    }
+
+    /// Coloured text for the terminal; `[-삭제-]{+추가+}` marks when colours are off
+    pub fn render_terminal(&self) -> String {
+        let colorize = colored::control::SHOULD_COLORIZE.should_colorize();
+        let mut out = format!(
+            "\n{} {}\n{} → {}   {}\n",
+            "⚖️".cyan(),
+            self.law_name.bold(),
+            self.from.label().red(),
+            self.to.label().green(),
+            self.summary().dimmed()
+        );
+        if self.articles.is_empty() {
+            out.push_str("\n조문 변경 없음\n");
+            return out;
+        }
+
+        for article in &self.articles {
+            let heading = heading(article);
+            out.push('\n');
+            match article.kind {
+                ChangeKind::Added => {
+                    out.push_str(&format!(
+                        "{} {}\n",
+                        "+".green().bold(),
+                        heading.green().bold()
+                    ));
+                    out.push_str(
+                        &indent(article.new.as_deref().unwrap_or_default())
+                            .green()
+                            .to_string(),
+                    );
+                }
+                ChangeKind::Removed => {
+                    out.push_str(&format!("{} {}\n", "-".red().bold(), heading.red().bold()));
+                    out.push_str(
+                        &indent(article.old.as_deref().unwrap_or_default())
+                            .red()
+                            .to_string(),
+                    );
+                }
+                ChangeKind::Changed => {
+                    out.push_str(&format!(
+                        "{} {}\n",
+                        "~".yellow().bold(),
+                        heading.yellow().bold()
+                    ));
+                    let mut text = String::new();
+                    for segment in &article.segments {
+                        text.push_str(&match (segment.op, colorize) {
+                            ("insert", true) => segment.text.green().underline().to_string(),
+                            ("delete", true) => segment.text.red().strikethrough().to_string(),
+                            ("insert", false) => format!("{{+{}+}}", segment.text),
+                            ("delete", false) => format!("[-{}-]", segment.text),
+                            _ => segment.text.clone(),
+                        });
+                    }
+                    out.push_str(&indent(&text));
+                }
+            }
+        }
+        out
+    }
+
+    /// Unified diff of the full text, as `diff -u` would print it
+    pub fn render_unified(&self) -> String {
+        let (old, new) = &self.texts;
+        TextDiff::from_lines(old, new)
+            .unified_diff()
+            .context_radius(UNIFIED_CONTEXT)
+            .header(
+                &format!("{} ({})", self.law_name, self.from.label()),
+                &format!("{} ({})", self.law_name, self.to.label()),
+            )
+            .to_string()
+    }
+
+    /// Markdown with ~~deleted~~ and **inserted** words
+    pub fn render_markdown(&self) -> String {
+        let mut md = format!("# {} 신구 비교\n\n", self.law_name);
+        md.push_str("| 구분 | 버전 | ID |\n|------|------|----|\n");
+        md.push_str(&format!(
+            "| 이전 | {} | {} |\n",
+            self.from.label(),
+            self.from.id
+        ));
+        md.push_str(&format!(
+            "| 이후 | {} | {} |\n\n",
+            self.to.label(),
+            self.to.id
+        ));
+        md.push_str(&format!("{}\n", self.summary()));
+
+        for article in &self.articles {
+            md.push_str(&format!(
+                "\n## {} ({})\n\n",
+                heading(article),
+                article.kind.label()
+            ));
+            let text = match article.kind {
+                ChangeKind::Added => article.new.clone().unwrap_or_default(),
+                ChangeKind::Removed => strike(article.old.as_deref().unwrap_or_default()),
+                ChangeKind::Changed => article
+                    .segments
+                    .iter()
+                    .map(|segment| match segment.op {
+                        "insert" => bold(&segment.text),
+                        "delete" => strike(&segment.text),
+                        _ => segment.text.clone(),
+                    })
+                    .collect(),
+            };
+            for line in text.lines() {
+                md.push_str(&format!("> {}\n", line));
+            }
+        }
+        md
+    }
+
+    /// Standalone HTML with <del> and <ins>
+    pub fn render_html(&self) -> String {
+        let name = escape_html(&self.law_name);
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"UTF-8\">\n");
+        html.push_str(&format!("<title>{} 신구 비교</title>\n", name));
+        html.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", STYLE));
+        html.push_str(&format!("<h1>{} 신구 비교</h1>\n", name));
+        html.push_str(&format!(
+            "<p class=\"versions\"><del>{}</del> → <ins>{}</ins> · {}</p>\n",
+            escape_html(&self.from.label()),
+            escape_html(&self.to.label()),
+            self.summary()
+        ));
+
+        for article in &self.articles {
+            let class = match article.kind {
+                ChangeKind::Added => "added",
+                ChangeKind::Removed => "removed",
+                ChangeKind::Changed => "changed",
+            };
+            html.push_str(&format!(
+                "<section class=\"{}\">\n<h2>{} <span class=\"kind\">{}</span></h2>\n<p>",
+                class,
+                escape_html(&heading(article)),
+                article.kind.label()
+            ));
+            match article.kind {
+                ChangeKind::Added => html.push_str(&format!(
+                    "<ins>{}</ins>",
+                    escape_html(article.new.as_deref().unwrap_or_default())
+                )),
+                ChangeKind::Removed => html.push_str(&format!(
+                    "<del>{}</del>",
+                    escape_html(article.old.as_deref().unwrap_or_default())
+                )),
+                ChangeKind::Changed => {
+                    for segment in &article.segments {
+                        let text = escape_html(&segment.text);
+                        html.push_str(&match segment.op {
+                            "insert" => format!("<ins>{}</ins>", text),
+                            "delete" => format!("<del>{}</del>", text),
+                            _ => text,
+                        });
+                    }
+                }
+            }
+            html.push_str("</p>\n</section>\n");
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+/// History entry named by `--from`/`--to`
+///
+/// `spec` is `latest`, a date (the last revision promulgated by then, e.g.
+/// 20230101 or 2023-01-01), a revision number from `warp law history`, or a
+/// version ID.
+pub fn find_revision<'a>(history: &'a LawHistory, spec: &str) -> Result<&'a HistoryEntry> {
+    let spec = spec.trim();
+    let digits = |s: &str| s.chars().filter(char::is_ascii_digit).collect::<String>();
+    let by_date = |entry: &&HistoryEntry| (digits(&entry.revision_date), entry.revision_no);
+
+    let found = match spec {
+        "latest" | "current" | "최신" => history.entries.iter().max_by_key(by_date),
+        _ if spec.len() >= 8
+            && spec
+                .chars()
+                .all(|c| c.is_ascii_digit() || "-./".contains(c)) =>
+        {
+            let date = digits(spec);
+            let found = history
+                .entries
+                .iter()
+                .filter(|entry| digits(&entry.revision_date) <= date)
+                .max_by_key(by_date);
+            if found.is_none() {
+                return Err(WarpError::NotFound(format!("{} 이전의 개정", spec)));
+            }
+            found
+        }
+        _ => history
+            .entries
+            .iter()
+            .find(|entry| spec.parse() == Ok(entry.revision_no))
+            .or_else(|| {
+                history
+                    .entries
+                    .iter()
+                    .find(|entry| entry.version_id.as_deref() == Some(spec))
+            }),
+    };
+    found.ok_or_else(|| {
+        WarpError::InvalidInput(format!(
+            "'{}'에 해당하는 개정이 없습니다 (날짜 YYYYMMDD, 연혁 순번, latest 중 하나로 지정하세요)",
+            spec
+        ))
+    })
+}
+
+/// Articles proper, without chapter and section headings
+fn articles(detail: &LawDetail) -> Vec<&Article> {
+    detail
+        .articles
+        .iter()
+        .filter(|article| division_heading(article).is_none())
+        .collect()
+}
+
+/// "3", "제3조" and "제 3 조" all match
+fn article_key(number: &str) -> String {
+    let number: String = number.chars().filter(|c| !c.is_whitespace()).collect();
+    article_label(&number)
+}
+
+fn title(article: &Article) -> Option<String> {
+    article.title.clone().filter(|t| !t.is_empty())
+}
+
+/// Title and body of an article, compared as one text
+fn article_text(article: &Article) -> String {
+    let mut text = match title(article) {
+        Some(title) => format!("({})\n{}", title, article.content.trim()),
+        None => article.content.trim().to_string(),
+    };
+    for paragraph in &article.paragraphs {
+        for part in std::iter::once(&paragraph.content).chain(paragraph.items.iter()) {
+            if !part.is_empty() && !text.contains(part.as_str()) {
+                text.push('\n');
+                text.push_str(part.trim());
+            }
+        }
+    }
+    text
+}
+
+fn full_text(detail: &LawDetail) -> String {
+    detail
+        .articles
+        .iter()
+        .map(|article| match division_heading(article) {
+            Some((_, heading)) => format!("{}\n", heading),
+            None => {
+                let text = article_text(article);
+                // The APIs usually repeat the number at the start of the text
+                if text.starts_with('제') {
+                    format!("{}\n", text)
+                } else {
+                    format!("{}{}\n", article_label(&article.number), text)
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Word-level changes, with neighbouring words of the same kind merged
+fn word_diff(old: &str, new: &str) -> Vec<Segment> {
+    let diff = TextDiff::configure()
+        .algorithm(Algorithm::Patience)
+        .diff_words(old, new);
+    let mut segments: Vec<Segment> = Vec::new();
+    for change in diff.iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => "equal",
+            ChangeTag::Insert => "insert",
+            ChangeTag::Delete => "delete",
+        };
+        match segments.last_mut() {
+            Some(last) if last.op == op => last.text.push_str(change.value()),
+            _ => segments.push(Segment {
+                op,
+                text: change.value().to_string(),
+            }),
+        }
+    }
+    segments
+}
+
+fn heading(article: &ArticleDiff) -> String {
+    match &article.title {
+        Some(title) => format!("{}({})", article_label(&article.number), title),
+        None => article_label(&article.number),
+    }
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("    {}\n", line)).collect()
+}
+
+/// Wrap non-blank runs in `**`, keeping surrounding spaces outside the markers
+fn bold(text: &str) -> String {
+    wrap(text, "**")
+}
+
+fn strike(text: &str) -> String {
+    wrap(text, "~~")
+}
+
+fn wrap(text: &str, marker: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                return line.to_string();
+            }
+            let start = line.len() - line.trim_start().len();
+            let end = start + trimmed.len();
+            format!(
+                "{}{}{}{}{}",
+                &line[..start],
+                marker,
+                trimmed,
+                marker,
+                &line[end..]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn article(number: &str, title: Option<&str>, content: &str) -> Article {
+        Article {
+            number: number.to_string(),
+            title: title.map(str::to_string),
+            content: content.to_string(),
+            paragraphs: vec![],
+        }
+    }
+
+    fn detail(articles: Vec<Article>) -> LawDetail {
+        LawDetail {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles,
+            attachments: vec![],
+            related_laws: vec![],
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        }
+    }
+
+    fn version(id: &str, date: &str) -> Version {
+        Version {
+            id: id.to_string(),
+            date: Some(date.to_string()),
+            kind: Some("일부개정".to_string()),
+        }
+    }
+
+    fn sample() -> LawDiff {
+        let old = detail(vec![
+            article("제1장 총칙", None, "제1장 총칙"),
+            article(
+                "1",
+                Some("목적"),
+                "이 법은 개인정보의 처리에 관한 사항을 정한다.",
+            ),
+            article(
+                "2",
+                Some("정의"),
+                "이 법에서 사용하는 용어의 뜻은 다음과 같다.",
+            ),
+            article(
+                "3",
+                Some("보호 원칙"),
+                "개인정보처리자는 목적을 명확하게 하여야 한다.",
+            ),
+        ]);
+        let new = detail(vec![
+            article("제1장 총칙", None, "제1장 총칙"),
+            article(
+                "제1조",
+                Some("목적"),
+                "이 법은 개인정보의 처리에 관한 사항을 정한다.",
+            ),
+            article(
+                "3",
+                Some("보호 원칙"),
+                "개인정보처리자는 처리 목적을 명확하게 하여야 한다.",
+            ),
+            article("3의2", Some("공개"), "처리방침을 공개하여야 한다."),
+        ]);
+        LawDiff::between(
+            &old,
+            &new,
+            version("100", "20200101"),
+            version("200", "20230314"),
+        )
+    }
+
+    #[test]
+    fn test_articles_are_aligned_by_number() {
+        let diff = sample();
+        let kinds: Vec<(&str, ChangeKind)> = diff
+            .articles
+            .iter()
+            .map(|a| (a.number.as_str(), a.kind))
+            .collect();
+        // 제1조 only changed its number format; the chapter heading is ignored
+        assert_eq!(
+            kinds,
+            [
+                ("2", ChangeKind::Removed),
+                ("3", ChangeKind::Changed),
+                ("3의2", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(diff.counts(), (1, 1, 1));
+
+        let changed = &diff.articles[1];
+        let inserted: Vec<&str> = changed
+            .segments
+            .iter()
+            .filter(|s| s.op == "insert")
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(inserted, ["처리 "]);
+        assert!(changed.segments.iter().all(|s| s.op != "delete"));
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let diff = sample();
+
+        let md = diff.render_markdown();
+        assert!(md.contains("# 민법 신구 비교"));
+        assert!(md.contains("## 제2조(정의) (삭제)\n\n> ~~(정의)~~"));
+        assert!(md.contains("> 개인정보처리자는 **처리** 목적을"));
+
+        let html = diff.render_html();
+        assert!(html.contains("<h2>제3조의2(공개) <span class=\"kind\">신설</span></h2>"));
+        assert!(html.contains("개인정보처리자는 <ins>처리 </ins>목적을"));
+
+        let unified = diff.render_unified();
+        assert!(unified.starts_with("--- 민법 (20200101 일부개정)\n+++ 민법 (20230314 일부개정)"));
+        assert!(unified.contains("-개인정보처리자는 목적을 명확하게 하여야 한다."));
+        assert!(unified.contains("+제3조의2(공개)"));
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["articles"][0]["kind"], "removed");
+        assert_eq!(json["to"]["id"], "200");
+    }
+
+    #[test]
+    fn test_find_revision() {
+        let entry = |no: u32, date: &str, id: &str| HistoryEntry {
+            revision_no: no,
+            revision_date: date.to_string(),
+            enforcement_date: None,
+            revision_type: "일부개정".to_string(),
+            reason: None,
+            changed_articles: vec![],
+            version_id: Some(id.to_string()),
+        };
+        let history = LawHistory {
+            law_id: "011357".to_string(),
+            law_name: "개인정보 보호법".to_string(),
+            total_count: 3,
+            entries: vec![
+                entry(1, "20110329", "111"),
+                entry(2, "20200204", "222"),
+                entry(3, "20230314", "333"),
+            ],
+            stale: false,
+        };
+        let id = |spec: &str| {
+            find_revision(&history, spec)
+                .ok()
+                .and_then(|e| e.version_id.clone())
+        };
+        assert_eq!(id("latest").as_deref(), Some("333"));
+        assert_eq!(id("20221231").as_deref(), Some("222"));
+        assert_eq!(id("2020-02-04").as_deref(), Some("222"));
+        assert_eq!(id("1").as_deref(), Some("111"));
+        assert_eq!(id("333").as_deref(), Some("333"));
+        assert!(find_revision(&history, "20000101").is_err());
+        assert!(find_revision(&history, "9").is_err());
+    }
+}
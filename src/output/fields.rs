@@ -0,0 +1,328 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+use crate::api::types::{LawDetail, LawHistory, SearchItem, SearchResponse};
+use crate::error::{Result, WarpError};
+
+/// Fields of a search result
+pub const SEARCH_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "law_no",
+    "law_type",
+    "department",
+    "enforcement_date",
+    "revision_date",
+    "summary",
+    "source",
+    "metadata",
+    "score",
+    "sources",
+];
+
+/// Fields of a law detail
+pub const DETAIL_FIELDS: &[&str] = &[
+    "law_id",
+    "law_name",
+    "law_no",
+    "law_type",
+    "department",
+    "enforcement_date",
+    "revision_date",
+    "content",
+    "articles",
+    "attachments",
+    "related_laws",
+    "metadata",
+];
+
+/// Fields of a history entry
+pub const HISTORY_FIELDS: &[&str] = &[
+    "revision_no",
+    "revision_date",
+    "enforcement_date",
+    "revision_type",
+    "reason",
+    "changed_articles",
+];
+
+/// Columns requested with `--fields`, in order
+///
+/// A field is a top-level name (`title`) or a dotted path into a nested
+/// value (`metadata.detail_link`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSelection {
+    fields: Vec<String>,
+}
+
+/// Records reduced to the selected fields
+///
+/// `key` names the list the records came from (`items`, `entries`); a
+/// detail is a single record without a key.
+#[derive(Debug)]
+pub struct Projection {
+    pub key: Option<&'static str>,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+/// One projected record; serializes as a map in column order
+pub struct Record<'a> {
+    columns: &'a [String],
+    values: &'a [Value],
+    skip_nulls: bool,
+}
+
+/// A projection for formats without null (TOML); missing fields are left
+/// out of each record
+pub struct WithoutNulls<'a>(pub &'a Projection);
+
+impl FieldSelection {
+    pub fn new<I, S>(fields: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields: Vec<String> = fields
+            .into_iter()
+            .map(|f| f.as_ref().trim().to_string())
+            .filter(|f| !f.is_empty())
+            .collect();
+        if fields.is_empty() {
+            return Err(WarpError::InvalidInput(
+                "--fields에 최소 한 개의 필드를 지정하세요".to_string(),
+            ));
+        }
+        Ok(Self { fields })
+    }
+
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    /// Project search results, one row per item
+    pub fn project_search(&self, response: &SearchResponse) -> Result<Projection> {
+        self.check(SEARCH_FIELDS, "검색 결과")?;
+        let rows = response
+            .items
+            .iter()
+            .map(|item| self.row(item))
+            .collect::<Result<_>>()?;
+        Ok(self.projection(Some("items"), rows))
+    }
+
+    /// Project a law detail into a single row
+    pub fn project_detail(&self, detail: &LawDetail) -> Result<Projection> {
+        self.check(DETAIL_FIELDS, "상세 정보")?;
+        Ok(self.projection(None, vec![self.row(detail)?]))
+    }
+
+    /// Project history, one row per revision
+    pub fn project_history(&self, history: &LawHistory) -> Result<Projection> {
+        self.check(HISTORY_FIELDS, "개정 연혁")?;
+        let rows = history
+            .entries
+            .iter()
+            .map(|entry| self.row(entry))
+            .collect::<Result<_>>()?;
+        Ok(self.projection(Some("entries"), rows))
+    }
+
+    /// Project one search item, for streaming output
+    pub fn project_item(&self, item: &SearchItem) -> Result<Projection> {
+        self.check(SEARCH_FIELDS, "검색 결과")?;
+        Ok(self.projection(None, vec![self.row(item)?]))
+    }
+
+    fn projection(&self, key: Option<&'static str>, rows: Vec<Vec<Value>>) -> Projection {
+        Projection {
+            key,
+            columns: self.fields.clone(),
+            rows,
+        }
+    }
+
+    fn row<T: serde::Serialize>(&self, record: &T) -> Result<Vec<Value>> {
+        let value = serde_json::to_value(record)?;
+        Ok(self
+            .fields
+            .iter()
+            .map(|field| lookup(&value, field))
+            .collect())
+    }
+
+    /// Reject unknown top-level fields so typos do not yield empty columns
+    fn check(&self, known: &[&str], what: &str) -> Result<()> {
+        for field in &self.fields {
+            let head = field.split('.').next().unwrap_or(field);
+            if !known.contains(&head) {
+                return Err(WarpError::InvalidInput(format!(
+                    "{}에 '{}' 필드가 없습니다 (사용 가능: {})",
+                    what,
+                    field,
+                    known.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Projection {
+    /// Records as serializable maps
+    pub fn records(&self) -> impl Iterator<Item = Record<'_>> {
+        self.records_with(false)
+    }
+
+    fn records_with(&self, skip_nulls: bool) -> impl Iterator<Item = Record<'_>> {
+        self.rows.iter().map(move |values| Record {
+            columns: &self.columns,
+            values,
+            skip_nulls,
+        })
+    }
+
+    fn serialize_records<S: Serializer>(
+        &self,
+        skip_nulls: bool,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self.key {
+            Some(key) => {
+                let records: Vec<Record<'_>> = self.records_with(skip_nulls).collect();
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(key, &records)?;
+                map.end()
+            }
+            None => match self.records_with(skip_nulls).next() {
+                Some(record) => record.serialize(serializer),
+                None => serializer.serialize_map(Some(0))?.end(),
+            },
+        }
+    }
+
+    /// Rows rendered as text cells
+    pub fn text_rows(&self) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|row| row.iter().map(cell_text).collect())
+            .collect()
+    }
+}
+
+impl Serialize for Record<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (column, value) in self.columns.iter().zip(self.values) {
+            if self.skip_nulls && value.is_null() {
+                continue;
+            }
+            map.serialize_entry(column, value)?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes like the unprojected value: `{"items": [...]}`, a bare
+/// record for details
+impl Serialize for Projection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.serialize_records(false, serializer)
+    }
+}
+
+impl Serialize for WithoutNulls<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.0.serialize_records(true, serializer)
+    }
+}
+
+/// Follow a dotted path; missing parts yield null
+fn lookup(value: &Value, path: &str) -> Value {
+    path.split('.')
+        .try_fold(value, |current, part| match current {
+            Value::Object(map) => map.get(part),
+            Value::Array(items) => part.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+/// Text for a table or CSV cell
+pub fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.iter().all(Value::is_string) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn item() -> SearchItem {
+        SearchItem {
+            id: "001706".to_string(),
+            title: "민법".to_string(),
+            law_no: None,
+            law_type: Some("법률".to_string()),
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: "NLIC".to_string(),
+            metadata: HashMap::from([("detail_link".to_string(), "/DRF/x".to_string())]),
+            score: None,
+            sources: vec!["NLIC".to_string(), "ELIS".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_project_item_follows_paths_in_order() {
+        let fields =
+            FieldSelection::new(["metadata.detail_link", "title", "law_no", "sources"]).unwrap();
+        let projection = fields.project_item(&item()).unwrap();
+        assert_eq!(
+            projection.text_rows(),
+            vec![vec!["/DRF/x", "민법", "", "NLIC, ELIS"]]
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        let fields = FieldSelection::new(["titel"]).unwrap();
+        assert!(fields.project_item(&item()).is_err());
+        assert!(FieldSelection::new([" ", ""]).is_err());
+    }
+
+    #[test]
+    fn test_projection_serializes_in_column_order() {
+        let fields = FieldSelection::new(["title", "id"]).unwrap();
+        let response = SearchResponse {
+            total_count: 1,
+            page_no: 1,
+            page_size: 10,
+            items: vec![item()],
+            source: "NLIC".to_string(),
+            timestamp: chrono::Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+        assert_eq!(
+            serde_json::to_string(&fields.project_search(&response).unwrap()).unwrap(),
+            r#"{"items":[{"title":"민법","id":"001706"}]}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&fields.project_item(&item()).unwrap()).unwrap(),
+            r#"{"title":"민법","id":"001706"}"#
+        );
+    }
+}
@@ -2,9 +2,15 @@ use colored::*;
 use comfy_table::{Cell, Color, ContentArrangement, Table};
 use serde_json;
 
-use crate::api::types::{LawDetail, LawHistory, SearchResponse};
+use crate::api::types::{Article, LawDetail, LawHistory, SearchResponse};
+use crate::api::ApiType;
 use crate::cli::OutputFormat;
 use crate::error::Result;
+use crate::output::fields::{FieldSelection, Projection, WithoutNulls};
+use crate::output::template::Template;
+
+/// Notice shown when a response was served from an expired cache entry
+pub(crate) const STALE_NOTICE: &str = "캐시된 이전 결과입니다 (최신 정보가 아닐 수 있음)";
 
 /// Manual div_ceil implementation for MSRV 1.70.0 compatibility
 /// Can be replaced with u32::div_ceil when MSRV is increased to 1.73.0+
@@ -17,49 +23,245 @@ fn div_ceil(dividend: u32, divisor: u32) -> u32 {
     (dividend + divisor - 1) / divisor
 }
 
+/// Serialize a value as a single line of JSON
+pub fn to_json_line<T: serde::Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string(value).map_err(crate::error::WarpError::Serialization)
+}
+
+/// Serialize a value as YAML
+pub fn to_yaml<T: serde::Serialize>(value: &T) -> Result<String> {
+    serde_yaml::to_string(value)
+        .map_err(|e| crate::error::WarpError::Other(format!("YAML 변환 오류: {}", e)))
+}
+
+/// Serialize a value as a TOML document
+pub fn to_toml<T: serde::Serialize>(value: &T) -> Result<String> {
+    toml::to_string_pretty(value)
+        .map_err(|e| crate::error::WarpError::Other(format!("TOML 변환 오류: {}", e)))
+}
+
+/// XLSX is written as bytes by `output::print_*`, never as text
+fn binary_format_error() -> crate::error::WarpError {
+    crate::error::WarpError::InvalidInput(
+        "XLSX는 바이너리 형식이라 텍스트로 변환할 수 없습니다".to_string(),
+    )
+}
+
+#[derive(Debug, Clone)]
 pub struct Formatter {
     format: OutputFormat,
+    source: Option<ApiType>,
+    fields: Option<FieldSelection>,
+    template: Option<Template>,
 }
 
 impl Formatter {
     pub fn new(format: OutputFormat) -> Self {
-        Self { format }
+        Self {
+            format,
+            source: None,
+            fields: None,
+            template: None,
+        }
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    pub fn fields(&self) -> Option<&FieldSelection> {
+        self.fields.as_ref()
+    }
+
+    pub fn template(&self) -> Option<&Template> {
+        self.template.as_ref()
+    }
+
+    /// Switch to another format, keeping the other options
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Name the API the formatted data came from, used to link back to law.go.kr
+    pub fn with_source(mut self, api_type: ApiType) -> Self {
+        self.source = Some(api_type);
+        self
+    }
+
+    /// Output only the given fields, in order
+    pub fn with_fields(mut self, fields: FieldSelection) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Render through a template instead of the format
+    pub fn with_template(mut self, template: Template) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Whether output is a binary document (XLSX) rather than text
+    pub fn writes_binary(&self) -> bool {
+        matches!(self.format, OutputFormat::Xlsx) && self.template.is_none()
     }
 
     /// Format search response
     pub fn format_search(&self, response: &SearchResponse) -> Result<String> {
+        if let Some(template) = &self.template {
+            return template.render("search", response);
+        }
+        if let Some(fields) = &self.fields {
+            return self.format_projection(&fields.project_search(response)?);
+        }
         match self.format {
             OutputFormat::Table => self.format_search_table(response),
             OutputFormat::Json => self.format_search_json(response),
+            OutputFormat::Yaml => to_yaml(response),
+            OutputFormat::Toml => to_toml(response),
+            OutputFormat::Jsonl => self.format_search_jsonl(response),
             OutputFormat::Markdown => self.format_search_markdown(response),
             OutputFormat::Csv => self.format_search_csv(response),
+            OutputFormat::Xlsx => Err(binary_format_error()),
             OutputFormat::Html | OutputFormat::HtmlSimple => self.format_search_html(response),
         }
     }
 
     /// Format law detail
     pub fn format_detail(&self, detail: &LawDetail) -> Result<String> {
+        if let Some(template) = &self.template {
+            return template.render("detail", detail);
+        }
+        if let Some(fields) = &self.fields {
+            return self.format_projection(&fields.project_detail(detail)?);
+        }
         match self.format {
             OutputFormat::Table => self.format_detail_table(detail),
             OutputFormat::Json => self.format_detail_json(detail),
+            OutputFormat::Yaml => to_yaml(detail),
+            OutputFormat::Toml => to_toml(detail),
+            OutputFormat::Jsonl => to_json_line(detail),
             OutputFormat::Markdown => self.format_detail_markdown(detail),
             OutputFormat::Csv => self.format_detail_csv(detail),
+            OutputFormat::Xlsx => Err(binary_format_error()),
             OutputFormat::Html | OutputFormat::HtmlSimple => self.format_detail_html(detail),
         }
     }
 
     /// Format law history
     pub fn format_history(&self, history: &LawHistory) -> Result<String> {
+        if let Some(template) = &self.template {
+            return template.render("history", history);
+        }
+        if let Some(fields) = &self.fields {
+            return self.format_projection(&fields.project_history(history)?);
+        }
         match self.format {
             OutputFormat::Table => self.format_history_table(history),
             OutputFormat::Json => self.format_history_json(history),
+            OutputFormat::Yaml => to_yaml(history),
+            OutputFormat::Toml => to_toml(history),
+            OutputFormat::Jsonl => self.format_history_jsonl(history),
             OutputFormat::Markdown => self.format_history_markdown(history),
             OutputFormat::Csv => self.format_history_csv(history),
+            OutputFormat::Xlsx => Err(binary_format_error()),
             OutputFormat::Html | OutputFormat::HtmlSimple => self.format_history_html(history),
         }
     }
 
     // Table formatting methods
+    /// Format records reduced to `--fields`
+    fn format_projection(&self, projection: &Projection) -> Result<String> {
+        match self.format {
+            OutputFormat::Table => {
+                let mut table = Table::new();
+                table.set_header(
+                    projection
+                        .columns
+                        .iter()
+                        .map(|c| Cell::new(c).fg(Color::Cyan)),
+                );
+                for row in projection.text_rows() {
+                    table.add_row(row);
+                }
+                table.set_content_arrangement(ContentArrangement::Dynamic);
+                Ok(table.to_string())
+            }
+            OutputFormat::Json => serde_json::to_string_pretty(projection)
+                .map_err(crate::error::WarpError::Serialization),
+            OutputFormat::Jsonl => Ok(projection
+                .records()
+                .map(|record| to_json_line(&record))
+                .collect::<Result<Vec<_>>>()?
+                .join("\n")),
+            OutputFormat::Yaml => to_yaml(projection),
+            OutputFormat::Toml => to_toml(&WithoutNulls(projection)),
+            OutputFormat::Markdown => {
+                let mut result = format!("| {} |\n", projection.columns.join(" | "));
+                result.push_str(&format!(
+                    "|{}\n",
+                    "------|".repeat(projection.columns.len())
+                ));
+                for row in projection.text_rows() {
+                    let cells: Vec<String> = row
+                        .iter()
+                        .map(|cell| escape_markdown(cell).replace('\n', " "))
+                        .collect();
+                    result.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+                Ok(result)
+            }
+            OutputFormat::Csv => {
+                let mut wtr = csv::Writer::from_writer(vec![]);
+                wtr.write_record(&projection.columns)?;
+                for row in projection.text_rows() {
+                    wtr.write_record(&row)?;
+                }
+                let data = wtr
+                    .into_inner()
+                    .map_err(|e| crate::error::WarpError::Other(e.to_string()))?;
+
+                // Add BOM for Excel compatibility
+                let mut result = vec![0xEF, 0xBB, 0xBF];
+                result.extend_from_slice(&data);
+
+                String::from_utf8(result).map_err(|e| crate::error::WarpError::Other(e.to_string()))
+            }
+            OutputFormat::Html | OutputFormat::HtmlSimple => {
+                let mut html = String::new();
+                let is_simple = matches!(self.format, OutputFormat::HtmlSimple);
+                if !is_simple {
+                    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
+                    html.push_str("<meta charset=\"UTF-8\">\n");
+                    html.push_str("<style>\n");
+                    html.push_str("table { border-collapse: collapse; }\n");
+                    html.push_str(
+                        "th, td { border: 1px solid #ddd; padding: 8px; text-align: left; }\n",
+                    );
+                    html.push_str("</style>\n</head>\n<body>\n");
+                }
+                html.push_str("<table>\n<thead>\n<tr>");
+                for column in &projection.columns {
+                    html.push_str(&format!("<th>{}</th>", escape_html(column)));
+                }
+                html.push_str("</tr>\n</thead>\n<tbody>\n");
+                for row in projection.text_rows() {
+                    html.push_str("<tr>");
+                    for cell in &row {
+                        html.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    html.push_str("</tr>\n");
+                }
+                html.push_str("</tbody>\n</table>\n");
+                if !is_simple {
+                    html.push_str("</body>\n</html>");
+                }
+                Ok(html)
+            }
+            OutputFormat::Xlsx => Err(binary_format_error()),
+        }
+    }
+
     fn format_search_table(&self, response: &SearchResponse) -> Result<String> {
         let mut table = Table::new();
 
@@ -108,6 +310,15 @@ impl Formatter {
             response.items.len().to_string().yellow()
         ));
 
+        if response.stale {
+            result.push_str(&format!(
+                "{} {} ({})\n\n",
+                "⚠️".yellow(),
+                STALE_NOTICE.yellow(),
+                response.timestamp.format("%Y-%m-%d %H:%M UTC")
+            ));
+        }
+
         result.push_str(&table.to_string());
 
         Ok(result)
@@ -121,6 +332,10 @@ impl Formatter {
         result.push_str(&"=".repeat(80));
         result.push('\n');
 
+        if detail.stale {
+            result.push_str(&format!("{} {}\n", "⚠️".yellow(), STALE_NOTICE.yellow()));
+        }
+
         // Basic info
         if let Some(law_no) = &detail.law_no {
             result.push_str(&format!("법령번호: {}\n", law_no));
@@ -192,6 +407,9 @@ impl Formatter {
             "📚".cyan(),
             history.law_name.bold()
         ));
+        if history.stale {
+            result.push_str(&format!("{} {}\n\n", "⚠️".yellow(), STALE_NOTICE.yellow()));
+        }
         result.push_str(&table.to_string());
 
         Ok(result)
@@ -210,6 +428,25 @@ impl Formatter {
         serde_json::to_string_pretty(history).map_err(crate::error::WarpError::Serialization)
     }
 
+    // JSON Lines formatting methods
+    fn format_search_jsonl(&self, response: &SearchResponse) -> Result<String> {
+        let lines = response
+            .items
+            .iter()
+            .map(to_json_line)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    fn format_history_jsonl(&self, history: &LawHistory) -> Result<String> {
+        let lines = history
+            .entries
+            .iter()
+            .map(to_json_line)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(lines.join("\n"))
+    }
+
     // Markdown formatting methods
     fn format_search_markdown(&self, response: &SearchResponse) -> Result<String> {
         let mut result = String::new();
@@ -221,7 +458,15 @@ impl Formatter {
             response.page_no,
             div_ceil(response.total_count, response.page_size)
         ));
-        result.push_str(&format!("- **출처**: {}\n\n", response.source));
+        result.push_str(&format!("- **출처**: {}\n", response.source));
+        if response.stale {
+            result.push_str(&format!(
+                "- **주의**: {} ({})\n",
+                STALE_NOTICE,
+                response.timestamp.format("%Y-%m-%d %H:%M UTC")
+            ));
+        }
+        result.push('\n');
 
         result.push_str("| 번호 | 법령명 | 법령번호 | 종류 | 소관부처 | 시행일 |\n");
         result.push_str("|------|--------|----------|------|----------|--------|\n");
@@ -243,39 +488,15 @@ impl Formatter {
     }
 
     fn format_detail_markdown(&self, detail: &LawDetail) -> Result<String> {
-        let mut result = String::new();
-
-        result.push_str(&format!("# {}\n\n", detail.law_name));
-
-        if let Some(law_no) = &detail.law_no {
-            result.push_str(&format!("- **법령번호**: {}\n", law_no));
-        }
-        if let Some(law_type) = &detail.law_type {
-            result.push_str(&format!("- **법령종류**: {}\n", law_type));
-        }
-        if let Some(department) = &detail.department {
-            result.push_str(&format!("- **소관부처**: {}\n", department));
-        }
-        if let Some(enforcement_date) = &detail.enforcement_date {
-            result.push_str(&format!("- **시행일자**: {}\n", enforcement_date));
-        }
-
-        result.push_str("\n---\n\n");
-
-        if !detail.articles.is_empty() {
-            result.push_str("## 조문\n\n");
-            for article in &detail.articles {
-                result.push_str(&format!("### {}", article.number));
-                if let Some(title) = &article.title {
-                    result.push_str(&format!(" ({})", title));
-                }
-                result.push_str("\n\n");
-                result.push_str(&article.content);
-                result.push_str("\n\n");
+        Ok(detail_markdown(detail, |article| {
+            let mut block = format!("### {}", article.number);
+            if let Some(title) = &article.title {
+                block.push_str(&format!(" ({})", title));
             }
-        }
-
-        Ok(result)
+            block.push_str("\n\n");
+            block.push_str(&article.content);
+            block
+        }))
     }
 
     // CSV formatting
@@ -424,28 +645,16 @@ impl Formatter {
     }
 
     fn format_detail_html(&self, detail: &LawDetail) -> Result<String> {
-        let mut html = String::new();
-        let is_simple = matches!(self.format, OutputFormat::HtmlSimple);
-
-        if !is_simple {
-            html.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
-            html.push_str("<meta charset=\"UTF-8\">\n");
-            html.push_str(&format!(
-                "<title>{}</title>\n",
-                escape_html(&detail.law_name)
+        if matches!(self.format, OutputFormat::Html) {
+            return Ok(super::report::detail_report(
+                detail,
+                self.source,
+                chrono::Local::now(),
             ));
-            html.push_str("<style>\n");
-            html.push_str("body { font-family: 'Malgun Gothic', sans-serif; margin: 20px; line-height: 1.6; }\n");
-            html.push_str(".info { background: #f9f9f9; padding: 15px; border-radius: 5px; margin-bottom: 20px; }\n");
-            html.push_str(
-                ".article { margin: 20px 0; padding: 15px; border-left: 3px solid #4CAF50; }\n",
-            );
-            html.push_str(
-                ".article-title { font-weight: bold; color: #333; margin-bottom: 10px; }\n",
-            );
-            html.push_str("</style>\n</head>\n<body>\n");
         }
 
+        // HTML fragment for embedding
+        let mut html = String::new();
         html.push_str(&format!("<h1>{}</h1>\n", escape_html(&detail.law_name)));
 
         html.push_str("<div class=\"info\">\n");
@@ -501,10 +710,6 @@ impl Formatter {
             }
         }
 
-        if !is_simple {
-            html.push_str("</body>\n</html>");
-        }
-
         Ok(html)
     }
 
@@ -513,6 +718,9 @@ impl Formatter {
 
         result.push_str(&format!("# {} 개정 연혁\n\n", history.law_name));
         result.push_str(&format!("총 {}건의 개정 이력\n\n", history.total_count));
+        if history.stale {
+            result.push_str(&format!("> **주의**: {}\n\n", STALE_NOTICE));
+        }
 
         result.push_str("| 순번 | 개정일자 | 시행일자 | 개정구분 | 개정이유 |\n");
         result.push_str("|------|----------|----------|----------|----------|\n");
@@ -620,6 +828,47 @@ impl Formatter {
     }
 }
 
+/// Markdown for a law detail: the info list followed by every article
+///
+/// `render_article` writes one article block (heading and text); the Markdown
+/// output format and vault export differ only there.
+pub(crate) fn detail_markdown(
+    detail: &LawDetail,
+    render_article: impl Fn(&Article) -> String,
+) -> String {
+    let mut result = String::new();
+
+    result.push_str(&format!("# {}\n\n", detail.law_name));
+
+    if let Some(law_no) = &detail.law_no {
+        result.push_str(&format!("- **법령번호**: {}\n", law_no));
+    }
+    if let Some(law_type) = &detail.law_type {
+        result.push_str(&format!("- **법령종류**: {}\n", law_type));
+    }
+    if let Some(department) = &detail.department {
+        result.push_str(&format!("- **소관부처**: {}\n", department));
+    }
+    if let Some(enforcement_date) = &detail.enforcement_date {
+        result.push_str(&format!("- **시행일자**: {}\n", enforcement_date));
+    }
+    if detail.stale {
+        result.push_str(&format!("- **주의**: {}\n", STALE_NOTICE));
+    }
+
+    result.push_str("\n---\n\n");
+
+    if !detail.articles.is_empty() {
+        result.push_str("## 조문\n\n");
+        for article in &detail.articles {
+            result.push_str(&render_article(article));
+            result.push_str("\n\n");
+        }
+    }
+
+    result
+}
+
 // Helper functions
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.chars().count() <= max_len {
@@ -636,7 +885,7 @@ fn escape_markdown(s: &str) -> String {
         .replace("_", "\\_")
 }
 
-fn escape_html(s: &str) -> String {
+pub(crate) fn escape_html(s: &str) -> String {
     s.replace("&", "&amp;")
         .replace("<", "&lt;")
         .replace(">", "&gt;")
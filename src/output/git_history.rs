@@ -0,0 +1,381 @@
+//! Revision history of a law as a git repository
+//!
+//! Each revision becomes one commit dated with its promulgation date, whose
+//! tree holds the law as canonical Markdown, so `git log -p` shows what every
+//! revision changed and `git blame` shows when each line was introduced.
+//! Trailers in the commit messages record which revisions are already in the
+//! repository, so exporting again only appends newer revisions.
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use crate::api::types::{HistoryEntry, LawDetail};
+use crate::error::{Result, WarpError};
+use crate::output::formatter::detail_markdown;
+use crate::output::report::division_heading;
+use crate::output::vault::article_label;
+
+/// Git cannot store dates before the Unix epoch; older revisions get this one
+const EARLIEST_COMMIT_DATE: &str = "1970-01-02T00:00:00+09:00";
+
+/// Trailer naming the law a repository holds
+const LAW_TRAILER: &str = "Law-Id";
+/// Trailer naming the revision a commit holds
+const VERSION_TRAILER: &str = "Version-Id";
+
+/// Outcome of a git export
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitExportReport {
+    pub dir: PathBuf,
+    /// Revisions committed by this export
+    pub committed: Vec<String>,
+    /// Revisions already in the repository
+    pub existing: usize,
+    /// Revisions whose text could not be fetched (no version ID in the history)
+    pub missing: Vec<String>,
+}
+
+/// Repository holding the revisions of one law
+pub struct GitRepo {
+    root: PathBuf,
+    law_id: String,
+}
+
+impl GitRepo {
+    /// Open the repository for `law_id`, creating it if needed
+    ///
+    /// Refuses directories that hold other files or another repository, since
+    /// every commit replaces the Markdown files in the working tree.
+    pub fn open(root: impl Into<PathBuf>, law_id: &str) -> Result<Self> {
+        let repo = Self {
+            root: root.into(),
+            law_id: law_id.to_string(),
+        };
+        std::fs::create_dir_all(&repo.root)?;
+
+        if !repo.root.join(".git").exists() {
+            if std::fs::read_dir(&repo.root)?.next().is_some() {
+                return Err(WarpError::InvalidInput(format!(
+                    "{}는 비어 있지 않습니다. 빈 디렉터리나 이전에 내보낸 저장소를 지정하세요",
+                    repo.root.display()
+                )));
+            }
+            repo.git(&["init", "--quiet"], &[])?;
+        } else if repo.has_commits()? && !repo.trailers(LAW_TRAILER)?.contains(law_id) {
+            return Err(WarpError::InvalidInput(format!(
+                "{}는 법령 {}의 연혁 저장소가 아닙니다",
+                repo.root.display(),
+                law_id
+            )));
+        }
+        Ok(repo)
+    }
+
+    /// Version IDs of the revisions already committed
+    pub fn committed_versions(&self) -> Result<HashSet<String>> {
+        if !self.has_commits()? {
+            return Ok(HashSet::new());
+        }
+        self.trailers(VERSION_TRAILER)
+    }
+
+    /// Commit `detail` as the text of revision `entry`
+    ///
+    /// Revisions that leave the text unchanged still get a commit, so the log
+    /// lists every revision.
+    pub fn commit_revision(&self, detail: &LawDetail, entry: &HistoryEntry) -> Result<()> {
+        let file = format!("{}.md", file_name(&detail.law_name));
+        // A renamed law replaces its old file
+        for existing in std::fs::read_dir(&self.root)? {
+            let path = existing?.path();
+            if path.extension().is_some_and(|ext| ext == "md")
+                && path.file_name().is_some_and(|name| *name != *file)
+            {
+                std::fs::remove_file(path)?;
+            }
+        }
+        std::fs::write(self.root.join(&file), law_markdown(detail))?;
+
+        let author = detail
+            .department
+            .as_deref()
+            .filter(|d| !d.is_empty())
+            .unwrap_or("국가법령정보센터");
+        let date = commit_date(&entry.revision_date);
+        let message = commit_message(&detail.law_name, &self.law_id, entry);
+        self.git(&["add", "--all"], &[])?;
+        let mut child = self
+            .command(&["commit", "--quiet", "--allow-empty", "--file", "-"])
+            .envs([
+                ("GIT_AUTHOR_NAME", author),
+                ("GIT_AUTHOR_EMAIL", ""),
+                ("GIT_AUTHOR_DATE", date.as_str()),
+                ("GIT_COMMITTER_NAME", "warp"),
+                ("GIT_COMMITTER_EMAIL", ""),
+                ("GIT_COMMITTER_DATE", date.as_str()),
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(git_error)?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(message.as_bytes())?;
+        }
+        check(&["commit"], child.wait_with_output()?).map(|_| ())
+    }
+
+    fn has_commits(&self) -> Result<bool> {
+        let status = self
+            .command(&["rev-parse", "--verify", "--quiet", "HEAD"])
+            .output()
+            .map_err(git_error)?
+            .status;
+        Ok(status.success())
+    }
+
+    /// Values of a trailer across all commits
+    fn trailers(&self, key: &str) -> Result<HashSet<String>> {
+        let format = format!("--format=%(trailers:key={},valueonly)", key);
+        let log = self.git(&["log", &format], &[])?;
+        Ok(log
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new("git");
+        command.arg("-C").arg(&self.root).args(args);
+        command
+    }
+
+    /// Run git and return its standard output
+    fn git(&self, args: &[&str], env: &[(&str, &str)]) -> Result<String> {
+        let output = self
+            .command(args)
+            .envs(env.iter().copied())
+            .output()
+            .map_err(git_error)?;
+        check(args, output)
+    }
+}
+
+/// Standard output of a finished git command, or its error message
+fn check(args: &[&str], output: Output) -> Result<String> {
+    if !output.status.success() {
+        return Err(WarpError::Other(format!(
+            "git {} 실패: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn git_error(e: std::io::Error) -> WarpError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        WarpError::Other("git을 찾을 수 없습니다. git을 설치한 뒤 다시 실행하세요".to_string())
+    } else {
+        WarpError::Io(e)
+    }
+}
+
+/// Canonical Markdown of a law, as in `--format markdown` without notices
+///
+/// The text of a revision never changes, so a copy served from an expired
+/// cache renders the same as a fresh one.
+pub fn law_markdown(detail: &LawDetail) -> String {
+    let detail = LawDetail {
+        stale: false,
+        ..detail.clone()
+    };
+    let body = detail_markdown(&detail, |article| match division_heading(article) {
+        Some((_, text)) => format!("**{}**", text),
+        None => format!(
+            "### {}\n\n{}",
+            article_label(&article.number),
+            article.content.trim()
+        ),
+    });
+    let mut md = body.trim_end().to_string();
+    if detail.articles.is_empty() && !detail.content.trim().is_empty() {
+        md.push_str("\n\n");
+        md.push_str(detail.content.trim());
+    }
+    md.push('\n');
+    md
+}
+
+/// "[일부개정] 민법" with the dates and reason in the body
+pub fn commit_message(law_name: &str, law_id: &str, entry: &HistoryEntry) -> String {
+    let kind = Some(entry.revision_type.as_str())
+        .filter(|k| !k.is_empty())
+        .unwrap_or("개정");
+    let mut message = format!("[{}] {}\n\n", kind, law_name);
+    message.push_str(&format!("공포일자: {}\n", dashed(&entry.revision_date)));
+    if let Some(date) = entry.enforcement_date.as_deref().filter(|d| !d.is_empty()) {
+        message.push_str(&format!("시행일자: {}\n", dashed(date)));
+    }
+    if let Some(reason) = entry
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+    {
+        message.push_str(&format!("\n개정이유:\n{}\n", reason));
+    }
+    message.push_str(&format!("\n{}: {}\n", LAW_TRAILER, law_id));
+    if let Some(version) = &entry.version_id {
+        message.push_str(&format!("{}: {}\n", VERSION_TRAILER, version));
+    }
+    message
+}
+
+/// Commit date for a promulgation date (YYYYMMDD), midnight in Korea
+pub fn commit_date(revision_date: &str) -> String {
+    let date = dashed(revision_date);
+    if date.len() != 10 || date.as_str() < &EARLIEST_COMMIT_DATE[..10] {
+        return EARLIEST_COMMIT_DATE.to_string();
+    }
+    format!("{}T00:00:00+09:00", date)
+}
+
+/// "20230526" → "2023-05-26"; other forms are kept
+fn dashed(date: &str) -> String {
+    let digits: String = date.chars().filter(char::is_ascii_digit).collect();
+    if digits.len() == 8 {
+        format!("{}-{}-{}", &digits[..4], &digits[4..6], &digits[6..])
+    } else {
+        date.to_string()
+    }
+}
+
+/// Law name usable as a file name
+fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if "/\\:*?\"<>|".contains(c) { '_' } else { c })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Order revisions oldest first
+pub fn chronological(entries: &[HistoryEntry]) -> Vec<&HistoryEntry> {
+    let mut entries: Vec<&HistoryEntry> = entries.iter().collect();
+    entries.sort_by(|a, b| {
+        (dashed(&a.revision_date), a.revision_no).cmp(&(dashed(&b.revision_date), b.revision_no))
+    });
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::Article;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn detail(name: &str, content: &str) -> LawDetail {
+        LawDetail {
+            law_id: "001706".to_string(),
+            law_name: name.to_string(),
+            law_no: Some("19098".to_string()),
+            law_type: Some("법률".to_string()),
+            department: Some("법무부".to_string()),
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles: vec![Article {
+                number: "1".to_string(),
+                title: Some("법원".to_string()),
+                content: content.to_string(),
+                paragraphs: vec![],
+            }],
+            attachments: vec![],
+            related_laws: vec![],
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: true,
+        }
+    }
+
+    fn entry(no: u32, date: &str, version: &str) -> HistoryEntry {
+        HistoryEntry {
+            revision_no: no,
+            revision_date: date.to_string(),
+            enforcement_date: Some(date.to_string()),
+            revision_type: "일부개정".to_string(),
+            reason: Some("조문 정비".to_string()),
+            changed_articles: vec![],
+            version_id: Some(version.to_string()),
+        }
+    }
+
+    fn git_available() -> bool {
+        Command::new("git").arg("--version").output().is_ok()
+    }
+
+    #[test]
+    fn test_law_markdown_and_commit_message() {
+        let md = law_markdown(&detail("민법", "제1조(법원) 민사에 관하여"));
+        assert!(md.starts_with("# 민법\n"));
+        assert!(md.contains("### 제1조\n\n제1조(법원) 민사에 관하여\n"));
+        assert!(!md.contains("주의"));
+
+        let message = commit_message("민법", "001706", &entry(1, "20230526", "253527"));
+        assert!(message.starts_with("[일부개정] 민법\n\n공포일자: 2023-05-26\n"));
+        assert!(message.contains("개정이유:\n조문 정비\n"));
+        assert!(message.ends_with("Law-Id: 001706\nVersion-Id: 253527\n"));
+    }
+
+    #[test]
+    fn test_commit_date_clamps_to_epoch() {
+        assert_eq!(commit_date("20230526"), "2023-05-26T00:00:00+09:00");
+        assert_eq!(commit_date("19580222"), EARLIEST_COMMIT_DATE);
+        assert_eq!(commit_date(""), EARLIEST_COMMIT_DATE);
+    }
+
+    #[test]
+    fn test_repo_commits_each_revision_once() {
+        if !git_available() {
+            return;
+        }
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().join("민법");
+
+        let repo = GitRepo::open(&root, "001706").unwrap();
+        assert!(repo.committed_versions().unwrap().is_empty());
+        repo.commit_revision(&detail("민법", "제1조 구법"), &entry(1, "19580222", "1"))
+            .unwrap();
+        repo.commit_revision(&detail("민법", "제1조 신법"), &entry(2, "20230526", "2"))
+            .unwrap();
+
+        let repo = GitRepo::open(&root, "001706").unwrap();
+        let versions = repo.committed_versions().unwrap();
+        assert_eq!(versions.len(), 2);
+        assert!(versions.contains("1") && versions.contains("2"));
+        let text = std::fs::read_to_string(root.join("민법.md")).unwrap();
+        assert!(text.contains("제1조 신법"));
+
+        let log = repo
+            .git(&["log", "--format=%an|%ad|%s", "--date=short"], &[])
+            .unwrap();
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            [
+                "법무부|2023-05-26|[일부개정] 민법",
+                "법무부|1970-01-02|[일부개정] 민법"
+            ]
+        );
+
+        assert!(GitRepo::open(&root, "000001").is_err());
+        std::fs::write(dir.path().join("note.txt"), "").unwrap();
+        assert!(GitRepo::open(dir.path(), "001706").is_err());
+    }
+}
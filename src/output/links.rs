@@ -0,0 +1,53 @@
+use crate::api::ApiType;
+
+/// Public law.go.kr site (not the DRF API, whose links carry the API key)
+const LAW_GO_KR: &str = "https://www.law.go.kr/";
+
+/// Link to a document on law.go.kr
+///
+/// Laws, ordinances and administrative rules are linked by name, which
+/// always resolves to the current version. Precedents and interpretations
+/// have generic titles, so their serial number is used instead.
+pub fn law_go_kr_link(api_type: ApiType, id: &str, title: &str) -> Option<String> {
+    let mut url = url::Url::parse(LAW_GO_KR).ok()?;
+    match api_type {
+        ApiType::Nlic | ApiType::Elis | ApiType::Admrul if !title.is_empty() => {
+            let section = match api_type {
+                ApiType::Nlic => "법령",
+                ApiType::Elis => "자치법규",
+                _ => "행정규칙",
+            };
+            url.path_segments_mut().ok()?.push(section).push(title);
+        }
+        ApiType::Prec | ApiType::Expc if !id.is_empty() => {
+            let (page, key) = if api_type == ApiType::Prec {
+                ("precInfoP.do", "precSeq")
+            } else {
+                ("expcInfoP.do", "expcSeq")
+            };
+            url.path_segments_mut().ok()?.push("LSW").push(page);
+            url.query_pairs_mut().append_pair(key, id);
+        }
+        _ => return None,
+    }
+    Some(url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_law_go_kr_links() {
+        assert_eq!(
+            law_go_kr_link(ApiType::Nlic, "001706", "민법").unwrap(),
+            "https://www.law.go.kr/%EB%B2%95%EB%A0%B9/%EB%AF%BC%EB%B2%95"
+        );
+        assert_eq!(
+            law_go_kr_link(ApiType::Prec, "228541", "손해배상(기)").unwrap(),
+            "https://www.law.go.kr/LSW/precInfoP.do?precSeq=228541"
+        );
+        assert!(law_go_kr_link(ApiType::Expc, "", "질의").is_none());
+        assert!(law_go_kr_link(ApiType::All, "1", "민법").is_none());
+    }
+}
@@ -1,25 +1,90 @@
+pub mod diff;
+pub mod fields;
 pub mod formatter;
+pub mod git_history;
+pub mod links;
+pub mod report;
+pub mod template;
+pub mod vault;
+pub mod xlsx;
 
 pub use formatter::Formatter;
+pub use links::law_go_kr_link;
 
 use crate::api::types::{LawDetail, LawHistory, SearchResponse};
+use crate::api::ApiType;
 use crate::cli::OutputFormat;
-use crate::error::Result;
+use crate::error::{Result, WarpError};
+use std::io::{IsTerminal, Write};
 
 /// Format search response based on the specified format
 pub fn format_search_response(response: &SearchResponse, format: OutputFormat) -> Result<String> {
-    let formatter = Formatter::new(format);
-    formatter.format_search(response)
+    Formatter::new(format).format_search(response)
 }
 
 /// Format law detail based on the specified format
 pub fn format_law_detail(detail: &LawDetail, format: OutputFormat) -> Result<String> {
-    let formatter = Formatter::new(format);
-    formatter.format_detail(detail)
+    Formatter::new(format).format_detail(detail)
 }
 
 /// Format law history based on the specified format
 pub fn format_law_history(history: &LawHistory, format: OutputFormat) -> Result<String> {
-    let formatter = Formatter::new(format);
-    formatter.format_history(history)
+    Formatter::new(format).format_history(history)
+}
+
+/// Print a search response to stdout
+pub fn print_search_response(response: &SearchResponse, formatter: &Formatter) -> Result<()> {
+    if formatter.writes_binary() {
+        return write_binary(&match formatter.fields() {
+            Some(fields) => xlsx::projection_workbook(&fields.project_search(response)?)?,
+            None => xlsx::search_workbook(response)?,
+        });
+    }
+    println!("{}", formatter.format_search(response)?);
+    Ok(())
+}
+
+/// Print a law detail to stdout
+///
+/// `api_type` is the source the detail came from; workbooks and HTML reports
+/// use it to link back to law.go.kr.
+pub fn print_law_detail(
+    detail: &LawDetail,
+    api_type: ApiType,
+    formatter: &Formatter,
+) -> Result<()> {
+    if formatter.writes_binary() {
+        return write_binary(&match formatter.fields() {
+            Some(fields) => xlsx::projection_workbook(&fields.project_detail(detail)?)?,
+            None => xlsx::detail_workbook(detail, api_type)?,
+        });
+    }
+    let formatter = formatter.clone().with_source(api_type);
+    println!("{}", formatter.format_detail(detail)?);
+    Ok(())
+}
+
+/// Print a law history to stdout
+pub fn print_law_history(history: &LawHistory, formatter: &Formatter) -> Result<()> {
+    if formatter.writes_binary() {
+        return write_binary(&match formatter.fields() {
+            Some(fields) => xlsx::projection_workbook(&fields.project_history(history)?)?,
+            None => xlsx::history_workbook(history)?,
+        });
+    }
+    println!("{}", formatter.format_history(history)?);
+    Ok(())
+}
+
+/// Write a binary document to stdout, refusing to dump it on a terminal
+fn write_binary(bytes: &[u8]) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    if stdout.is_terminal() {
+        return Err(WarpError::InvalidInput(
+            "XLSX 출력은 파일로 리디렉션하세요 (예: warp law 민법 -f xlsx > 민법.xlsx)".to_string(),
+        ));
+    }
+    stdout.write_all(bytes)?;
+    stdout.flush()?;
+    Ok(())
 }
@@ -0,0 +1,423 @@
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::api::types::{Article, LawDetail};
+use crate::api::ApiType;
+use crate::output::formatter::escape_html;
+use crate::output::law_go_kr_link;
+
+/// Division headings (편/장/절/관) that the APIs return as article rows
+static DIVISION_HEADING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^제\s*\d+(?:의\s*\d+)?\s*([편장절관])(?:\s|$)").expect("valid division regex")
+});
+
+/// Paragraph markers ①–⑳ that start a new paragraph inside article text
+const PARAGRAPH_MARKERS: std::ops::RangeInclusive<char> = '①'..='⑳';
+
+const STYLE: &str = r#"
+:root { --accent: #1f4e79; --muted: #666; --rule: #d0d7de; }
+* { box-sizing: border-box; }
+body { font-family: 'Malgun Gothic', 'Apple SD Gothic Neo', 'Noto Sans KR', sans-serif;
+       max-width: 860px; margin: 0 auto; padding: 24px; line-height: 1.7; color: #222; }
+header h1 { margin: 0 0 12px; color: var(--accent); }
+.meta { border-collapse: collapse; width: 100%; margin-bottom: 8px; }
+.meta th, .meta td { border: 1px solid var(--rule); padding: 4px 10px; text-align: left; }
+.meta th { width: 9em; background: #f6f8fa; font-weight: 600; }
+.stale { color: #9a6700; }
+.toolbar { margin: 16px 0; }
+.toolbar button { margin-right: 6px; }
+nav.toc { border: 1px solid var(--rule); padding: 12px 20px; margin: 16px 0 24px; }
+nav.toc h2 { margin: 0 0 8px; font-size: 1.1em; }
+nav.toc ol { list-style: none; margin: 0; padding: 0; }
+nav.toc a { text-decoration: none; color: var(--accent); }
+nav.toc .division { font-weight: 600; margin-top: 6px; }
+.level-0 { padding-left: 0; } .level-1 { padding-left: 1em; }
+.level-2 { padding-left: 2em; } .level-3 { padding-left: 3em; }
+.toc-article.level-0 { padding-left: 1em; } .toc-article.level-1 { padding-left: 2em; }
+.toc-article.level-2 { padding-left: 3em; } .toc-article.level-3 { padding-left: 4em; }
+h2.division { color: var(--accent); border-bottom: 2px solid var(--accent); padding-bottom: 4px; }
+section.article { margin: 12px 0; border-left: 3px solid var(--rule); padding-left: 12px; }
+section.article summary { cursor: pointer; font-weight: 600; }
+.paragraph { margin: 6px 0; }
+.items { margin: 2px 0 6px 1.5em; padding: 0; list-style: none; }
+footer { margin-top: 32px; padding-top: 8px; border-top: 1px solid var(--rule);
+         font-size: 0.9em; color: var(--muted); }
+@page { margin: 18mm 16mm; }
+@media print {
+  body { max-width: none; padding: 0; font-size: 10.5pt; }
+  .toolbar { display: none; }
+  nav.toc { page-break-after: always; border: none; padding: 0; }
+  a { color: inherit; text-decoration: none; }
+  section.article { break-inside: avoid; }
+  summary { list-style: none; }
+  summary::-webkit-details-marker { display: none; }
+}
+"#;
+
+/// Expand everything before printing so collapsed articles are not lost
+const SCRIPT: &str = r#"
+function setAll(open) { document.querySelectorAll('details').forEach(function (d) { d.open = open; }); }
+window.addEventListener('beforeprint', function () { setAll(true); });
+"#;
+
+/// Standalone, print-ready HTML document for a law detail
+///
+/// Chapters and sections found among the articles become headings in the
+/// table of contents; every article gets an anchor (`#article-N`) and a
+/// collapsible body. The footer records when the detail was fetched from the
+/// API (which predates generation when it comes from cache), when the
+/// document was generated, and where it can be found on law.go.kr.
+pub fn detail_report(
+    detail: &LawDetail,
+    source: Option<ApiType>,
+    generated_at: DateTime<Local>,
+) -> String {
+    let name = escape_html(&detail.law_name);
+    let source_url = source.and_then(|api| law_go_kr_link(api, &detail.law_id, &detail.law_name));
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n");
+    html.push_str("<meta charset=\"UTF-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!("<title>{}</title>\n", name));
+    html.push_str(&format!("<style>{}</style>\n", STYLE));
+    html.push_str(&format!("<script>{}</script>\n", SCRIPT));
+    html.push_str("</head>\n<body>\n");
+
+    // Metadata header
+    html.push_str(&format!("<header>\n<h1>{}</h1>\n", name));
+    html.push_str("<table class=\"meta\">\n");
+    let fields = [
+        ("법령번호", detail.law_no.as_deref()),
+        ("법령종류", detail.law_type.as_deref()),
+        ("소관부처", detail.department.as_deref()),
+        ("시행일자", detail.enforcement_date.as_deref()),
+        ("개정일자", detail.revision_date.as_deref()),
+        ("출처", source.map(|api| api.display_name())),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            html.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                label,
+                escape_html(value)
+            ));
+        }
+    }
+    html.push_str("</table>\n");
+    if detail.stale {
+        html.push_str(&format!(
+            "<p class=\"stale\">⚠ {}</p>\n",
+            escape_html(super::formatter::STALE_NOTICE)
+        ));
+    }
+    html.push_str("</header>\n");
+
+    if !detail.articles.is_empty() {
+        html.push_str("<div class=\"toolbar\">");
+        html.push_str("<button type=\"button\" onclick=\"setAll(true)\">모두 펼치기</button>");
+        html.push_str("<button type=\"button\" onclick=\"setAll(false)\">모두 접기</button>");
+        html.push_str("<button type=\"button\" onclick=\"window.print()\">인쇄</button>");
+        html.push_str("</div>\n");
+
+        let entries = classify(&detail.articles);
+        html.push_str(&table_of_contents(&entries));
+        html.push_str("<main>\n");
+        for entry in &entries {
+            match entry {
+                Entry::Division {
+                    anchor,
+                    level,
+                    text,
+                } => html.push_str(&format!(
+                    "<h2 class=\"division level-{}\" id=\"{}\">{}</h2>\n",
+                    level,
+                    anchor,
+                    escape_html(text)
+                )),
+                Entry::Article {
+                    anchor, article, ..
+                } => html.push_str(&article_section(anchor, article)),
+            }
+        }
+        html.push_str("</main>\n");
+    } else if !detail.content.is_empty() {
+        html.push_str("<main>\n");
+        for line in detail.content.lines().filter(|l| !l.trim().is_empty()) {
+            html.push_str(&format!("<p>{}</p>\n", escape_html(line.trim())));
+        }
+        html.push_str("</main>\n");
+    }
+
+    // Footer with provenance
+    html.push_str("<footer>\n");
+    if let Some(fetched_at) = detail.fetched_at {
+        html.push_str(&format!(
+            "<p>조회 시각: {}</p>\n",
+            fetched_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+        ));
+    }
+    html.push_str(&format!(
+        "<p>생성 시각: {}",
+        generated_at.format("%Y-%m-%d %H:%M:%S")
+    ));
+    if detail.stale {
+        html.push_str(&format!(
+            " <span class=\"stale\">({})</span>",
+            escape_html(super::formatter::STALE_NOTICE)
+        ));
+    }
+    html.push_str("</p>\n");
+    match source_url {
+        Some(url) => {
+            let url = escape_html(&url);
+            html.push_str(&format!("<p>출처: <a href=\"{}\">{}</a></p>\n", url, url));
+        }
+        None => html.push_str("<p>출처: 국가법령정보센터 (https://www.law.go.kr)</p>\n"),
+    }
+    html.push_str("</footer>\n</body>\n</html>\n");
+
+    html
+}
+
+enum Entry<'a> {
+    Division {
+        anchor: String,
+        level: usize,
+        text: &'a str,
+    },
+    Article {
+        anchor: String,
+        level: usize,
+        article: &'a Article,
+    },
+}
+
+/// Split article rows into division headings and articles
+///
+/// Articles are indented one level below the division they follow.
+fn classify(articles: &[Article]) -> Vec<Entry<'_>> {
+    let mut entries = Vec::with_capacity(articles.len());
+    let mut level = 0;
+    let (mut divisions, mut count) = (0, 0);
+
+    for article in articles {
+        if let Some((division_level, text)) = division_heading(article) {
+            level = division_level;
+            divisions += 1;
+            entries.push(Entry::Division {
+                anchor: format!("division-{}", divisions),
+                level,
+                text,
+            });
+        } else {
+            count += 1;
+            entries.push(Entry::Article {
+                anchor: format!("article-{}", count),
+                level,
+                article,
+            });
+        }
+    }
+
+    entries
+}
+
+/// Level (편 0, 장 1, 절 2, 관 3) and text of a division heading row
+///
+/// The APIs list headings such as "제1장 총칙" among the articles, without a
+/// title of their own.
+pub(crate) fn division_heading(article: &Article) -> Option<(usize, &str)> {
+    if article.title.as_deref().is_some_and(|t| !t.is_empty()) {
+        return None;
+    }
+    let text = article.content.trim();
+    let caps = DIVISION_HEADING.captures(text)?;
+    let level = match &caps[1] {
+        "편" => 0,
+        "장" => 1,
+        "절" => 2,
+        _ => 3,
+    };
+    Some((level, text.lines().next().unwrap_or(text)))
+}
+
+fn table_of_contents(entries: &[Entry<'_>]) -> String {
+    let mut toc = String::from("<nav class=\"toc\">\n<h2>목차</h2>\n<ol>\n");
+    for entry in entries {
+        match entry {
+            Entry::Division {
+                anchor,
+                level,
+                text,
+            } => toc.push_str(&format!(
+                "<li class=\"division level-{}\"><a href=\"#{}\">{}</a></li>\n",
+                level,
+                anchor,
+                escape_html(text)
+            )),
+            Entry::Article {
+                anchor,
+                level,
+                article,
+            } => toc.push_str(&format!(
+                "<li class=\"toc-article level-{}\"><a href=\"#{}\">{}</a></li>\n",
+                level,
+                anchor,
+                escape_html(&article_heading(article))
+            )),
+        }
+    }
+    toc.push_str("</ol>\n</nav>\n");
+    toc
+}
+
+fn article_section(anchor: &str, article: &Article) -> String {
+    let mut html = format!(
+        "<section class=\"article\" id=\"{}\">\n<details open>\n<summary>{}</summary>\n",
+        anchor,
+        escape_html(&article_heading(article))
+    );
+
+    if article.paragraphs.is_empty() {
+        for paragraph in split_paragraphs(&article.content) {
+            html.push_str(&format!(
+                "<p class=\"paragraph\">{}</p>\n",
+                escape_html(&paragraph).replace('\n', "<br>")
+            ));
+        }
+    } else {
+        for paragraph in &article.paragraphs {
+            html.push_str(&format!(
+                "<p class=\"paragraph\">{} {}</p>\n",
+                escape_html(&paragraph.number),
+                escape_html(&paragraph.content)
+            ));
+            if !paragraph.items.is_empty() {
+                html.push_str("<ul class=\"items\">\n");
+                for item in &paragraph.items {
+                    html.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+                }
+                html.push_str("</ul>\n");
+            }
+        }
+    }
+
+    html.push_str("</details>\n</section>\n");
+    html
+}
+
+fn article_heading(article: &Article) -> String {
+    match article.title.as_deref().filter(|t| !t.is_empty()) {
+        Some(title) => format!("{} ({})", article.number, title),
+        None => article.number.clone(),
+    }
+}
+
+/// Break article text into paragraphs at ①, ② … markers
+fn split_paragraphs(content: &str) -> Vec<String> {
+    let mut paragraphs: Vec<String> = Vec::new();
+    for line in content.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let starts_paragraph = line
+            .chars()
+            .next()
+            .is_some_and(|c| PARAGRAPH_MARKERS.contains(&c));
+        match paragraphs.last_mut() {
+            Some(last) if !starts_paragraph => {
+                last.push('\n');
+                last.push_str(line);
+            }
+            _ => paragraphs.push(line.to_string()),
+        }
+    }
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+
+    fn article(number: &str, title: Option<&str>, content: &str) -> Article {
+        Article {
+            number: number.to_string(),
+            title: title.map(str::to_string),
+            content: content.to_string(),
+            paragraphs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_divisions_and_articles() {
+        let articles = vec![
+            article("1", None, "제1장 총칙"),
+            article("1", Some("목적"), "제1조(목적) 이 법은 ..."),
+            article("2", None, "제2조 삭제"),
+            article("3", None, "제1절 통칙"),
+        ];
+        let entries = classify(&articles);
+        assert!(matches!(entries[0], Entry::Division { level: 1, .. }));
+        assert!(matches!(entries[1], Entry::Article { level: 1, .. }));
+        assert!(matches!(entries[2], Entry::Article { .. }));
+        assert!(matches!(entries[3], Entry::Division { level: 2, .. }));
+    }
+
+    #[test]
+    fn test_split_paragraphs() {
+        let paragraphs = split_paragraphs("제3조(정의)\n① 첫째 항\n1. 호\n② 둘째 항");
+        assert_eq!(
+            paragraphs,
+            vec!["제3조(정의)", "① 첫째 항\n1. 호", "② 둘째 항"]
+        );
+    }
+
+    #[test]
+    fn test_detail_report_document() {
+        let detail = LawDetail {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            law_no: Some("265307".to_string()),
+            law_type: Some("법률".to_string()),
+            department: Some("법무부".to_string()),
+            enforcement_date: Some("20250131".to_string()),
+            revision_date: None,
+            content: String::new(),
+            articles: vec![
+                article("1", None, "제1편 총칙"),
+                article("1", Some("법원"), "제1조(법원) 민사에 관하여 ..."),
+            ],
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        };
+
+        let html = detail_report(&detail, Some(ApiType::Nlic), Local::now());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<a href=\"#division-1\">제1편 총칙</a>"));
+        assert!(html.contains("<a href=\"#article-1\">1 (법원)</a>"));
+        assert!(html.contains("id=\"article-1\""));
+        assert!(html.contains("<details open>"));
+        assert!(html.contains("https://www.law.go.kr/%EB%B2%95%EB%A0%B9/"));
+        assert!(html.contains("<p>생성 시각: "));
+        assert!(!html.contains("조회 시각"));
+        assert!(!html.contains("class=\"stale\""));
+
+        let fetched_at = Local.with_ymd_and_hms(2025, 3, 1, 9, 30, 0).unwrap();
+        let stale = LawDetail {
+            fetched_at: Some(fetched_at.with_timezone(&chrono::Utc)),
+            stale: true,
+            ..detail
+        };
+        let html = detail_report(&stale, Some(ApiType::Nlic), Local::now());
+        assert!(html.contains("<p>조회 시각: 2025-03-01 09:30:00</p>"));
+        assert!(html.contains(&format!(
+            "<span class=\"stale\">({})</span></p>",
+            crate::output::formatter::STALE_NOTICE
+        )));
+    }
+}
@@ -0,0 +1,145 @@
+use include_dir::{include_dir, Dir};
+use minijinja::value::Value;
+use minijinja::Environment;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::error::{Result, WarpError};
+
+/// Example templates shipped with the binary
+static BUNDLED: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/templates");
+
+/// A user-defined output template (Jinja syntax)
+///
+/// Templates see the fields of the rendered value at the top level (e.g.
+/// `items` for search results, `articles` for details, `entries` for
+/// history), plus `kind` ("search", "detail" or "history") and
+/// `generated_at`. Templates whose name ends in `.html` (optionally followed
+/// by `.j2`) escape values for HTML.
+#[derive(Debug, Clone)]
+pub struct Template {
+    name: String,
+    source: String,
+}
+
+#[derive(Serialize)]
+struct Context<'a, T: Serialize> {
+    kind: &'a str,
+    generated_at: String,
+    #[serde(flatten)]
+    value: &'a T,
+}
+
+impl Template {
+    pub fn new(name: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Load a template file, or a bundled template by name (`memo`, `memo.md.j2`)
+    pub fn load(spec: &str) -> Result<Self> {
+        let path = Path::new(spec);
+        if path.is_file() {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(spec)
+                .to_string();
+            return Ok(Self::new(name, std::fs::read_to_string(path)?));
+        }
+
+        let bundled = BUNDLED.files().find(|file| {
+            let name = file.path().to_str().unwrap_or_default();
+            name == spec || name.split('.').next() == Some(spec)
+        });
+        match bundled.and_then(|file| Some((file.path().to_str()?, file.contents_utf8()?))) {
+            Some((name, source)) => Ok(Self::new(name, source)),
+            None => Err(WarpError::InvalidInput(format!(
+                "템플릿을 찾을 수 없습니다: {} (기본 제공: {})",
+                spec,
+                bundled_names().join(", ")
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Render a search response, law detail or history
+    pub fn render<T: Serialize>(&self, kind: &str, value: &T) -> Result<String> {
+        let env = environment();
+        let template = env.template_from_named_str(&self.name, &self.source)?;
+        let context = Context {
+            kind,
+            generated_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            value,
+        };
+        Ok(template.render(context)?)
+    }
+}
+
+/// Names of the bundled example templates
+pub fn bundled_names() -> Vec<&'static str> {
+    let mut names: Vec<_> = BUNDLED
+        .files()
+        .filter_map(|file| file.path().to_str())
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.add_filter("date", date);
+    env
+}
+
+/// `{{ enforcement_date | date }}`: "20250131" → "2025-01-31", missing → "-"
+fn date(value: Value) -> String {
+    match value.as_str() {
+        Some(s) if s.len() == 8 && s.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{}-{}-{}", &s[..4], &s[4..6], &s[6..])
+        }
+        Some(s) if !s.is_empty() => s.to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_flattens_value() {
+        let template = Template::new(
+            "t.md",
+            "{{ kind }}: {{ law_name }} {{ enforcement_date | date }} {{ missing | date }}",
+        );
+        let out = template
+            .render(
+                "detail",
+                &json!({"law_name": "민법", "enforcement_date": "20250131", "missing": null}),
+            )
+            .unwrap();
+        assert_eq!(out, "detail: 민법 2025-01-31 -");
+    }
+
+    #[test]
+    fn test_html_templates_escape() {
+        let template = Template::new("t.html.j2", "{{ title }}");
+        let out = template.render("search", &json!({"title": "<b>"})).unwrap();
+        assert_eq!(out, "&lt;b&gt;");
+    }
+
+    #[test]
+    fn test_bundled_templates_load_by_name() {
+        assert_eq!(Template::load("memo").unwrap().name(), "memo.md.j2");
+        assert!(Template::load("history.html.j2").is_ok());
+        assert!(Template::load("no-such-template").is_err());
+    }
+}
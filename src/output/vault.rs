@@ -0,0 +1,512 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::api::types::{Article, LawDetail};
+use crate::api::ApiType;
+use crate::error::Result;
+use crate::output::formatter::{detail_markdown, to_yaml};
+use crate::output::law_go_kr_link;
+use crate::output::report::division_heading;
+
+/// 「법령명」 optionally followed by an article, or a bare article reference
+static REFERENCE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"「([^」]+)」(?:(\s*)(제\d+조(?:의\d+)?))?|(제\d+조(?:의\d+)?)")
+        .expect("valid reference regex")
+});
+
+/// Text that may sit between "「민법」 제3조" and a following "제5조" that
+/// still refers to the same law
+static CONNECTOR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:\s|,|·|및|또는|내지|부터|까지|와|과)*$").expect("valid connector regex")
+});
+
+/// Suffixes of the decrees and rules that belong to a law
+const SUBORDINATE_SUFFIXES: [&str; 2] = [" 시행령", " 시행규칙"];
+
+/// Outcome of writing a vault
+#[derive(Debug, Default, Serialize)]
+pub struct VaultReport {
+    /// Files created or updated
+    pub written: Vec<PathBuf>,
+    /// Files whose content was already up to date
+    pub unchanged: usize,
+}
+
+/// A directory of Markdown notes, one per law, linked with `[[wiki links]]`
+///
+/// Notes are rendered deterministically and only rewritten when their
+/// content changes, so exporting the same laws again leaves the vault as is.
+/// Notes exported earlier are kept, and gain links to the decrees and parent
+/// laws exported after them.
+pub struct Vault {
+    root: PathBuf,
+}
+
+#[derive(Serialize)]
+struct LawFrontMatter<'a> {
+    title: &'a str,
+    law_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    law_no: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    law_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    department: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enforcement_date: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    revision_date: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    tags: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ArticleFrontMatter<'a> {
+    law: String,
+    article: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+}
+
+impl Vault {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Names of the laws that already have a note in the vault
+    pub fn law_names(&self) -> Result<BTreeSet<String>> {
+        let mut names = BTreeSet::new();
+        if !self.root.is_dir() {
+            return Ok(names);
+        }
+        for entry in std::fs::read_dir(&self.root)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "md") {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    names.insert(stem.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Write a note per law, and per article when `per_article` is set
+    pub fn write(&self, details: &[LawDetail], per_article: bool) -> Result<VaultReport> {
+        std::fs::create_dir_all(&self.root)?;
+
+        let exported: BTreeSet<String> = details.iter().map(|d| note_name(&d.law_name)).collect();
+        let mut known = self.law_names()?;
+        known.extend(exported.iter().cloned());
+
+        let mut report = VaultReport::default();
+        for detail in details {
+            let path = self
+                .root
+                .join(format!("{}.md", note_name(&detail.law_name)));
+            self.write_note(&path, &law_note(detail, &known)?, &mut report)?;
+
+            if per_article {
+                let dir = self.root.join(note_name(&detail.law_name));
+                for article in &detail.articles {
+                    if division_heading(article).is_some() {
+                        continue;
+                    }
+                    let label = article_label(&article.number);
+                    let path = dir.join(format!("{}.md", note_name(&label)));
+                    self.write_note(&path, &article_note(detail, article)?, &mut report)?;
+                }
+            }
+        }
+
+        // Notes from earlier exports link to the family members added now
+        for name in known.difference(&exported) {
+            let path = self.root.join(format!("{}.md", name));
+            let Ok(existing) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Some(note) = with_related(&existing, &family_laws(name, &known))? {
+                self.write_note(&path, &note, &mut report)?;
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn write_note(&self, path: &Path, content: &str, report: &mut VaultReport) -> Result<()> {
+        if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+            report.unchanged += 1;
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)?;
+        report.written.push(path.to_path_buf());
+        Ok(())
+    }
+}
+
+/// Note for a whole law
+///
+/// `known` holds the note names in the vault; the law's decrees and parent
+/// law are linked when they are among them.
+pub fn law_note(detail: &LawDetail, known: &BTreeSet<String>) -> Result<String> {
+    let name = note_name(&detail.law_name);
+    let related = related_laws(detail, known);
+
+    let front = LawFrontMatter {
+        title: &detail.law_name,
+        law_id: &detail.law_id,
+        law_no: detail.law_no.as_deref(),
+        law_type: detail.law_type.as_deref(),
+        department: detail.department.as_deref(),
+        enforcement_date: detail.enforcement_date.as_deref(),
+        revision_date: detail.revision_date.as_deref(),
+        source: law_go_kr_link(ApiType::Nlic, &detail.law_id, &detail.law_name),
+        tags: std::iter::once("법령")
+            .chain(detail.law_type.as_deref())
+            .collect(),
+        related: related.iter().map(|r| wiki_link(r, None, r)).collect(),
+    };
+
+    let body = detail_markdown(detail, |article| match division_heading(article) {
+        Some((_, text)) => format!("**{}**", text),
+        None => format!(
+            "### {}\n\n{}",
+            article_label(&article.number),
+            linkify(&article.content, &name)
+        ),
+    });
+
+    let mut note = front_matter(&front)?;
+    note.push_str(body.trim_end());
+    note.push('\n');
+    note.push_str(&related_section(&related));
+    Ok(note)
+}
+
+/// Law note with `family` merged into its related laws, or `None` when it
+/// already links to all of them (or is not a note this vault wrote)
+fn with_related(note: &str, family: &[String]) -> Result<Option<String>> {
+    let Some((front, body)) = note
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.split_once("---\n\n"))
+    else {
+        return Ok(None);
+    };
+    let Ok(mut front) = serde_yaml::from_str::<serde_yaml::Mapping>(front) else {
+        return Ok(None);
+    };
+
+    let key = serde_yaml::Value::from("related");
+    let mut related: BTreeSet<String> = front
+        .get(&key)
+        .and_then(|links| links.as_sequence())
+        .into_iter()
+        .flatten()
+        .filter_map(|link| link.as_str().and_then(link_target))
+        .collect();
+    let before = related.len();
+    related.extend(family.iter().cloned());
+    if related.len() == before {
+        return Ok(None);
+    }
+
+    let related: Vec<String> = related.into_iter().collect();
+    front.insert(
+        key,
+        related
+            .iter()
+            .map(|law| serde_yaml::Value::from(wiki_link(law, None, law)))
+            .collect(),
+    );
+    let body = body
+        .split_once("\n\n## 관련 법령\n\n")
+        .map_or(body.trim_end(), |(text, _)| text);
+
+    let mut note = front_matter(&front)?;
+    note.push_str(body);
+    note.push('\n');
+    note.push_str(&related_section(&related));
+    Ok(Some(note))
+}
+
+fn related_section(related: &[String]) -> String {
+    if related.is_empty() {
+        return String::new();
+    }
+    let mut section = String::from("\n## 관련 법령\n\n");
+    for law in related {
+        section.push_str(&format!("- {}\n", wiki_link(law, None, law)));
+    }
+    section
+}
+
+/// Note for a single article, linking back to its law
+pub fn article_note(detail: &LawDetail, article: &Article) -> Result<String> {
+    let name = note_name(&detail.law_name);
+    let label = article_label(&article.number);
+    let title = article.title.as_deref().filter(|t| !t.is_empty());
+
+    let front = ArticleFrontMatter {
+        law: wiki_link(&name, None, &name),
+        article: &label,
+        title,
+    };
+
+    let mut note = front_matter(&front)?;
+    note.push_str(&format!("# {} {}", detail.law_name, label));
+    if let Some(title) = title {
+        note.push_str(&format!(" ({})", title));
+    }
+    note.push_str("\n\n");
+    note.push_str(&linkify(&article.content, &name));
+    note.push_str(&format!(
+        "\n\n← {}\n",
+        wiki_link(&name, Some(&label), &detail.law_name)
+    ));
+    Ok(note)
+}
+
+/// "1" → "제1조", "10의2" → "제10조의2"; numbers already spelled out are kept
+pub fn article_label(number: &str) -> String {
+    let number = number.trim();
+    if number.starts_with('제') {
+        return number.to_string();
+    }
+    match number.split_once('의') {
+        Some((main, branch)) => format!("제{}조의{}", main, branch),
+        None => format!("제{}조", number),
+    }
+}
+
+/// Turn law and article references into wiki links
+///
+/// 「법령」 links to that law's note and a following article to the heading
+/// in it. A bare 제N조 refers to the law the note is about, unless it
+/// directly continues a list that started with another law.
+pub fn linkify(text: &str, current: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    let mut context: Option<String> = None;
+
+    for caps in REFERENCE.captures_iter(text) {
+        let whole = caps.get(0).expect("match");
+        let between = &text[last..whole.start()];
+        out.push_str(between);
+        if !CONNECTOR.is_match(between) {
+            context = None;
+        }
+
+        if let Some(law) = caps.get(1) {
+            let target = note_name(law.as_str());
+            out.push_str(&format!("「{}」", wiki_link(&target, None, law.as_str())));
+            if let Some(article) = caps.get(3) {
+                out.push_str(caps.get(2).map_or("", |m| m.as_str()));
+                out.push_str(&wiki_link(
+                    &target,
+                    Some(article.as_str()),
+                    article.as_str(),
+                ));
+            }
+            context = Some(target);
+        } else {
+            out.push_str(&bare_article(
+                &caps,
+                whole.start(),
+                context.as_deref(),
+                current,
+            ));
+        }
+        last = whole.end();
+    }
+
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Link a bare article reference; the article's own number at the start of
+/// its text is left alone
+fn bare_article(caps: &Captures<'_>, start: usize, context: Option<&str>, current: &str) -> String {
+    let article = &caps[4];
+    if start == 0 {
+        return article.to_string();
+    }
+    wiki_link(context.unwrap_or(current), Some(article), article)
+}
+
+/// Family of a law that is in the vault, plus the related laws reported by
+/// the API
+fn related_laws(detail: &LawDetail, known: &BTreeSet<String>) -> Vec<String> {
+    let mut related: BTreeSet<String> = family_laws(&note_name(&detail.law_name), known)
+        .into_iter()
+        .collect();
+    related.extend(detail.related_laws.iter().map(|law| note_name(&law.title)));
+    related.into_iter().collect()
+}
+
+/// Parent law and decrees of the law noted as `name` that are in the vault
+fn family_laws(name: &str, known: &BTreeSet<String>) -> Vec<String> {
+    let mut related = BTreeSet::new();
+
+    for suffix in SUBORDINATE_SUFFIXES {
+        if let Some(parent) = name.strip_suffix(suffix) {
+            related.insert(parent.to_string());
+        }
+    }
+    let base = SUBORDINATE_SUFFIXES
+        .iter()
+        .find_map(|suffix| name.strip_suffix(suffix))
+        .unwrap_or(name);
+    for suffix in SUBORDINATE_SUFFIXES {
+        related.insert(format!("{}{}", base, suffix));
+    }
+    related.retain(|law| law != name && known.contains(law));
+    related.into_iter().collect()
+}
+
+/// Note name a `[[target]]` or `[[target|label]]` link points to
+fn link_target(link: &str) -> Option<String> {
+    let inner = link.strip_prefix("[[")?.strip_suffix("]]")?;
+    Some(inner.split('|').next().unwrap_or(inner).to_string())
+}
+
+fn wiki_link(target: &str, heading: Option<&str>, label: &str) -> String {
+    let link = match heading {
+        Some(heading) => format!("{}#{}", target, heading),
+        None => target.to_string(),
+    };
+    if link == label {
+        format!("[[{}]]", link)
+    } else {
+        format!("[[{}|{}]]", link, label)
+    }
+}
+
+/// File-safe note name; wiki links use the same name
+fn note_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+fn front_matter<T: Serialize>(value: &T) -> Result<String> {
+    Ok(format!("---\n{}---\n\n", to_yaml(value)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn detail(name: &str, articles: Vec<Article>) -> LawDetail {
+        LawDetail {
+            law_id: "001706".to_string(),
+            law_name: name.to_string(),
+            law_no: Some("265307".to_string()),
+            law_type: Some("법률".to_string()),
+            department: None,
+            enforcement_date: Some("20250131".to_string()),
+            revision_date: None,
+            content: String::new(),
+            articles,
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        }
+    }
+
+    fn article(number: &str, title: Option<&str>, content: &str) -> Article {
+        Article {
+            number: number.to_string(),
+            title: title.map(str::to_string),
+            content: content.to_string(),
+            paragraphs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_article_label() {
+        assert_eq!(article_label("1"), "제1조");
+        assert_eq!(article_label("10의2"), "제10조의2");
+        assert_eq!(article_label("제3조"), "제3조");
+    }
+
+    #[test]
+    fn test_linkify_references() {
+        let text = "제3조(정의) 「민법」 제750조 및 제751조에 따르며, 제2조를 준용한다.";
+        assert_eq!(
+            linkify(text, "상법"),
+            "제3조(정의) 「[[민법]]」 [[민법#제750조|제750조]] 및 [[민법#제751조|제751조]]에 \
+             따르며, [[상법#제2조|제2조]]를 준용한다."
+        );
+    }
+
+    #[test]
+    fn test_vault_is_idempotent_and_links_decrees() {
+        let dir = TempDir::new().unwrap();
+        let vault = Vault::new(dir.path());
+        let law = detail(
+            "민법",
+            vec![
+                article("1", None, "제1편 총칙"),
+                article(
+                    "1",
+                    Some("법원"),
+                    "제1조(법원) 민사에 관하여는 제2조에 따른다.",
+                ),
+            ],
+        );
+        let decree = detail("민법 시행령", vec![]);
+
+        let report = vault.write(&[law.clone(), decree], true).unwrap();
+        assert_eq!(report.written.len(), 3);
+
+        let note = std::fs::read_to_string(dir.path().join("민법.md")).unwrap();
+        assert!(note.starts_with("---\ntitle: 민법\n"));
+        assert!(note.contains("**제1편 총칙**"));
+        assert!(note.contains("### 제1조\n\n제1조(법원) 민사에 관하여는 [[민법#제2조|제2조]]"));
+        assert!(note.contains("- [[민법 시행령]]"));
+        assert!(dir.path().join("민법").join("제1조.md").exists());
+
+        let report = vault.write(&[law], true).unwrap();
+        assert!(report.written.is_empty());
+        assert_eq!(report.unchanged, 2);
+    }
+
+    #[test]
+    fn test_vault_links_notes_from_earlier_exports() {
+        let dir = TempDir::new().unwrap();
+        let vault = Vault::new(dir.path());
+        let law = detail("민법", vec![article("1", None, "제1조(법원) 민사")]);
+        vault.write(std::slice::from_ref(&law), false).unwrap();
+        let before = std::fs::read_to_string(dir.path().join("민법.md")).unwrap();
+        assert!(!before.contains("관련 법령"));
+
+        let report = vault
+            .write(&[detail("민법 시행령", vec![])], false)
+            .unwrap();
+        assert_eq!(report.written.len(), 2);
+        let updated = std::fs::read_to_string(dir.path().join("민법.md")).unwrap();
+        let both = BTreeSet::from(["민법".to_string(), "민법 시행령".to_string()]);
+        assert_eq!(updated, law_note(&law, &both).unwrap());
+
+        let report = vault
+            .write(&[detail("민법 시행령", vec![])], false)
+            .unwrap();
+        assert!(report.written.is_empty());
+    }
+}
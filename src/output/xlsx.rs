@@ -0,0 +1,220 @@
+use rust_xlsxwriter::{Format, FormatAlign, Workbook, Worksheet};
+use std::str::FromStr;
+
+use crate::api::types::{LawDetail, LawHistory, SearchResponse};
+use crate::api::ApiType;
+use crate::error::Result;
+use crate::output::fields::Projection;
+use crate::output::law_go_kr_link;
+
+/// Longest string Excel stores in a single cell
+const MAX_CELL_CHARS: usize = 32_767;
+
+/// Workbook with a "검색결과" sheet, one row per result
+pub fn search_workbook(response: &SearchResponse) -> Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("검색결과")?;
+
+    let headers = [
+        ("번호", 6.0),
+        ("법령명", 40.0),
+        ("법령번호", 16.0),
+        ("종류", 14.0),
+        ("소관부처", 18.0),
+        ("시행일자", 12.0),
+        ("출처", 12.0),
+        ("링크", 14.0),
+    ];
+    write_headers(sheet, &headers)?;
+
+    let offset = response.page_no.saturating_sub(1) * response.page_size;
+    for (i, item) in response.items.iter().enumerate() {
+        let row = i as u32 + 1;
+        sheet.write_number(row, 0, offset + row)?;
+        sheet.write_string(row, 1, cell_text(&item.title))?;
+        sheet.write_string(row, 2, item.law_no.as_deref().unwrap_or(""))?;
+        sheet.write_string(row, 3, item.law_type.as_deref().unwrap_or(""))?;
+        sheet.write_string(row, 4, item.department.as_deref().unwrap_or(""))?;
+        sheet.write_string(row, 5, item.enforcement_date.as_deref().unwrap_or(""))?;
+        let sources = if item.sources.is_empty() {
+            item.source.clone()
+        } else {
+            item.sources.join(", ")
+        };
+        sheet.write_string(row, 6, sources)?;
+
+        // Unified search replaces the source code with its display name
+        let link = ApiType::from_str(&item.source)
+            .ok()
+            .or_else(|| ApiType::from_display_name(&item.source))
+            .and_then(|api_type| law_go_kr_link(api_type, &item.id, &item.title));
+        if let Some(link) = link {
+            sheet.write_url_with_text(row, 7, link.as_str(), "law.go.kr")?;
+        }
+    }
+
+    finish_table(sheet, response.items.len(), headers.len())?;
+    Ok(workbook.save_to_buffer()?)
+}
+
+/// Workbook with a "조문" sheet, one row per article
+///
+/// Precedents and interpretations have no articles; their full text is
+/// written as a single "본문" row instead. A second "정보" sheet holds the
+/// basic fields, with the law name linked to law.go.kr.
+pub fn detail_workbook(detail: &LawDetail, api_type: ApiType) -> Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("조문")?;
+
+    let headers = [("조문번호", 12.0), ("제목", 24.0), ("내용", 100.0)];
+    write_headers(sheet, &headers)?;
+
+    let wrap = Format::new().set_text_wrap().set_align(FormatAlign::Top);
+    for (i, article) in detail.articles.iter().enumerate() {
+        let row = i as u32 + 1;
+        sheet.write_string_with_format(row, 0, &article.number, &wrap)?;
+        sheet.write_string_with_format(row, 1, article.title.as_deref().unwrap_or(""), &wrap)?;
+        sheet.write_string_with_format(row, 2, cell_text(&article.content), &wrap)?;
+    }
+    let mut rows = detail.articles.len();
+    if rows == 0 && !detail.content.trim().is_empty() {
+        sheet.write_string_with_format(1, 0, "본문", &wrap)?;
+        sheet.write_string_with_format(1, 1, &detail.law_name, &wrap)?;
+        sheet.write_string_with_format(1, 2, cell_text(detail.content.trim()), &wrap)?;
+        rows = 1;
+    }
+    finish_table(sheet, rows, headers.len())?;
+
+    let info = workbook.add_worksheet();
+    info.set_name("정보")?;
+    write_headers(info, &[("항목", 12.0), ("내용", 60.0)])?;
+    let fields = [
+        ("법령번호", detail.law_no.as_deref()),
+        ("법령종류", detail.law_type.as_deref()),
+        ("소관부처", detail.department.as_deref()),
+        ("시행일자", detail.enforcement_date.as_deref()),
+        ("개정일자", detail.revision_date.as_deref()),
+    ];
+    info.write_string(1, 0, "법령명")?;
+    match law_go_kr_link(api_type, &detail.law_id, &detail.law_name) {
+        Some(link) => info.write_url_with_text(1, 1, link.as_str(), &detail.law_name)?,
+        None => info.write_string(1, 1, &detail.law_name)?,
+    };
+    let mut row = 2;
+    for (label, value) in fields {
+        if let Some(value) = value {
+            info.write_string(row, 0, label)?;
+            info.write_string(row, 1, value)?;
+            row += 1;
+        }
+    }
+
+    Ok(workbook.save_to_buffer()?)
+}
+
+/// Workbook with a "개정연혁" sheet, one row per revision
+pub fn history_workbook(history: &LawHistory) -> Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("개정연혁")?;
+
+    let headers = [
+        ("순번", 6.0),
+        ("개정일자", 12.0),
+        ("시행일자", 12.0),
+        ("개정구분", 14.0),
+        ("개정이유", 80.0),
+        ("변경조문", 30.0),
+    ];
+    write_headers(sheet, &headers)?;
+
+    let wrap = Format::new().set_text_wrap().set_align(FormatAlign::Top);
+    for (i, entry) in history.entries.iter().enumerate() {
+        let row = i as u32 + 1;
+        sheet.write_number(row, 0, entry.revision_no)?;
+        sheet.write_string(row, 1, &entry.revision_date)?;
+        sheet.write_string(row, 2, entry.enforcement_date.as_deref().unwrap_or(""))?;
+        sheet.write_string(row, 3, &entry.revision_type)?;
+        sheet.write_string_with_format(
+            row,
+            4,
+            cell_text(entry.reason.as_deref().unwrap_or("")),
+            &wrap,
+        )?;
+        sheet.write_string(row, 5, entry.changed_articles.join(", "))?;
+    }
+    finish_table(sheet, history.entries.len(), headers.len())?;
+
+    if let Some(link) = law_go_kr_link(ApiType::Nlic, &history.law_id, &history.law_name) {
+        let row = history.entries.len() as u32 + 2;
+        sheet.write_url_with_text(row, 0, link.as_str(), &history.law_name)?;
+    }
+
+    Ok(workbook.save_to_buffer()?)
+}
+
+/// Workbook with the `--fields` columns, one row per record
+pub fn projection_workbook(projection: &Projection) -> Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(projection.key.map_or("결과", |key| match key {
+        "entries" => "개정연혁",
+        _ => "검색결과",
+    }))?;
+
+    let headers: Vec<(&str, f64)> = projection
+        .columns
+        .iter()
+        .map(|c| (c.as_str(), 20.0))
+        .collect();
+    write_headers(sheet, &headers)?;
+
+    for (i, row) in projection.text_rows().iter().enumerate() {
+        for (col, cell) in row.iter().enumerate() {
+            sheet.write_string(i as u32 + 1, col as u16, cell_text(cell))?;
+        }
+    }
+    finish_table(sheet, projection.rows.len(), headers.len())?;
+    Ok(workbook.save_to_buffer()?)
+}
+
+/// Bold header row with column widths
+fn write_headers(sheet: &mut Worksheet, headers: &[(&str, f64)]) -> Result<()> {
+    let bold = Format::new().set_bold();
+    for (col, (title, width)) in headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *title, &bold)?;
+        sheet.set_column_width(col as u16, *width)?;
+    }
+    Ok(())
+}
+
+/// Keep the header visible and let the rows be filtered
+fn finish_table(sheet: &mut Worksheet, rows: usize, columns: usize) -> Result<()> {
+    sheet.set_freeze_panes(1, 0)?;
+    if rows > 0 {
+        sheet.autofilter(0, 0, rows as u32, columns as u16 - 1)?;
+    }
+    Ok(())
+}
+
+/// Cut text to what fits in one cell
+fn cell_text(text: &str) -> &str {
+    match text.char_indices().nth(MAX_CELL_CHARS) {
+        Some((idx, _)) => &text[..idx],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_text_limit() {
+        let long = "가".repeat(MAX_CELL_CHARS + 10);
+        assert_eq!(cell_text(&long).chars().count(), MAX_CELL_CHARS);
+        assert_eq!(cell_text("민법"), "민법");
+    }
+}
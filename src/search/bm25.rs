@@ -0,0 +1,248 @@
+use crate::api::types::SearchItem;
+use std::collections::HashMap;
+
+/// Weight of title terms relative to summary and metadata text
+const TITLE_BOOST: usize = 2;
+
+/// Split text into index terms with Korean-aware n-grams
+///
+/// Korean legal text rarely separates particles and compounds with spaces
+/// (e.g. "개인정보보호법을"), so Hangul runs are broken into overlapping
+/// character bigrams while Latin words and numbers are kept whole.
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_lowercase();
+        let mut run = String::new();
+        let mut run_is_hangul = false;
+
+        for c in word.chars() {
+            let hangul = is_hangul(c);
+            if !run.is_empty() && hangul != run_is_hangul {
+                push_run(&mut terms, &run, run_is_hangul);
+                run.clear();
+            }
+            run_is_hangul = hangul;
+            run.push(c);
+        }
+        if !run.is_empty() {
+            push_run(&mut terms, &run, run_is_hangul);
+        }
+    }
+
+    terms
+}
+
+fn push_run(terms: &mut Vec<String>, run: &str, hangul: bool) {
+    let chars: Vec<char> = run.chars().collect();
+    if !hangul || chars.len() < 2 {
+        terms.push(run.to_string());
+        return;
+    }
+    for pair in chars.windows(2) {
+        terms.push(pair.iter().collect());
+    }
+}
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+/// Okapi BM25 scorer over a set of search results
+///
+/// The corpus statistics (document frequency, average length) are taken
+/// from the items being ranked, which is what we have for merged API pages.
+#[derive(Debug, Clone)]
+pub struct Bm25Scorer {
+    /// Term frequency saturation
+    pub k1: f32,
+    /// Length normalization strength
+    pub b: f32,
+}
+
+impl Default for Bm25Scorer {
+    fn default() -> Self {
+        Self { k1: 1.2, b: 0.75 }
+    }
+}
+
+impl Bm25Scorer {
+    pub fn new(k1: f32, b: f32) -> Self {
+        Self { k1, b }
+    }
+
+    /// Score every item against the query and store it in `SearchItem::score`
+    ///
+    /// Scores are normalized to 0.0-1.0 relative to the best match in the
+    /// set, so `--min-score 0.5` keeps results at least half as relevant as
+    /// the top hit. Items sharing no terms with the query score 0.0.
+    pub fn score_items(&self, query: &str, items: &mut [SearchItem]) {
+        let query_terms = unique(tokenize(query));
+        if items.is_empty() {
+            return;
+        }
+        if query_terms.is_empty() {
+            for item in items.iter_mut() {
+                item.score = Some(0.0);
+            }
+            return;
+        }
+
+        let documents: Vec<Vec<String>> = items.iter().map(document_terms).collect();
+        let raw = self.score_documents(&query_terms, &documents);
+
+        let max = raw.iter().cloned().fold(0.0f32, f32::max);
+        for (item, score) in items.iter_mut().zip(raw) {
+            item.score = Some(if max > 0.0 { score / max } else { 0.0 });
+        }
+    }
+
+    /// Raw (unnormalized) BM25 scores of tokenized documents
+    pub fn score_documents(&self, query_terms: &[String], documents: &[Vec<String>]) -> Vec<f32> {
+        let doc_count = documents.len() as f32;
+        if documents.is_empty() {
+            return Vec::new();
+        }
+
+        let avg_len = documents.iter().map(|d| d.len()).sum::<usize>() as f32 / doc_count;
+        let avg_len = avg_len.max(1.0);
+
+        let frequencies: Vec<HashMap<&str, usize>> = documents
+            .iter()
+            .map(|doc| {
+                let mut tf = HashMap::new();
+                for term in doc {
+                    *tf.entry(term.as_str()).or_insert(0) += 1;
+                }
+                tf
+            })
+            .collect();
+
+        let idf: Vec<f32> = query_terms
+            .iter()
+            .map(|term| {
+                let df = frequencies
+                    .iter()
+                    .filter(|tf| tf.contains_key(term.as_str()))
+                    .count() as f32;
+                ((doc_count - df + 0.5) / (df + 0.5) + 1.0).ln()
+            })
+            .collect();
+
+        documents
+            .iter()
+            .zip(&frequencies)
+            .map(|(doc, tf)| {
+                let len_norm = 1.0 - self.b + self.b * doc.len() as f32 / avg_len;
+                query_terms
+                    .iter()
+                    .zip(&idf)
+                    .map(|(term, idf)| {
+                        let f = *tf.get(term.as_str()).unwrap_or(&0) as f32;
+                        idf * f * (self.k1 + 1.0) / (f + self.k1 * len_norm)
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+/// Terms of the searchable text of an item: boosted title, summary and text metadata
+fn document_terms(item: &SearchItem) -> Vec<String> {
+    let mut terms = Vec::new();
+    let title_terms = tokenize(&item.title);
+    for _ in 0..TITLE_BOOST {
+        terms.extend(title_terms.iter().cloned());
+    }
+    if let Some(ref summary) = item.summary {
+        terms.extend(tokenize(summary));
+    }
+    for (key, value) in &item.metadata {
+        // Links carry URL fragments, not document text
+        if key.contains("link") || key.contains("url") {
+            continue;
+        }
+        terms.extend(tokenize(value));
+    }
+    terms
+}
+
+fn unique(terms: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    terms
+        .into_iter()
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str, summary: Option<&str>) -> SearchItem {
+        SearchItem {
+            id: title.to_string(),
+            title: title.to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: summary.map(|s| s.to_string()),
+            source: "NLIC".to_string(),
+            metadata: HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_tokenize_korean_bigrams() {
+        assert_eq!(tokenize("민법"), vec!["민법"]);
+        assert_eq!(tokenize("개인정보"), vec!["개인", "인정", "정보"]);
+        assert_eq!(tokenize("법"), vec!["법"]);
+    }
+
+    #[test]
+    fn test_tokenize_mixed_text() {
+        assert_eq!(
+            tokenize("GDPR 대응, 제3조"),
+            vec!["gdpr", "대응", "제", "3", "조"]
+        );
+    }
+
+    #[test]
+    fn test_particles_still_match() {
+        // "개인정보를" shares every bigram of "개인정보" plus one for the particle
+        let query = tokenize("개인정보");
+        let doc = tokenize("개인정보를");
+        assert!(query.iter().all(|t| doc.contains(t)));
+    }
+
+    #[test]
+    fn test_score_items_ranks_title_matches_first() {
+        let mut items = vec![
+            item("건축법", Some("건축물의 대지 및 구조")),
+            item("개인정보 보호법", Some("개인정보의 처리 및 보호")),
+            item("정보통신망법", Some("개인정보 유출 시 통지")),
+        ];
+
+        Bm25Scorer::default().score_items("개인정보 보호", &mut items);
+
+        let scores: Vec<f32> = items.iter().map(|i| i.score.unwrap()).collect();
+        assert_eq!(scores[1], 1.0);
+        assert!(scores[1] > scores[2]);
+        assert_eq!(scores[0], 0.0);
+    }
+
+    #[test]
+    fn test_score_items_empty_query() {
+        let mut items = vec![item("민법", None)];
+        Bm25Scorer::default().score_items("  ", &mut items);
+        assert_eq!(items[0].score, Some(0.0));
+    }
+}
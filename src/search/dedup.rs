@@ -0,0 +1,226 @@
+use crate::api::types::SearchItem;
+use crate::api::ApiType;
+use std::collections::HashMap;
+
+/// Key identifying the same document across sources and revisions
+///
+/// Precedents and interpretations carry a case/interpretation number that is
+/// stable for the document. For laws, ordinances and administrative rules the
+/// number returned by the APIs is a per-revision serial, so the normalized
+/// title is used instead to fold revisions of the same law together.
+///
+/// Municipalities and agencies issue ordinances and rules under the same
+/// titles ("주차장 설치 및 관리 조례"), so those are keyed by issuer and
+/// title; without an issuer they are only folded with the same document ID.
+pub fn dedup_key(api_type: ApiType, item: &SearchItem) -> String {
+    let number = item
+        .law_no
+        .as_deref()
+        .map(normalize_number)
+        .filter(|n| !n.is_empty());
+
+    match (api_type, number) {
+        (ApiType::Prec | ApiType::Expc, Some(number)) => format!("no:{}", number),
+        (ApiType::Elis | ApiType::Admrul, _) => {
+            match item
+                .department
+                .as_deref()
+                .map(normalize_title)
+                .filter(|d| !d.is_empty())
+            {
+                Some(issuer) => format!("title:{}:{}", issuer, normalize_title(&item.title)),
+                None => format!("id:{}:{}", api_type.as_str(), item.id),
+            }
+        }
+        _ => format!("title:{}", normalize_title(&item.title)),
+    }
+}
+
+/// Remove duplicate items, keeping the first occurrence of each document
+///
+/// The surviving item takes missing fields and metadata from its duplicates,
+/// the best score among them, and lists every contributing source in
+/// `SearchItem::sources`.
+pub fn dedup_items(items: Vec<(ApiType, SearchItem)>) -> Vec<SearchItem> {
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut unique: Vec<SearchItem> = Vec::with_capacity(items.len());
+
+    for (api_type, mut item) in items {
+        let key = dedup_key(api_type, &item);
+        match positions.get(&key) {
+            Some(&idx) => merge_into(&mut unique[idx], item),
+            None => {
+                positions.insert(key, unique.len());
+                if item.sources.is_empty() {
+                    item.sources.push(item.source.clone());
+                }
+                unique.push(item);
+            }
+        }
+    }
+
+    unique
+}
+
+/// Fold a duplicate into the surviving item
+fn merge_into(survivor: &mut SearchItem, duplicate: SearchItem) {
+    let duplicate_sources = if duplicate.sources.is_empty() {
+        vec![duplicate.source.clone()]
+    } else {
+        duplicate.sources
+    };
+    for source in duplicate_sources {
+        if !survivor.sources.contains(&source) {
+            survivor.sources.push(source);
+        }
+    }
+
+    fill(&mut survivor.law_no, duplicate.law_no);
+    fill(&mut survivor.law_type, duplicate.law_type);
+    fill(&mut survivor.department, duplicate.department);
+    fill(&mut survivor.enforcement_date, duplicate.enforcement_date);
+    fill(&mut survivor.revision_date, duplicate.revision_date);
+    fill(&mut survivor.summary, duplicate.summary);
+
+    for (key, value) in duplicate.metadata {
+        survivor.metadata.entry(key).or_insert(value);
+    }
+
+    survivor.score = match (survivor.score, duplicate.score) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+}
+
+fn fill(target: &mut Option<String>, value: Option<String>) {
+    if target.as_ref().is_some_and(|t| !t.is_empty()) {
+        return;
+    }
+    if let Some(value) = value.filter(|v| !v.is_empty()) {
+        *target = Some(value);
+    }
+}
+
+/// Normalize a law/case number: "제 2019다12345 호" and "2019다12345" match
+fn normalize_number(number: &str) -> String {
+    let compact: String = number
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    let compact = compact.strip_prefix('제').unwrap_or(&compact);
+    let compact = compact.strip_suffix('호').unwrap_or(compact);
+    compact.to_string()
+}
+
+/// Normalize a title by dropping whitespace and punctuation
+fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str, law_no: Option<&str>, source: &str) -> SearchItem {
+        SearchItem {
+            id: format!("{}-{}", source, title),
+            title: title.to_string(),
+            law_no: law_no.map(|s| s.to_string()),
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: source.to_string(),
+            metadata: HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(normalize_number("제 2019다12345 호"), "2019다12345");
+        assert_eq!(normalize_number("2019-다-12345"), "2019다12345");
+        assert_eq!(
+            normalize_title("개인정보 보호법"),
+            normalize_title("개인정보보호법")
+        );
+    }
+
+    #[test]
+    fn test_revisions_of_a_law_fold_together() {
+        let mut newer = item("민법", Some("265307"), "국가법령");
+        newer.enforcement_date = Some("20250131".to_string());
+        let mut older = item("민 법", Some("250001"), "국가법령");
+        older.department = Some("법무부".to_string());
+        older
+            .metadata
+            .insert("detail_link".to_string(), "/old".to_string());
+
+        let items = dedup_items(vec![
+            (ApiType::Nlic, newer),
+            (ApiType::Nlic, older),
+            (ApiType::Nlic, item("형법", Some("100"), "국가법령")),
+        ]);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].law_no.as_deref(), Some("265307"));
+        assert_eq!(items[0].enforcement_date.as_deref(), Some("20250131"));
+        assert_eq!(items[0].department.as_deref(), Some("법무부"));
+        assert_eq!(items[0].metadata.get("detail_link").unwrap(), "/old");
+        assert_eq!(items[0].sources, vec!["국가법령"]);
+    }
+
+    #[test]
+    fn test_ordinances_keyed_on_issuer() {
+        let ordinance = |id: &str, region: Option<&str>| {
+            let mut item = item("주차장 설치 및 관리 조례", None, "자치법규");
+            item.id = id.to_string();
+            item.department = region.map(str::to_string);
+            item
+        };
+
+        let items = dedup_items(vec![
+            (ApiType::Elis, ordinance("1", Some("서울특별시"))),
+            (ApiType::Elis, ordinance("2", Some("부산광역시"))),
+            (ApiType::Elis, ordinance("3", Some("서울특별시"))),
+            (ApiType::Elis, ordinance("4", None)),
+            (ApiType::Elis, ordinance("5", None)),
+        ]);
+        let ids: Vec<_> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "4", "5"]);
+    }
+
+    #[test]
+    fn test_precedents_keyed_on_case_number() {
+        // Precedents often share generic titles such as "손해배상(기)"
+        let items = dedup_items(vec![
+            (
+                ApiType::Prec,
+                item("손해배상(기)", Some("2019다12345"), "판례"),
+            ),
+            (ApiType::Prec, item("손해배상(기)", Some("2020다1"), "판례")),
+        ]);
+        assert_eq!(items.len(), 2);
+
+        let items = dedup_items(vec![
+            (
+                ApiType::Prec,
+                item("손해배상(기)", Some("2019다12345"), "판례"),
+            ),
+            (
+                ApiType::Prec,
+                item("손해배상", Some("제2019다 12345호"), "PREC"),
+            ),
+        ]);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "손해배상(기)");
+        assert_eq!(items[0].sources, vec!["판례", "PREC"]);
+    }
+}
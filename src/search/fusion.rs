@@ -0,0 +1,134 @@
+use crate::api::types::SearchItem;
+use crate::api::ApiType;
+use crate::search::dedup_key;
+use std::collections::HashMap;
+
+/// Rank constant from the original RRF paper; dampens the head of each list
+pub const DEFAULT_RRF_K: f32 = 60.0;
+
+/// Reciprocal-rank fusion of result lists from several sources
+///
+/// A document at 0-based rank `r` in the list of a source with weight `w`
+/// contributes `w / (k + r + 1)`; contributions of the same document (by
+/// [`dedup_key`]) from different sources are summed. Only ranks are used, so
+/// sources whose own scores are not comparable can still be interleaved.
+#[derive(Debug, Clone)]
+pub struct RankFusion {
+    /// Rank constant
+    pub k: f32,
+    /// Per-source weights; sources without an entry weigh 1.0
+    pub weights: HashMap<ApiType, f32>,
+}
+
+impl Default for RankFusion {
+    fn default() -> Self {
+        Self {
+            k: DEFAULT_RRF_K,
+            weights: HashMap::new(),
+        }
+    }
+}
+
+impl RankFusion {
+    pub fn new(k: f32) -> Self {
+        Self {
+            k,
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Use the given per-source weights
+    pub fn with_weights(mut self, weights: HashMap<ApiType, f32>) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Weight of a source (1.0 unless configured)
+    pub fn weight(&self, api_type: ApiType) -> f32 {
+        self.weights.get(&api_type).copied().unwrap_or(1.0)
+    }
+
+    /// Order items by their fused score, best first
+    ///
+    /// Items must be in each source's own rank order; items of different
+    /// sources may be interleaved arbitrarily. Ties keep the input order.
+    pub fn fuse(&self, items: Vec<(ApiType, SearchItem)>) -> Vec<(ApiType, SearchItem)> {
+        let mut ranks: HashMap<ApiType, usize> = HashMap::new();
+        let mut fused: HashMap<String, f32> = HashMap::new();
+        let mut keyed = Vec::with_capacity(items.len());
+
+        for (api_type, item) in items {
+            let rank = ranks.entry(api_type).or_insert(0);
+            let key = dedup_key(api_type, &item);
+            *fused.entry(key.clone()).or_insert(0.0) +=
+                self.weight(api_type) / (self.k + *rank as f32 + 1.0);
+            *rank += 1;
+            keyed.push((key, api_type, item));
+        }
+
+        keyed.sort_by(|(a, ..), (b, ..)| fused[b].total_cmp(&fused[a]));
+        keyed
+            .into_iter()
+            .map(|(_, api_type, item)| (api_type, item))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str) -> SearchItem {
+        SearchItem {
+            id: title.to_string(),
+            title: title.to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: String::new(),
+            metadata: HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        }
+    }
+
+    fn titles(items: &[(ApiType, SearchItem)]) -> Vec<&str> {
+        items.iter().map(|(_, i)| i.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_interleaves_sources_by_rank() {
+        let fused = RankFusion::default().fuse(vec![
+            (ApiType::Nlic, item("법1")),
+            (ApiType::Nlic, item("법2")),
+            (ApiType::Elis, item("조례1")),
+            (ApiType::Elis, item("조례2")),
+        ]);
+        assert_eq!(titles(&fused), vec!["법1", "조례1", "법2", "조례2"]);
+    }
+
+    #[test]
+    fn test_weights_favour_a_source() {
+        let weights = HashMap::from([(ApiType::Prec, 2.0)]);
+        let fused = RankFusion::default().with_weights(weights).fuse(vec![
+            (ApiType::Elis, item("조례1")),
+            (ApiType::Prec, item("판례1")),
+            (ApiType::Prec, item("판례2")),
+        ]);
+        assert_eq!(titles(&fused), vec!["판례1", "판례2", "조례1"]);
+    }
+
+    #[test]
+    fn test_documents_found_by_several_sources_rank_higher() {
+        let fused = RankFusion::default().fuse(vec![
+            (ApiType::Nlic, item("민법")),
+            (ApiType::Nlic, item("상법")),
+            (ApiType::Expc, item("해석례")),
+            (ApiType::Expc, item("상법")),
+        ]);
+        assert_eq!(titles(&fused), vec!["상법", "상법", "민법", "해석례"]);
+    }
+}
@@ -0,0 +1,9 @@
+//! Result ranking and post-processing shared by the search commands
+
+pub mod bm25;
+pub mod dedup;
+pub mod fusion;
+
+pub use bm25::{tokenize, Bm25Scorer};
+pub use dedup::{dedup_items, dedup_key};
+pub use fusion::RankFusion;
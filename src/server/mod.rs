@@ -0,0 +1,392 @@
+//! JSON HTTP API over the search, detail and history operations
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+use crate::api::types::UnifiedSearchRequest;
+use crate::api::ApiType;
+use crate::cli::commands::metrics::structured_report;
+use crate::cli::commands::search::{date_bound, parse_source};
+use crate::cli::session::Session;
+use crate::error::{Result, WarpError};
+use crate::metrics::get_global_metrics;
+use crate::output::fields::FieldSelection;
+
+/// Query of `GET /search`, named after the options of `warp search`
+#[derive(Debug, Deserialize)]
+struct SearchParams {
+    q: String,
+    /// Sources like `--source`: all, nlic, or a comma-separated list
+    source: Option<String>,
+    /// Page of every source, from 1
+    page: Option<u32>,
+    size: Option<u32>,
+    law_type: Option<String>,
+    department: Option<String>,
+    region: Option<String>,
+    /// Date range, YYYY or YYYYMMDD
+    from: Option<String>,
+    to: Option<String>,
+    /// relevance, date_asc, date_desc, title_asc or title_desc
+    sort: Option<String>,
+    /// title or body
+    scope: Option<String>,
+    /// Fields like `--fields`, comma-separated
+    fields: Option<String>,
+}
+
+impl SearchParams {
+    /// The search request these parameters describe
+    fn request(&self, page_size: u32) -> Result<UnifiedSearchRequest> {
+        let invalid = |e: String| WarpError::InvalidInput(e);
+        Ok(UnifiedSearchRequest {
+            query: self.q.trim().to_string(),
+            page_no: self.page.unwrap_or(1).max(1),
+            page_size: self.size.unwrap_or(page_size).max(1),
+            law_type: self.law_type.clone(),
+            department: self.department.clone(),
+            region: self.region.clone(),
+            date_from: self
+                .from
+                .as_deref()
+                .map(|d| date_bound(d, false))
+                .transpose()?,
+            date_to: self
+                .to
+                .as_deref()
+                .map(|d| date_bound(d, true))
+                .transpose()?,
+            sort: self
+                .sort
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(invalid)?,
+            search_scope: self
+                .scope
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(invalid)?
+                .unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Query of `GET /detail/{id}` and `GET /history/{id}`
+#[derive(Debug, Deserialize)]
+struct SourceParams {
+    source: Option<String>,
+}
+
+/// A `WarpError` answered as JSON with a matching status code
+struct ApiError(WarpError);
+
+impl From<WarpError> for ApiError {
+    fn from(e: WarpError) -> Self {
+        Self(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            WarpError::InvalidInput(_) => StatusCode::BAD_REQUEST,
+            WarpError::NotFound(_) => StatusCode::NOT_FOUND,
+            WarpError::RateLimit => StatusCode::TOO_MANY_REQUESTS,
+            WarpError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            WarpError::Network(_) | WarpError::ApiError { .. } | WarpError::ServerError(_) => {
+                StatusCode::BAD_GATEWAY
+            }
+            WarpError::NoApiKey | WarpError::Offline(_) | WarpError::AuthenticationFailed(_) => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let body = json!({
+            "error": self.0.to_string(),
+            "hint": self.0.hint(),
+        });
+        (status, Json(body)).into_response()
+    }
+}
+
+type ApiResult<T> = std::result::Result<T, ApiError>;
+
+/// Routes of the API, sharing one session
+pub fn router(session: Arc<Session>) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/search", get(search))
+        .route("/detail/{id}", get(detail))
+        .route("/history/{id}", get(history))
+        .route("/metrics", get(metrics))
+        .with_state(session)
+}
+
+/// Serve the API on `listener` until `shutdown` completes
+pub async fn serve(
+    listener: TcpListener,
+    session: Session,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<()> {
+    axum::serve(listener, router(Arc::new(session)))
+        .with_graceful_shutdown(shutdown)
+        .await?;
+    Ok(())
+}
+
+async fn health(State(session): State<Arc<Session>>) -> Json<Value> {
+    let sources: Vec<&str> = session.sources().iter().map(ApiType::as_str).collect();
+    Json(json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION"),
+        "sources": sources,
+    }))
+}
+
+async fn search(
+    State(session): State<Arc<Session>>,
+    Query(params): Query<SearchParams>,
+) -> ApiResult<Json<Value>> {
+    if params.q.trim().is_empty() {
+        return Err(WarpError::InvalidInput("검색어(q)를 입력하세요".to_string()).into());
+    }
+    let api_types = match params.source.as_deref() {
+        Some(source) => {
+            let api_types = parse_source(source);
+            if api_types.is_empty() {
+                return Err(WarpError::InvalidInput(format!("알 수 없는 소스: {}", source)).into());
+            }
+            api_types
+        }
+        None => Vec::new(),
+    };
+    let request = params.request(session.page_size())?;
+    let response = session.search_request(&api_types, request).await?;
+
+    let value = match params.fields {
+        Some(fields) => {
+            let selection = FieldSelection::new(fields.split(','))?;
+            serde_json::to_value(selection.project_search(&response)?)
+        }
+        None => serde_json::to_value(&response),
+    };
+    Ok(Json(value.map_err(WarpError::from)?))
+}
+
+async fn detail(
+    State(session): State<Arc<Session>>,
+    Path(id): Path<String>,
+    Query(params): Query<SourceParams>,
+) -> ApiResult<Json<Value>> {
+    let api_type = source_param(params.source.as_deref())?;
+    let detail = session.client(api_type)?.get_detail(&id).await?;
+    Ok(Json(
+        serde_json::to_value(&detail).map_err(WarpError::from)?,
+    ))
+}
+
+async fn history(
+    State(session): State<Arc<Session>>,
+    Path(id): Path<String>,
+    Query(params): Query<SourceParams>,
+) -> ApiResult<Json<Value>> {
+    let api_type = source_param(params.source.as_deref())?;
+    let history = session.client(api_type)?.get_history(&id).await?;
+    Ok(Json(
+        serde_json::to_value(&history).map_err(WarpError::from)?,
+    ))
+}
+
+async fn metrics() -> Json<Value> {
+    let snapshot = get_global_metrics().get_snapshot().await;
+    Json(structured_report(
+        &snapshot,
+        &chrono::Local::now(),
+        None,
+        "json",
+    ))
+}
+
+/// Single source of a detail or history request; the law API by default
+fn source_param(source: Option<&str>) -> Result<ApiType> {
+    match source {
+        None => Ok(ApiType::Nlic),
+        Some(source) => source
+            .parse::<ApiType>()
+            .ok()
+            .filter(|t| *t != ApiType::All)
+            .ok_or_else(|| WarpError::InvalidInput(format!("알 수 없는 소스: {}", source))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::LegalApiClient;
+    use crate::api::types::{
+        LawDetail, LawHistory, SearchItem, SearchResponse, SearchScope, SortOrder,
+    };
+    use crate::search::RankFusion;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Reports 42 results and remembers the last request it was sent
+    #[derive(Default)]
+    struct MockClient {
+        last: Mutex<Option<UnifiedSearchRequest>>,
+    }
+
+    #[async_trait]
+    impl LegalApiClient for MockClient {
+        async fn search(&self, request: UnifiedSearchRequest) -> Result<SearchResponse> {
+            *self.last.lock().unwrap() = Some(request.clone());
+            let items = (1..=request.page_size)
+                .map(|n| SearchItem {
+                    id: n.to_string(),
+                    title: format!("{} {}", request.query, n),
+                    law_no: None,
+                    law_type: None,
+                    department: None,
+                    enforcement_date: None,
+                    revision_date: None,
+                    summary: None,
+                    source: String::new(),
+                    metadata: HashMap::new(),
+                    score: None,
+                    sources: Vec::new(),
+                })
+                .collect();
+            Ok(SearchResponse {
+                total_count: 42,
+                page_no: request.page_no,
+                page_size: request.page_size,
+                items,
+                source: "Mock".to_string(),
+                timestamp: Utc::now(),
+                stale: false,
+                cursors: Vec::new(),
+            })
+        }
+
+        async fn get_detail(&self, id: &str) -> Result<LawDetail> {
+            Err(WarpError::NotFound(id.to_string()))
+        }
+
+        async fn get_history(&self, _id: &str) -> Result<LawHistory> {
+            unimplemented!("Mock history not implemented")
+        }
+
+        fn api_type(&self) -> ApiType {
+            ApiType::Nlic
+        }
+        fn base_url(&self) -> &str {
+            "http://mock"
+        }
+        fn is_configured(&self) -> bool {
+            true
+        }
+    }
+
+    /// Start a server on a free port and return its base URL and client
+    async fn start_with_client() -> (String, Arc<MockClient>) {
+        let client = Arc::new(MockClient::default());
+        let session = Session::new(
+            vec![(ApiType::Nlic, client.clone())],
+            RankFusion::default(),
+            3,
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, session, std::future::pending()));
+        (format!("http://{}", addr), client)
+    }
+
+    async fn start() -> String {
+        start_with_client().await.0
+    }
+
+    async fn get(url: String) -> (u16, Value) {
+        let response = reqwest::get(url).await.unwrap();
+        let status = response.status().as_u16();
+        (status, response.json().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_search_endpoint() {
+        let base = start().await;
+
+        let (status, body) = get(format!("{}/search?q=민법", base)).await;
+        assert_eq!(status, 200);
+        assert_eq!(body["items"].as_array().unwrap().len(), 3);
+        assert_eq!(body["items"][0]["source"], "국가법령정보센터");
+
+        let (_, body) = get(format!("{}/search?q=민법&size=1&fields=id,title", base)).await;
+        assert_eq!(body, json!({"items": [{"id": "1", "title": "민법 1"}]}));
+
+        let (_, body) = get(format!("{}/health", base)).await;
+        assert_eq!(body["sources"], json!(["nlic"]));
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_and_paging() {
+        let (base, client) = start_with_client().await;
+
+        let (status, body) = get(format!(
+            "{}/search?q=민법&page=2&size=2&law_type=법률&department=법무부&region=서울\
+             &from=2020&to=2021&sort=date_desc&scope=body",
+            base
+        ))
+        .await;
+        assert_eq!(status, 200);
+        assert_eq!(body["total_count"], 42);
+        assert_eq!(body["page_no"], 2);
+        assert_eq!(body["page_size"], 2);
+        assert_eq!(body["cursors"][0]["total_count"], 42);
+
+        let sent = client.last.lock().unwrap().take().unwrap();
+        assert_eq!((sent.page_no, sent.page_size), (2, 2));
+        assert_eq!(sent.law_type.as_deref(), Some("법률"));
+        assert_eq!(sent.department.as_deref(), Some("법무부"));
+        assert_eq!(sent.region.as_deref(), Some("서울"));
+        assert_eq!(sent.date_from.as_deref(), Some("20200101"));
+        assert_eq!(sent.date_to.as_deref(), Some("20211231"));
+        assert!(matches!(sent.sort, Some(SortOrder::DateDesc)));
+        assert_eq!(sent.search_scope, SearchScope::Body);
+
+        let (status, _) = get(format!("{}/search?q=민법&sort=newest", base)).await;
+        assert_eq!(status, 400);
+        let (status, _) = get(format!("{}/search?q=민법&scope=all", base)).await;
+        assert_eq!(status, 400);
+    }
+
+    #[tokio::test]
+    async fn test_errors_map_to_status_codes() {
+        let base = start().await;
+
+        let (status, body) = get(format!("{}/search?q=%20", base)).await;
+        assert_eq!(status, 400);
+        assert!(body["error"].as_str().unwrap().contains("검색어"));
+
+        // Sources without a configured client are rejected
+        let (status, _) = get(format!("{}/search?q=민법&source=prec", base)).await;
+        assert_eq!(status, 400);
+        let (status, _) = get(format!("{}/detail/1?source=nope", base)).await;
+        assert_eq!(status, 400);
+
+        let (status, _) = get(format!("{}/detail/001706", base)).await;
+        assert_eq!(status, 404);
+    }
+}
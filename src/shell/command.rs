@@ -0,0 +1,223 @@
+use clap::ValueEnum;
+
+use crate::api::ApiType;
+use crate::cli::OutputFormat;
+use crate::error::{Result, WarpError};
+
+/// Commands of `warp shell`: name, arguments and description, for help and completion
+pub const COMMANDS: &[(&str, &str, &str)] = &[
+    ("search", "<검색어>", "모든 소스 통합 검색"),
+    ("law", "<검색어>", "국가법령 검색"),
+    ("ordinance", "<검색어>", "자치법규 검색"),
+    ("precedent", "<검색어>", "판례 검색"),
+    ("admrule", "<검색어>", "행정규칙 검색"),
+    ("interpretation", "<검색어>", "법령해석례 검색"),
+    (
+        "detail",
+        "[소스] <번호|ID>",
+        "상세 정보 (번호는 마지막 검색 결과)",
+    ),
+    ("history", "[소스] <번호|ID>", "개정 연혁"),
+    ("results", "", "마지막 검색 결과 다시 보기"),
+    (
+        "format",
+        "<형식>",
+        "출력 형식 변경 (table, json, markdown, ...)",
+    ),
+    ("size", "<N>", "소스별 검색 결과 수"),
+    ("help", "", "도움말"),
+    ("exit", "", "종료 (Ctrl-D)"),
+];
+
+/// Largest bare number read as a result number rather than an ID
+const MAX_RESULT_NUMBER: usize = 999;
+
+/// A parsed shell line
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShellCommand {
+    /// Search one source, or all of them when `source` is `None`
+    Search {
+        source: Option<ApiType>,
+        query: String,
+    },
+    Detail(Target),
+    History(Target),
+    Results,
+    Format(OutputFormat),
+    Size(u32),
+    Help,
+    Exit,
+    Empty,
+}
+
+/// What `detail` and `history` refer to
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    /// 1-based number in the last results (`3` or `#3`)
+    Result(usize),
+    /// Document ID; without a source it is looked up in the law API
+    Id { source: Option<ApiType>, id: String },
+}
+
+impl ShellCommand {
+    pub fn parse(line: &str) -> Result<Self> {
+        let line = line.trim();
+        let (name, rest) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(name, rest)| (name, rest.trim()));
+
+        let command =
+            match name {
+                "" => Self::Empty,
+                "search" | "s" => search(None, rest)?,
+                "law" | "l" => search(Some(ApiType::Nlic), rest)?,
+                "ordinance" | "o" => search(Some(ApiType::Elis), rest)?,
+                "precedent" | "p" => search(Some(ApiType::Prec), rest)?,
+                "admrule" | "a" => search(Some(ApiType::Admrul), rest)?,
+                "interpretation" | "i" => search(Some(ApiType::Expc), rest)?,
+                "detail" | "d" => Self::Detail(Target::parse(rest)?),
+                "history" | "h" => Self::History(Target::parse(rest)?),
+                "results" | "r" => Self::Results,
+                "format" | "f" => Self::Format(
+                    OutputFormat::from_str(rest, true)
+                        .map_err(|_| invalid(format!("알 수 없는 출력 형식: {}", rest)))?,
+                ),
+                "size" => Self::Size(rest.parse().ok().filter(|&n| n > 0).ok_or_else(|| {
+                    invalid(format!("결과 수는 1 이상의 숫자여야 합니다: {}", rest))
+                })?),
+                "help" | "?" => Self::Help,
+                "exit" | "quit" | "q" => Self::Exit,
+                other => {
+                    return Err(invalid(format!(
+                        "알 수 없는 명령: {} (help로 명령 목록 확인)",
+                        other
+                    )))
+                }
+            };
+        Ok(command)
+    }
+}
+
+impl Target {
+    fn parse(args: &str) -> Result<Self> {
+        let words: Vec<&str> = args.split_whitespace().collect();
+        match words.as_slice() {
+            [source, id] => Ok(Self::Id {
+                source: Some(parse_source(source)?),
+                id: id.to_string(),
+            }),
+            [arg] => {
+                let number = arg
+                    .strip_prefix('#')
+                    .or_else(|| (!arg.starts_with('0')).then_some(*arg))
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|&n| n >= 1 && (arg.starts_with('#') || n <= MAX_RESULT_NUMBER));
+                Ok(match number {
+                    Some(n) => Self::Result(n),
+                    None => Self::Id {
+                        source: None,
+                        id: arg.to_string(),
+                    },
+                })
+            }
+            _ => Err(invalid("번호나 ID를 하나 지정하세요 (예: detail 3)")),
+        }
+    }
+}
+
+fn search(source: Option<ApiType>, query: &str) -> Result<ShellCommand> {
+    let query = query.trim_matches(|c| c == '"' || c == '\'').trim();
+    if query.is_empty() {
+        return Err(invalid("검색어를 입력하세요"));
+    }
+    Ok(ShellCommand::Search {
+        source,
+        query: query.to_string(),
+    })
+}
+
+fn parse_source(source: &str) -> Result<ApiType> {
+    source
+        .parse::<ApiType>()
+        .ok()
+        .filter(|t| *t != ApiType::All)
+        .ok_or_else(|| invalid(format!("알 수 없는 소스: {}", source)))
+}
+
+fn invalid(message: impl Into<String>) -> WarpError {
+    WarpError::InvalidInput(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search_commands() {
+        assert_eq!(
+            ShellCommand::parse("  law \"개인정보 보호법\" ").unwrap(),
+            ShellCommand::Search {
+                source: Some(ApiType::Nlic),
+                query: "개인정보 보호법".to_string(),
+            }
+        );
+        assert_eq!(
+            ShellCommand::parse("s 민법").unwrap(),
+            ShellCommand::Search {
+                source: None,
+                query: "민법".to_string(),
+            }
+        );
+        assert!(ShellCommand::parse("law").is_err());
+        assert!(ShellCommand::parse("lawz 민법").is_err());
+        assert_eq!(ShellCommand::parse("").unwrap(), ShellCommand::Empty);
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        let target = |line: &str| match ShellCommand::parse(line).unwrap() {
+            ShellCommand::Detail(target) | ShellCommand::History(target) => target,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(target("detail 3"), Target::Result(3));
+        assert_eq!(target("history #12"), Target::Result(12));
+        // Zero-padded and long numbers are IDs
+        assert_eq!(
+            target("detail 001706"),
+            Target::Id {
+                source: None,
+                id: "001706".to_string()
+            }
+        );
+        assert_eq!(
+            target("detail 228541"),
+            Target::Id {
+                source: None,
+                id: "228541".to_string()
+            }
+        );
+        assert_eq!(
+            target("d prec 228541"),
+            Target::Id {
+                source: Some(ApiType::Prec),
+                id: "228541".to_string()
+            }
+        );
+        assert!(ShellCommand::parse("detail").is_err());
+        assert!(ShellCommand::parse("detail all 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        assert_eq!(
+            ShellCommand::parse("format JSON").unwrap(),
+            ShellCommand::Format(OutputFormat::Json)
+        );
+        assert_eq!(
+            ShellCommand::parse("size 20").unwrap(),
+            ShellCommand::Size(20)
+        );
+        assert!(ShellCommand::parse("size 0").is_err());
+        assert!(ShellCommand::parse("format pdf").is_err());
+    }
+}
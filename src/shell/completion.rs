@@ -0,0 +1,143 @@
+use clap::ValueEnum;
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+use super::command::COMMANDS;
+use crate::cli::OutputFormat;
+
+/// Commands whose argument is a search query
+const SEARCH_COMMANDS: &[&str] = &[
+    "search",
+    "s",
+    "law",
+    "l",
+    "ordinance",
+    "o",
+    "precedent",
+    "p",
+    "admrule",
+    "a",
+    "interpretation",
+    "i",
+];
+
+/// Law names seen in the session, shared between the shell and the completer
+pub type LawNames = Arc<Mutex<BTreeSet<String>>>;
+
+/// Tab completion of command names, law names and output formats
+pub struct ShellHelper {
+    names: LawNames,
+}
+
+impl ShellHelper {
+    pub fn new(names: LawNames) -> Self {
+        Self { names }
+    }
+}
+
+/// Start of the word being completed and the candidates for it
+pub fn complete_line(line: &str, names: &BTreeSet<String>) -> (usize, Vec<String>) {
+    let Some((command, _)) = line.split_once(char::is_whitespace) else {
+        let commands = COMMANDS
+            .iter()
+            .map(|(name, _, _)| *name)
+            .filter(|name| name.starts_with(line))
+            .map(str::to_string)
+            .collect();
+        return (0, commands);
+    };
+
+    // Law names may contain spaces, so the whole argument is completed
+    let start = line.len() - line[command.len()..].trim_start().len();
+    let arg = &line[start..];
+    let candidates = match command {
+        "format" | "f" => OutputFormat::value_variants()
+            .iter()
+            .filter_map(|format| format.to_possible_value())
+            .map(|value| value.get_name().to_string())
+            .filter(|name| name.starts_with(arg))
+            .collect(),
+        command if SEARCH_COMMANDS.contains(&command) => names
+            .iter()
+            .filter(|name| name.starts_with(arg))
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    };
+    (start, candidates)
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let names = self.names.lock().unwrap_or_else(|e| e.into_inner());
+        let (start, candidates) = complete_line(&line[..pos], &names);
+        let pairs = candidates
+            .into_iter()
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect();
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names() -> BTreeSet<String> {
+        ["민법", "민사소송법", "개인정보 보호법"]
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_complete_commands() {
+        assert_eq!(
+            complete_line("h", &names()),
+            (0, vec!["history".to_string(), "help".to_string()])
+        );
+        assert_eq!(complete_line("", &names()).1.len(), COMMANDS.len());
+    }
+
+    #[test]
+    fn test_complete_law_names_and_formats() {
+        assert_eq!(
+            complete_line("law 민", &names()),
+            (4, vec!["민법".to_string(), "민사소송법".to_string()])
+        );
+        assert_eq!(
+            complete_line("search  개인정보 ", &names()),
+            (8, vec!["개인정보 보호법".to_string()])
+        );
+        assert_eq!(
+            complete_line("format ya", &names()),
+            (7, vec!["yaml".to_string()])
+        );
+        assert!(complete_line("detail 3", &names()).1.is_empty());
+    }
+}
@@ -0,0 +1,210 @@
+//! Interactive shell running lookups against long-lived clients
+
+pub mod command;
+pub mod completion;
+
+use chrono::Utc;
+use clap::ValueEnum;
+use colored::*;
+use log::warn;
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::Editor;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::api::types::{SearchItem, SearchResponse};
+use crate::api::ApiType;
+use crate::cli::session::Session;
+use crate::error::{Result, WarpError};
+use crate::output;
+use crate::output::Formatter;
+use command::{ShellCommand, Target, COMMANDS};
+use completion::{LawNames, ShellHelper};
+
+/// Lines kept in the history file
+const HISTORY_SIZE: usize = 1000;
+
+/// State of a `warp shell` session
+///
+/// The clients, and with them the cache store and HTTP connections, are
+/// created once and reused by every command.
+pub struct Shell {
+    session: Session,
+    formatter: Formatter,
+    /// Results of the last search; `detail 3` refers to the third
+    results: Vec<SearchItem>,
+    results_source: String,
+    names: LawNames,
+}
+
+impl Shell {
+    pub fn new(
+        session: Session,
+        formatter: Formatter,
+        names: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            session,
+            formatter,
+            results: Vec::new(),
+            results_source: String::new(),
+            names: Arc::new(Mutex::new(names.into_iter().collect())),
+        }
+    }
+
+    /// Run one command
+    pub async fn execute(&mut self, command: ShellCommand) -> Result<()> {
+        match command {
+            ShellCommand::Search { source, query } => {
+                self.results = match source {
+                    Some(api_type) => self.session.search_source(api_type, &query).await?,
+                    None => self.session.search(&query).await?,
+                };
+                self.results_source = source.unwrap_or(ApiType::All).display_name().to_string();
+                self.remember(self.results.iter().map(|item| item.title.clone()));
+                self.print_results()
+            }
+            ShellCommand::Detail(target) => {
+                let (api_type, id) = self.resolve(target)?;
+                let detail = self.session.client(api_type)?.get_detail(&id).await?;
+                self.remember([detail.law_name.clone()]);
+                output::print_law_detail(&detail, api_type, &self.formatter)
+            }
+            ShellCommand::History(target) => {
+                let (api_type, id) = self.resolve(target)?;
+                let history = self.session.client(api_type)?.get_history(&id).await?;
+                self.remember([history.law_name.clone()]);
+                output::print_law_history(&history, &self.formatter)
+            }
+            ShellCommand::Results => self.print_results(),
+            ShellCommand::Format(format) => {
+                self.formatter = self.formatter.clone().with_format(format);
+                if let Some(value) = format.to_possible_value() {
+                    println!("출력 형식: {}", value.get_name());
+                }
+                Ok(())
+            }
+            ShellCommand::Size(size) => {
+                self.session.set_page_size(size);
+                println!("소스별 결과 수: {}", self.session.page_size());
+                Ok(())
+            }
+            ShellCommand::Help => {
+                print_help();
+                Ok(())
+            }
+            ShellCommand::Exit | ShellCommand::Empty => Ok(()),
+        }
+    }
+
+    /// Source and ID a `detail`/`history` argument refers to
+    fn resolve(&self, target: Target) -> Result<(ApiType, String)> {
+        match target {
+            Target::Result(n) => {
+                let item = self.results.get(n - 1).ok_or_else(|| {
+                    WarpError::InvalidInput(format!(
+                        "마지막 검색 결과에 {}번이 없습니다 ({}건)",
+                        n,
+                        self.results.len()
+                    ))
+                })?;
+                Ok((self.session.source_of(item)?, item.id.clone()))
+            }
+            Target::Id { source, id } => Ok((source.unwrap_or(ApiType::Nlic), id)),
+        }
+    }
+
+    fn print_results(&self) -> Result<()> {
+        if self.results.is_empty() {
+            println!("No results found for your search query.");
+            return Ok(());
+        }
+        let count = self.results.len() as u32;
+        let response = SearchResponse {
+            total_count: count,
+            page_no: 1,
+            page_size: count,
+            items: self.results.clone(),
+            source: self.results_source.clone(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+        output::print_search_response(&response, &self.formatter)
+    }
+
+    /// Offer these law names for completion
+    fn remember(&self, names: impl IntoIterator<Item = String>) {
+        let mut known = self.names.lock().unwrap_or_else(|e| e.into_inner());
+        known.extend(names.into_iter().filter(|name| !name.is_empty()));
+    }
+}
+
+/// Read and run commands until `exit` or Ctrl-D
+///
+/// Line history is loaded from and saved to `history`.
+pub async fn run(mut shell: Shell, history: &Path) -> Result<()> {
+    let config = rustyline::Config::builder()
+        .auto_add_history(true)
+        .max_history_size(HISTORY_SIZE)?
+        .build();
+    let mut editor: Editor<ShellHelper, DefaultHistory> = Editor::with_config(config)?;
+    editor.set_helper(Some(ShellHelper::new(shell.names.clone())));
+    if let Some(dir) = history.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    // There is no history file before the first session
+    let _ = editor.load_history(history);
+
+    println!(
+        "{} {}",
+        "warp shell".bold().cyan(),
+        "— help: 명령 목록, Tab: 자동완성, Ctrl-D: 종료".dimmed()
+    );
+
+    loop {
+        // Reading blocks; let other tasks (e.g. cache refreshes) run meanwhile
+        let line = tokio::task::block_in_place(|| editor.readline("warp> "));
+        let line = match line {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        // Saved after every line so an interrupted session keeps its history
+        if let Err(e) = editor.save_history(history) {
+            warn!("Failed to save shell history: {}", e);
+        }
+
+        let result = match ShellCommand::parse(&line) {
+            Ok(ShellCommand::Exit) => break,
+            Ok(command) => shell.execute(command).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            if let Some(hint) = e.hint() {
+                eprintln!("{}", hint);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_help() {
+    println!("{}", "명령 (Commands)".bold().cyan());
+    for (name, args, description) in COMMANDS {
+        println!(
+            "  {}{:<18}{}",
+            format!("{:<16}", name).bold(),
+            args,
+            description.dimmed()
+        );
+    }
+    println!(
+        "\n{}",
+        "검색 명령은 첫 글자만 써도 됩니다 (s, l, o, p, a, i, d, h)".dimmed()
+    );
+}
@@ -0,0 +1,354 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::api::types::{LawDetail, LawHistory, SearchItem};
+
+/// Lines moved by PageUp/PageDown
+const PAGE: usize = 10;
+
+/// What the event loop has to fetch for the app
+#[derive(Debug, Clone)]
+pub enum Action {
+    Search(String),
+    OpenDetail(SearchItem),
+    OpenHistory(SearchItem),
+}
+
+/// Text being typed in the footer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    /// `/`: narrow the result list locally
+    Filter(String),
+    /// `s`: run a new search
+    Query(String),
+}
+
+/// A screen opened on top of the result list
+#[derive(Debug)]
+pub enum Screen {
+    Detail(Box<DetailView>),
+    History(Box<HistoryView>),
+}
+
+#[derive(Debug)]
+pub struct DetailView {
+    pub item: SearchItem,
+    pub detail: LawDetail,
+    pub scroll: usize,
+    /// Number of rendered lines, to stop scrolling past the end
+    pub lines: usize,
+}
+
+#[derive(Debug)]
+pub struct HistoryView {
+    pub item: SearchItem,
+    pub history: LawHistory,
+    pub selected: usize,
+}
+
+/// State of the interactive browser
+///
+/// The app only reacts to keys; fetching is left to the event loop, which
+/// receives an [`Action`] and hands the result back through `show_*`.
+#[derive(Debug, Default)]
+pub struct App {
+    pub query: String,
+    pub items: Vec<SearchItem>,
+    pub filter: String,
+    pub input: Option<Input>,
+    /// Position in the filtered list
+    pub selected: usize,
+    /// Screens above the result list; `b` pops the last one
+    pub stack: Vec<Screen>,
+    pub status: Option<String>,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indices of the items matching the local filter
+    pub fn visible(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| filter.is_empty() || matches_filter(item, &filter))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn selected_item(&self) -> Option<&SearchItem> {
+        self.visible()
+            .get(self.selected)
+            .and_then(|&i| self.items.get(i))
+    }
+
+    pub fn show_results(&mut self, query: String, items: Vec<SearchItem>) {
+        self.status = Some(format!("'{}': {}건", query, items.len()));
+        self.query = query;
+        self.items = items;
+        self.filter.clear();
+        self.selected = 0;
+        self.stack.clear();
+    }
+
+    pub fn show_detail(&mut self, item: SearchItem, detail: LawDetail, lines: usize) {
+        self.status = None;
+        self.stack.push(Screen::Detail(Box::new(DetailView {
+            item,
+            detail,
+            scroll: 0,
+            lines,
+        })));
+    }
+
+    pub fn show_history(&mut self, item: SearchItem, history: LawHistory) {
+        self.status = if history.entries.is_empty() {
+            Some("개정 연혁이 없습니다".to_string())
+        } else {
+            None
+        };
+        self.stack.push(Screen::History(Box::new(HistoryView {
+            item,
+            history,
+            selected: 0,
+        })));
+    }
+
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = Some(status.into());
+    }
+
+    /// React to a key press; returns what has to be fetched, if anything
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return None;
+        }
+        if self.input.is_some() {
+            return self.handle_input(key);
+        }
+        match self.stack.last_mut() {
+            Some(Screen::Detail(view)) => match key.code {
+                KeyCode::Char('h') => return Some(Action::OpenHistory(view.item.clone())),
+                code => {
+                    let last = view.lines.saturating_sub(1);
+                    view.scroll = step(code, view.scroll, last).unwrap_or(view.scroll);
+                }
+            },
+            Some(Screen::History(view)) => {
+                let last = view.history.entries.len().saturating_sub(1);
+                view.selected = step(key.code, view.selected, last).unwrap_or(view.selected);
+            }
+            None => return self.handle_results_key(key.code),
+        }
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc | KeyCode::Backspace => {
+                self.stack.pop();
+                self.status = None;
+            }
+            KeyCode::Char('q') => self.quit = true,
+            _ => {}
+        }
+        None
+    }
+
+    fn handle_results_key(&mut self, code: KeyCode) -> Option<Action> {
+        match code {
+            KeyCode::Enter => return self.selected_item().cloned().map(Action::OpenDetail),
+            KeyCode::Char('h') => return self.selected_item().cloned().map(Action::OpenHistory),
+            KeyCode::Char('/') => self.input = Some(Input::Filter(self.filter.clone())),
+            KeyCode::Char('s') => self.input = Some(Input::Query(String::new())),
+            KeyCode::Char('b') | KeyCode::Esc if !self.filter.is_empty() => {
+                self.filter.clear();
+                self.selected = 0;
+            }
+            KeyCode::Char('q') => self.quit = true,
+            code => {
+                let last = self.visible().len().saturating_sub(1);
+                self.selected = step(code, self.selected, last).unwrap_or(self.selected);
+            }
+        }
+        None
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) -> Option<Action> {
+        let input = self.input.as_mut()?;
+        let text = match input {
+            Input::Filter(text) | Input::Query(text) => text,
+        };
+        match key.code {
+            KeyCode::Char(c) => text.push(c),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Esc => {
+                if matches!(input, Input::Filter(_)) {
+                    self.filter.clear();
+                    self.selected = 0;
+                }
+                self.input = None;
+                return None;
+            }
+            KeyCode::Enter => {
+                return match self.input.take() {
+                    Some(Input::Query(query)) if !query.trim().is_empty() => {
+                        Some(Action::Search(query.trim().to_string()))
+                    }
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+        // The list narrows while the filter is typed
+        if let Some(Input::Filter(text)) = &self.input {
+            self.filter = text.clone();
+            self.selected = 0;
+        }
+        None
+    }
+}
+
+/// New position after a movement key, within `0..=last`
+fn step(code: KeyCode, current: usize, last: usize) -> Option<usize> {
+    let next = match code {
+        KeyCode::Down | KeyCode::Char('j') => current + 1,
+        KeyCode::Up | KeyCode::Char('k') => current.saturating_sub(1),
+        KeyCode::PageDown | KeyCode::Char(' ') => current + PAGE,
+        KeyCode::PageUp => current.saturating_sub(PAGE),
+        KeyCode::Home | KeyCode::Char('g') => 0,
+        KeyCode::End | KeyCode::Char('G') => last,
+        _ => return None,
+    };
+    Some(next.min(last))
+}
+
+fn matches_filter(item: &SearchItem, filter: &str) -> bool {
+    [
+        Some(item.title.as_str()),
+        item.law_type.as_deref(),
+        item.department.as_deref(),
+        Some(item.source.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|field| field.to_lowercase().contains(filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn item(id: &str, title: &str) -> SearchItem {
+        SearchItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            law_no: None,
+            law_type: Some("법률".to_string()),
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: "국가법령정보센터".to_string(),
+            metadata: HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        }
+    }
+
+    fn detail(name: &str) -> LawDetail {
+        LawDetail {
+            law_id: "1".to_string(),
+            law_name: name.to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles: Vec::new(),
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        }
+    }
+
+    fn press(app: &mut App, code: KeyCode) -> Option<Action> {
+        app.handle_key(KeyEvent::from(code))
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.show_results(
+            "법".to_string(),
+            vec![
+                item("1", "민법"),
+                item("2", "형법"),
+                item("3", "민사소송법"),
+            ],
+        );
+        app
+    }
+
+    #[test]
+    fn test_filter_narrows_list_while_typing() {
+        let mut app = app();
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('/'));
+        press(&mut app, KeyCode::Char('민'));
+        assert_eq!(app.visible(), vec![0, 2]);
+        assert_eq!(app.selected, 0);
+
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.selected_item().unwrap().title, "민사소송법");
+
+        // `b` on the list drops the filter first
+        press(&mut app, KeyCode::Char('b'));
+        assert_eq!(app.visible().len(), 3);
+        assert!(!app.quit);
+    }
+
+    #[test]
+    fn test_enter_opens_detail_and_b_goes_back() {
+        let mut app = app();
+        press(&mut app, KeyCode::Down);
+        let action = press(&mut app, KeyCode::Enter);
+        assert!(matches!(action, Some(Action::OpenDetail(item)) if item.id == "2"));
+
+        app.show_detail(item("2", "형법"), detail("형법"), 5);
+        press(&mut app, KeyCode::PageDown);
+        match app.stack.last() {
+            Some(Screen::Detail(view)) => assert_eq!(view.scroll, 4),
+            other => panic!("expected detail, got {:?}", other),
+        }
+        let action = press(&mut app, KeyCode::Char('h'));
+        assert!(matches!(action, Some(Action::OpenHistory(item)) if item.id == "2"));
+
+        press(&mut app, KeyCode::Char('b'));
+        assert!(app.stack.is_empty());
+        assert_eq!(app.selected, 1);
+    }
+
+    #[test]
+    fn test_new_search_from_query_input() {
+        let mut app = app();
+        press(&mut app, KeyCode::Char('s'));
+        for c in "상법 ".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        // Typed keys go to the input, not to the list
+        assert!(!app.quit);
+        let action = press(&mut app, KeyCode::Enter);
+        assert!(matches!(action, Some(Action::Search(query)) if query == "상법"));
+        assert!(app.input.is_none());
+
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+}
@@ -0,0 +1,76 @@
+//! Full-screen browser for search results, details and history
+
+pub mod app;
+mod ui;
+
+use crossterm::event::{self, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+use std::io::IsTerminal;
+use std::time::Duration;
+
+use crate::cli::session::Session;
+use crate::error::{Result, WarpError};
+use app::{Action, App};
+
+/// Run the browser until the user quits, starting with `query` if given
+pub async fn run(session: Session, query: Option<String>) -> Result<()> {
+    if !std::io::stdout().is_terminal() {
+        return Err(WarpError::InvalidInput(
+            "대화형 화면은 터미널에서만 사용할 수 있습니다".to_string(),
+        ));
+    }
+
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut terminal, &session, query).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    session: &Session,
+    query: Option<String>,
+) -> Result<()> {
+    let mut app = App::new();
+    let mut pending = query.map(Action::Search);
+
+    while !app.quit {
+        if let Some(action) = pending.take() {
+            app.set_status("불러오는 중…");
+            terminal.draw(|frame| ui::draw(frame, &app))?;
+            perform(&mut app, session, action).await;
+        }
+
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+
+        if event::poll(Duration::from_millis(250))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    pending = app.handle_key(key);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fetch what an action asks for; failures end up in the status line
+async fn perform(app: &mut App, session: &Session, action: Action) {
+    let result = match action {
+        Action::Search(query) => session
+            .search(&query)
+            .await
+            .map(|items| app.show_results(query, items)),
+        Action::OpenDetail(item) => session.detail(&item).await.map(|detail| {
+            let lines = ui::detail_lines(&detail).len();
+            app.show_detail(item, detail, lines)
+        }),
+        Action::OpenHistory(item) => session
+            .history(&item)
+            .await
+            .map(|history| app.show_history(item, history)),
+    };
+    if let Err(e) = result {
+        app.set_status(e.to_string());
+    }
+}
@@ -0,0 +1,236 @@
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Block, Borders, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap,
+};
+use ratatui::Frame;
+
+use super::app::{App, DetailView, HistoryView, Input, Screen};
+use crate::api::types::LawDetail;
+use crate::output::formatter::STALE_NOTICE;
+use crate::output::report::division_heading;
+
+const HIGHLIGHT: Style = Style::new().fg(Color::Black).bg(Color::Cyan);
+
+/// Draw the current screen
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [body, footer] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+
+    match app.stack.last() {
+        Some(Screen::Detail(view)) => draw_detail(frame, body, view),
+        Some(Screen::History(view)) => draw_history(frame, body, view),
+        None => draw_results(frame, body, app),
+    }
+    draw_footer(frame, footer, app);
+}
+
+/// Text of a law detail, one entry per rendered line before wrapping
+pub fn detail_lines(detail: &LawDetail) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    if detail.stale {
+        lines.push(Line::from(STALE_NOTICE).yellow());
+    }
+    for (label, value) in [
+        ("법령번호", &detail.law_no),
+        ("법령종류", &detail.law_type),
+        ("소관부처", &detail.department),
+        ("시행일자", &detail.enforcement_date),
+        ("개정일자", &detail.revision_date),
+    ] {
+        if let Some(value) = value {
+            lines.push(Line::from(vec![
+                Span::from(format!("{}: ", label)).dark_gray(),
+                Span::from(value.clone()),
+            ]));
+        }
+    }
+
+    for article in &detail.articles {
+        lines.push(Line::default());
+        if let Some((_, text)) = division_heading(article) {
+            lines.push(Line::from(text.to_string()).magenta().bold());
+            continue;
+        }
+        let mut heading = vec![Span::from(article.number.clone()).cyan().bold()];
+        if let Some(title) = &article.title {
+            heading.push(Span::from(format!(" ({})", title)).bold());
+        }
+        lines.push(Line::from(heading));
+        lines.extend(article.content.lines().map(|l| Line::from(l.to_string())));
+    }
+
+    if detail.articles.is_empty() && !detail.content.is_empty() {
+        lines.push(Line::default());
+        lines.extend(detail.content.lines().map(|l| Line::from(l.to_string())));
+    }
+    lines
+}
+
+fn draw_results(frame: &mut Frame, area: Rect, app: &App) {
+    let visible = app.visible();
+    let items: Vec<ListItem> = visible
+        .iter()
+        .map(|&i| {
+            let item = &app.items[i];
+            let mut spans = vec![
+                Span::from(format!("[{}] ", item.source)).dark_gray(),
+                Span::from(item.title.clone()).bold(),
+            ];
+            for extra in [&item.law_type, &item.enforcement_date]
+                .into_iter()
+                .flatten()
+            {
+                spans.push(Span::from(format!("  {}", extra)).dark_gray());
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let mut title = if app.query.is_empty() {
+        " 검색: s 키로 검색어 입력 ".to_string()
+    } else {
+        format!(
+            " 검색: {} ({}/{}) ",
+            app.query,
+            visible.len(),
+            app.items.len()
+        )
+    };
+    if !app.filter.is_empty() {
+        title.push_str(&format!("[필터: {}] ", app.filter));
+    }
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(HIGHLIGHT);
+    let mut state = ListState::default().with_selected(Some(app.selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_detail(frame: &mut Frame, area: Rect, view: &DetailView) {
+    let title = format!(
+        " {} ({}/{}) ",
+        view.detail.law_name,
+        (view.scroll + 1).min(view.lines),
+        view.lines
+    );
+    let paragraph = Paragraph::new(detail_lines(&view.detail))
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .wrap(Wrap { trim: false })
+        .scroll((view.scroll.min(u16::MAX as usize) as u16, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_history(frame: &mut Frame, area: Rect, view: &HistoryView) {
+    let rows = view.history.entries.iter().map(|entry| {
+        Row::new(vec![
+            entry.revision_no.to_string(),
+            entry.revision_date.clone(),
+            entry
+                .enforcement_date
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+            entry.revision_type.clone(),
+            entry.reason.clone().unwrap_or_else(|| "-".to_string()),
+        ])
+    });
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Fill(1),
+    ];
+    let header = Row::new(["순번", "개정일자", "시행일자", "개정구분", "개정이유"])
+        .style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+    let name = if view.history.law_name.is_empty() {
+        &view.item.title
+    } else {
+        &view.history.law_name
+    };
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} 개정 연혁 ", name)),
+        )
+        .row_highlight_style(HIGHLIGHT);
+    let mut state = TableState::default().with_selected(Some(view.selected));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
+    let line = match (&app.input, &app.status) {
+        (Some(Input::Filter(text)), _) => Line::from(format!("/{}", text)),
+        (Some(Input::Query(text)), _) => Line::from(format!("검색어: {}", text)),
+        (None, Some(status)) => Line::from(status.clone()).yellow(),
+        (None, None) => Line::from(match app.stack.last() {
+            Some(Screen::Detail(_)) => "↑↓ 스크롤  h 연혁  b 뒤로  q 종료",
+            Some(Screen::History(_)) => "↑↓ 이동  b 뒤로  q 종료",
+            None => "↑↓ 이동  Enter 상세  h 연혁  / 필터  s 검색  q 종료",
+        })
+        .dark_gray(),
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::Article;
+    use std::collections::HashMap;
+
+    fn article(number: &str, title: Option<&str>, content: &str) -> Article {
+        Article {
+            number: number.to_string(),
+            title: title.map(str::to_string),
+            content: content.to_string(),
+            paragraphs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_detail_lines_mark_divisions_and_articles() {
+        let detail = LawDetail {
+            law_id: "1".to_string(),
+            law_name: "민법".to_string(),
+            law_no: Some("제1호".to_string()),
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles: vec![
+                article("", None, "제1장 통칙"),
+                article("제1조", Some("법원"), "민사에 관하여는\n관습법에 의한다."),
+            ],
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        };
+
+        let text: Vec<String> = detail_lines(&detail)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            text,
+            vec![
+                "법령번호: 제1호",
+                "",
+                "제1장 통칙",
+                "",
+                "제1조 (법원)",
+                "민사에 관하여는",
+                "관습법에 의한다.",
+            ]
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Watch list of laws whose revisions are checked for changes
+//!
+//! [`WatchList`] keeps the last revision seen of each watched law in a small
+//! SQLite database in the configuration directory; unlike the cache it is not
+//! meant to be cleared.
+
+use crate::api::types::{LawDetail, LawHistory};
+use crate::api::ApiType;
+use crate::error::{Result, WarpError};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// File name of the watch list database, stored in the configuration directory
+pub const WATCH_DB_FILE: &str = "watch.db";
+
+/// Latest revision of a law
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Revision {
+    /// Promulgation date of the latest revision (YYYYMMDD)
+    pub date: String,
+    /// Kind of revision, e.g. 일부개정
+    pub kind: Option<String>,
+    /// Number of revisions in the history, when it was available
+    pub count: Option<u32>,
+}
+
+impl Revision {
+    /// Latest entry of a history, if it has any
+    pub fn from_history(history: &LawHistory) -> Option<Self> {
+        let latest = history
+            .entries
+            .iter()
+            .filter(|entry| !entry.revision_date.is_empty())
+            .max_by(|a, b| {
+                (&a.revision_date, &a.enforcement_date)
+                    .cmp(&(&b.revision_date, &b.enforcement_date))
+            })?;
+        Some(Self {
+            date: latest.revision_date.clone(),
+            kind: Some(latest.revision_type.clone()).filter(|k| !k.is_empty()),
+            count: Some(history.entries.len() as u32),
+        })
+    }
+
+    /// Revision date of a detail, for sources without a history
+    pub fn from_detail(detail: &LawDetail) -> Option<Self> {
+        let date = detail
+            .revision_date
+            .clone()
+            .or_else(|| detail.enforcement_date.clone())
+            .filter(|d| !d.is_empty())?;
+        Some(Self {
+            date,
+            kind: None,
+            count: None,
+        })
+    }
+
+    /// Whether this revision is newer than `previous`
+    pub fn is_newer_than(&self, previous: &Revision) -> bool {
+        let digits = |date: &str| {
+            date.chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>()
+        };
+        match digits(&self.date).cmp(&digits(&previous.date)) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            // Several revisions can be promulgated on the same day
+            std::cmp::Ordering::Equal => match (self.count, previous.count) {
+                (Some(now), Some(before)) => now > before,
+                _ => false,
+            },
+        }
+    }
+}
+
+/// A watched law
+#[derive(Debug, Clone, Serialize)]
+pub struct Watch {
+    pub api_type: String,
+    pub law_id: String,
+    pub law_name: String,
+    /// Revision seen at the last check
+    pub revision: Revision,
+    pub added_at: DateTime<Utc>,
+    pub checked_at: Option<DateTime<Utc>>,
+    /// When a check last found a new revision
+    pub changed_at: Option<DateTime<Utc>>,
+}
+
+/// A watched law that was revised since the last check
+#[derive(Debug, Clone, Serialize)]
+pub struct Change {
+    pub api_type: String,
+    pub law_id: String,
+    pub law_name: String,
+    pub previous: Revision,
+    pub current: Revision,
+}
+
+/// SQLite table of watched laws
+#[derive(Debug, Clone)]
+pub struct WatchList {
+    db_path: PathBuf,
+}
+
+impl WatchList {
+    /// Open the watch list database, creating the schema if needed
+    pub async fn open<P: AsRef<Path>>(db_path: P) -> Result<Self> {
+        let db_path = db_path.as_ref().to_path_buf();
+        let path = db_path.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let conn = Self::connect(&path)?;
+            conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS watches (
+                    api_type TEXT NOT NULL,
+                    law_id TEXT NOT NULL,
+                    law_name TEXT NOT NULL,
+                    revision_date TEXT NOT NULL,
+                    revision_kind TEXT,
+                    revision_count INTEGER,
+                    added_at TEXT NOT NULL,
+                    checked_at TEXT,
+                    changed_at TEXT,
+                    PRIMARY KEY (api_type, law_id)
+                );
+                "#,
+            )
+            .map_err(|e| WarpError::Other(format!("Failed to create watch list schema: {}", e)))
+        })
+        .await
+        .map_err(|e| {
+            WarpError::Other(format!("Failed to spawn watch list initialization: {}", e))
+        })??;
+
+        Ok(Self { db_path })
+    }
+
+    fn connect(path: &Path) -> Result<Connection> {
+        Connection::open(path)
+            .map_err(|e| WarpError::Other(format!("Failed to open watch list database: {}", e)))
+    }
+
+    /// Run `f` on a connection off the async runtime
+    async fn with_conn<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let db_path = self.db_path.clone();
+        tokio::task::spawn_blocking(move || -> Result<T> {
+            let conn = Self::connect(&db_path)?;
+            f(&conn).map_err(|e| WarpError::Other(format!("Watch list query failed: {}", e)))
+        })
+        .await
+        .map_err(|e| WarpError::Other(format!("Failed to spawn watch list operation: {}", e)))?
+    }
+
+    /// Watch a law from its current revision on; returns false if it was already watched
+    pub async fn add(
+        &self,
+        api_type: ApiType,
+        law_id: &str,
+        law_name: &str,
+        revision: &Revision,
+    ) -> Result<bool> {
+        let (law_id, law_name, revision) =
+            (law_id.to_string(), law_name.to_string(), revision.clone());
+        self.with_conn(move |conn| {
+            let inserted = conn.execute(
+                r#"
+                INSERT OR IGNORE INTO watches
+                (api_type, law_id, law_name, revision_date, revision_kind, revision_count, added_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                "#,
+                params![
+                    api_type.as_str(),
+                    law_id,
+                    law_name,
+                    revision.date,
+                    revision.kind,
+                    revision.count,
+                    Utc::now().to_rfc3339(),
+                ],
+            )?;
+            Ok(inserted > 0)
+        })
+        .await
+    }
+
+    /// Stop watching laws by ID or exact name; returns how many were removed
+    pub async fn remove(&self, law: &str) -> Result<usize> {
+        let law = law.to_string();
+        self.with_conn(move |conn| {
+            conn.execute(
+                "DELETE FROM watches WHERE law_id = ?1 OR law_name = ?1",
+                params![law],
+            )
+        })
+        .await
+    }
+
+    /// Watched laws, by name
+    pub async fn list(&self) -> Result<Vec<Watch>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                r#"
+                SELECT api_type, law_id, law_name, revision_date, revision_kind, revision_count,
+                       added_at, checked_at, changed_at
+                FROM watches ORDER BY law_name, api_type, law_id
+                "#,
+            )?;
+            let watches = stmt.query_map([], watch_from_row)?.collect();
+            watches
+        })
+        .await
+    }
+
+    /// Look up a watched law
+    pub async fn get(&self, api_type: ApiType, law_id: &str) -> Result<Option<Watch>> {
+        let law_id = law_id.to_string();
+        self.with_conn(move |conn| {
+            conn.query_row(
+                r#"
+                SELECT api_type, law_id, law_name, revision_date, revision_kind, revision_count,
+                       added_at, checked_at, changed_at
+                FROM watches WHERE api_type = ?1 AND law_id = ?2
+                "#,
+                params![api_type.as_str(), law_id],
+                watch_from_row,
+            )
+            .optional()
+        })
+        .await
+    }
+
+    /// Record the revision found by a check, returning the change if it is newer
+    pub async fn record(&self, watch: &Watch, current: &Revision) -> Result<Option<Change>> {
+        let change = current.is_newer_than(&watch.revision).then(|| Change {
+            api_type: watch.api_type.clone(),
+            law_id: watch.law_id.clone(),
+            law_name: watch.law_name.clone(),
+            previous: watch.revision.clone(),
+            current: current.clone(),
+        });
+
+        let (api_type, law_id, current, changed) = (
+            watch.api_type.clone(),
+            watch.law_id.clone(),
+            current.clone(),
+            change.is_some(),
+        );
+        self.with_conn(move |conn| {
+            let now = Utc::now().to_rfc3339();
+            if changed {
+                conn.execute(
+                    r#"
+                    UPDATE watches
+                    SET revision_date = ?3, revision_kind = ?4, revision_count = ?5,
+                        checked_at = ?6, changed_at = ?6
+                    WHERE api_type = ?1 AND law_id = ?2
+                    "#,
+                    params![
+                        api_type,
+                        law_id,
+                        current.date,
+                        current.kind,
+                        current.count,
+                        now
+                    ],
+                )?;
+            } else {
+                conn.execute(
+                    "UPDATE watches SET checked_at = ?3 WHERE api_type = ?1 AND law_id = ?2",
+                    params![api_type, law_id, now],
+                )?;
+            }
+            Ok(())
+        })
+        .await?;
+
+        Ok(change)
+    }
+}
+
+fn watch_from_row(row: &Row<'_>) -> rusqlite::Result<Watch> {
+    let time = |value: Option<String>| {
+        value
+            .and_then(|v| DateTime::parse_from_rfc3339(&v).ok())
+            .map(|t| t.with_timezone(&Utc))
+    };
+    Ok(Watch {
+        api_type: row.get(0)?,
+        law_id: row.get(1)?,
+        law_name: row.get(2)?,
+        revision: Revision {
+            date: row.get(3)?,
+            kind: row.get(4)?,
+            count: row.get(5)?,
+        },
+        added_at: time(row.get(6)?).unwrap_or_else(Utc::now),
+        checked_at: time(row.get(7)?),
+        changed_at: time(row.get(8)?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::HistoryEntry;
+    use tempfile::TempDir;
+
+    fn revision(date: &str, count: u32) -> Revision {
+        Revision {
+            date: date.to_string(),
+            kind: Some("일부개정".to_string()),
+            count: Some(count),
+        }
+    }
+
+    #[test]
+    fn test_latest_revision_from_history() {
+        let entry = |date: &str, kind: &str| HistoryEntry {
+            revision_no: 0,
+            revision_date: date.to_string(),
+            enforcement_date: None,
+            revision_type: kind.to_string(),
+            reason: None,
+            changed_articles: vec![],
+            version_id: None,
+        };
+        let history = LawHistory {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            total_count: 3,
+            entries: vec![
+                entry("20221227", "일부개정"),
+                entry("20230526", "타법개정"),
+                entry("19580222", "제정"),
+            ],
+            stale: false,
+        };
+        let latest = Revision::from_history(&history).unwrap();
+        assert_eq!(latest.date, "20230526");
+        assert_eq!(latest.kind.as_deref(), Some("타법개정"));
+        assert_eq!(latest.count, Some(3));
+
+        assert!(revision("20230526", 3).is_newer_than(&revision("20221227", 2)));
+        assert!(revision("2023-05-26", 4).is_newer_than(&revision("20230526", 3)));
+        assert!(!revision("20230526", 3).is_newer_than(&revision("20230526", 3)));
+        assert!(!revision("20221227", 3).is_newer_than(&revision("20230526", 3)));
+    }
+
+    #[tokio::test]
+    async fn test_add_check_and_remove() {
+        let dir = TempDir::new().unwrap();
+        let list = WatchList::open(dir.path().join(WATCH_DB_FILE))
+            .await
+            .unwrap();
+
+        let old = revision("20221227", 2);
+        assert!(list
+            .add(ApiType::Nlic, "001706", "민법", &old)
+            .await
+            .unwrap());
+        assert!(!list
+            .add(ApiType::Nlic, "001706", "민법", &old)
+            .await
+            .unwrap());
+        let watch = list.get(ApiType::Nlic, "001706").await.unwrap().unwrap();
+        assert_eq!(watch.revision, old);
+        assert!(watch.checked_at.is_none());
+
+        // Unchanged: only the check time moves
+        assert!(list.record(&watch, &old).await.unwrap().is_none());
+        let watch = list.get(ApiType::Nlic, "001706").await.unwrap().unwrap();
+        assert!(watch.checked_at.is_some());
+        assert!(watch.changed_at.is_none());
+
+        let new = revision("20230526", 3);
+        let change = list.record(&watch, &new).await.unwrap().unwrap();
+        assert_eq!(change.previous, old);
+        assert_eq!(change.current, new);
+
+        // The next check starts from the new revision
+        let watches = list.list().await.unwrap();
+        assert_eq!(watches.len(), 1);
+        assert_eq!(watches[0].revision, new);
+        assert!(watches[0].changed_at.is_some());
+        assert!(list.record(&watches[0], &new).await.unwrap().is_none());
+
+        assert_eq!(list.remove("민법").await.unwrap(), 1);
+        assert!(list.list().await.unwrap().is_empty());
+    }
+}
@@ -26,6 +26,61 @@ fn test_unified_search_request_default() {
     assert!(request.query.is_empty());
 }
 
+#[test]
+fn test_drf_date_range() {
+    let mut request = UnifiedSearchRequest::default();
+    assert_eq!(request.drf_date_range(), None);
+
+    request.date_from = Some("20200101".to_string());
+    request.date_to = Some("20201231".to_string());
+    assert_eq!(
+        request.drf_date_range(),
+        Some("20200101~20201231".to_string())
+    );
+
+    // Open-ended ranges still restrict the server-side search
+    request.date_from = None;
+    assert_eq!(
+        request.drf_date_range(),
+        Some("19000101~20201231".to_string())
+    );
+}
+
+#[test]
+fn test_sort_order_drf_code() {
+    assert_eq!(SortOrder::Relevance.drf_code(), None);
+    assert_eq!(SortOrder::DateDesc.drf_code(), Some("ddes"));
+    assert_eq!(SortOrder::TitleAsc.drf_code(), Some("lasc"));
+}
+
+#[test]
+fn test_search_scope() {
+    assert_eq!(
+        UnifiedSearchRequest::default().search_scope,
+        SearchScope::Title
+    );
+    assert_eq!("body".parse::<SearchScope>(), Ok(SearchScope::Body));
+    assert_eq!("본문".parse::<SearchScope>(), Ok(SearchScope::Body));
+    assert!("article".parse::<SearchScope>().is_err());
+    assert!("all".parse::<SearchScope>().is_err());
+    assert_eq!(SearchScope::Title.drf_code(), "1");
+    assert_eq!(SearchScope::Body.drf_code(), "2");
+}
+
+#[test]
+fn test_status_filters() {
+    let mut request = UnifiedSearchRequest::default();
+    assert!(request.status_filters().is_empty());
+
+    request
+        .extras
+        .insert("status".to_string(), "시행중, 폐지,unknown".to_string());
+    let statuses = request.status_filters();
+    assert_eq!(statuses, vec![LawStatus::InForce, LawStatus::Repealed]);
+    assert_eq!(statuses[0].eflaw_nw_code(), Some("3"));
+    assert_eq!(statuses[1].revision_code(), Some("300204"));
+}
+
 #[test]
 fn test_config_path() {
     let path = Config::config_path();
@@ -125,9 +180,13 @@ mod formatter_tests {
                 summary: None,
                 source: "NLIC".to_string(),
                 metadata: HashMap::new(),
+                score: None,
+                sources: Vec::new(),
             }],
             source: "NLIC".to_string(),
             timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
         };
 
         let result = format_search_response(&response, OutputFormat::Json);
@@ -155,6 +214,8 @@ mod formatter_tests {
                     summary: None,
                     source: "NLIC".to_string(),
                     metadata: HashMap::new(),
+                    score: None,
+                    sources: Vec::new(),
                 },
                 SearchItem {
                     id: "124".to_string(),
@@ -167,10 +228,14 @@ mod formatter_tests {
                     summary: None,
                     source: "NLIC".to_string(),
                     metadata: HashMap::new(),
+                    score: None,
+                    sources: Vec::new(),
                 },
             ],
             source: "NLIC".to_string(),
             timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
         };
 
         let result = format_search_response(&response, OutputFormat::Table);
@@ -180,4 +245,591 @@ mod formatter_tests {
         assert!(table.contains("형법"));
         assert!(table.contains("Total: 2"));
     }
+
+    #[test]
+    fn test_format_search_jsonl() {
+        let item = |id: &str| SearchItem {
+            id: id.to_string(),
+            title: "민법".to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            summary: None,
+            source: "NLIC".to_string(),
+            metadata: HashMap::new(),
+            score: None,
+            sources: Vec::new(),
+        };
+        let response = SearchResponse {
+            total_count: 2,
+            page_no: 1,
+            page_size: 2,
+            items: vec![item("1"), item("2")],
+            source: "NLIC".to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+
+        let output = format_search_response(&response, OutputFormat::Jsonl).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: SearchItem = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first.id, "1");
+    }
+
+    #[test]
+    fn test_format_search_yaml_and_toml() {
+        let response = SearchResponse {
+            total_count: 1,
+            page_no: 1,
+            page_size: 10,
+            items: vec![SearchItem {
+                id: "001706".to_string(),
+                title: "민법".to_string(),
+                law_no: Some("265307".to_string()),
+                law_type: Some("법률".to_string()),
+                department: None,
+                enforcement_date: Some("20250131".to_string()),
+                revision_date: None,
+                summary: None,
+                source: "NLIC".to_string(),
+                metadata: HashMap::new(),
+                score: Some(1.0),
+                sources: Vec::new(),
+            }],
+            source: "NLIC".to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+
+        let yaml = format_search_response(&response, OutputFormat::Yaml).unwrap();
+        let parsed: SearchResponse = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.items[0].title, "민법");
+
+        let toml_out = format_search_response(&response, OutputFormat::Toml).unwrap();
+        let parsed: SearchResponse = toml::from_str(&toml_out).unwrap();
+        assert_eq!(parsed.total_count, 1);
+        assert_eq!(parsed.items[0].law_no.as_deref(), Some("265307"));
+        assert_eq!(parsed.items[0].department, None);
+    }
+
+    #[test]
+    fn test_xlsx_workbooks() {
+        use warp::output::xlsx;
+
+        let response = SearchResponse {
+            total_count: 1,
+            page_no: 1,
+            page_size: 10,
+            items: vec![SearchItem {
+                id: "228541".to_string(),
+                title: "손해배상(기)".to_string(),
+                law_no: Some("2019다12345".to_string()),
+                law_type: None,
+                department: None,
+                enforcement_date: None,
+                revision_date: None,
+                summary: None,
+                source: "PREC".to_string(),
+                metadata: HashMap::new(),
+                score: None,
+                sources: Vec::new(),
+            }],
+            source: "PREC".to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+        // XLSX files are ZIP archives
+        let bytes = xlsx::search_workbook(&response).unwrap();
+        assert!(bytes.starts_with(b"PK"));
+        assert!(format_search_response(&response, OutputFormat::Xlsx).is_err());
+
+        let detail = LawDetail {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            law_no: None,
+            law_type: Some("법률".to_string()),
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles: vec![Article {
+                number: "제1조".to_string(),
+                title: Some("법원".to_string()),
+                content: "민사에 관하여 법률에 규정이 없으면 관습법에 의하고".to_string(),
+                paragraphs: Vec::new(),
+            }],
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        };
+        let bytes = xlsx::detail_workbook(&detail, ApiType::Nlic).unwrap();
+        assert!(bytes.starts_with(b"PK"));
+
+        let history = LawHistory {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            total_count: 0,
+            entries: Vec::new(),
+            stale: false,
+        };
+        assert!(xlsx::history_workbook(&history).unwrap().starts_with(b"PK"));
+    }
+
+    /// Text of one file in an XLSX archive
+    fn xlsx_part(bytes: &[u8], name: &str) -> String {
+        use std::io::Read;
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        let mut text = String::new();
+        if let Ok(mut part) = archive.by_name(name) {
+            part.read_to_string(&mut text).unwrap();
+        }
+        text
+    }
+
+    #[test]
+    fn test_xlsx_links_unified_search_results() {
+        use warp::output::xlsx;
+
+        // Unified search tags items with the source's display name
+        let response = SearchResponse {
+            total_count: 1,
+            page_no: 1,
+            page_size: 10,
+            items: vec![SearchItem {
+                id: "228541".to_string(),
+                title: "손해배상(기)".to_string(),
+                law_no: None,
+                law_type: None,
+                department: None,
+                enforcement_date: None,
+                revision_date: None,
+                summary: None,
+                source: ApiType::Prec.display_name().to_string(),
+                metadata: HashMap::new(),
+                score: None,
+                sources: vec!["판례".to_string()],
+            }],
+            source: "Unified (2)".to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+        let bytes = xlsx::search_workbook(&response).unwrap();
+        let rels = xlsx_part(&bytes, "xl/worksheets/_rels/sheet1.xml.rels");
+        assert!(rels.contains("precSeq=228541"), "{}", rels);
+
+        assert_eq!(
+            ApiType::from_display_name("법령해석례"),
+            Some(ApiType::Expc)
+        );
+        assert_eq!(ApiType::from_display_name("통합검색"), None);
+    }
+
+    #[test]
+    fn test_xlsx_detail_without_articles_keeps_content() {
+        use warp::output::xlsx;
+
+        let detail = LawDetail {
+            law_id: "228541".to_string(),
+            law_name: "손해배상(기)".to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: "【판시사항】 불법행위로 인한 손해배상의 범위".to_string(),
+            articles: Vec::new(),
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        };
+        let bytes = xlsx::detail_workbook(&detail, ApiType::Prec).unwrap();
+        let strings = xlsx_part(&bytes, "xl/sharedStrings.xml");
+        assert!(
+            strings.contains("불법행위로 인한 손해배상의 범위"),
+            "{}",
+            strings
+        );
+        assert!(strings.contains("본문"));
+    }
+
+    #[test]
+    fn test_format_search_selected_fields() {
+        use warp::output::fields::FieldSelection;
+        use warp::output::Formatter;
+
+        let mut metadata = HashMap::new();
+        metadata.insert("detail_link".to_string(), "/DRF/lawService.do".to_string());
+        let response = SearchResponse {
+            total_count: 1,
+            page_no: 1,
+            page_size: 10,
+            items: vec![SearchItem {
+                id: "001706".to_string(),
+                title: "민법".to_string(),
+                law_no: Some("265307".to_string()),
+                law_type: Some("법률".to_string()),
+                department: None,
+                enforcement_date: None,
+                revision_date: None,
+                summary: None,
+                source: "NLIC".to_string(),
+                metadata,
+                score: None,
+                sources: Vec::new(),
+            }],
+            source: "NLIC".to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+        let fields = FieldSelection::new(["title", "metadata.detail_link", "id"]).unwrap();
+
+        let csv = Formatter::new(OutputFormat::Csv)
+            .with_fields(fields.clone())
+            .format_search(&response)
+            .unwrap();
+        let csv = csv.trim_start_matches('\u{feff}');
+        assert_eq!(
+            csv,
+            "title,metadata.detail_link,id\n민법,/DRF/lawService.do,001706\n"
+        );
+
+        let markdown = Formatter::new(OutputFormat::Markdown)
+            .with_fields(fields.clone())
+            .format_search(&response)
+            .unwrap();
+        assert!(markdown.starts_with("| title | metadata.detail_link | id |\n"));
+
+        let json = Formatter::new(OutputFormat::Json)
+            .with_fields(fields)
+            .format_search(&response)
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed["items"][0]["metadata.detail_link"],
+            "/DRF/lawService.do"
+        );
+        assert!(parsed["items"][0].get("law_no").is_none());
+
+        // TOML has no null; a field the item lacks is left out
+        let with_missing = FieldSelection::new(["title", "department"]).unwrap();
+        let toml = Formatter::new(OutputFormat::Toml)
+            .with_fields(with_missing)
+            .format_search(&response)
+            .unwrap();
+        let parsed: toml::Value = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed["items"][0]["title"].as_str(), Some("민법"));
+        assert!(parsed["items"][0].get("department").is_none());
+    }
+
+    #[test]
+    fn test_bundled_templates_render() {
+        use warp::output::template::Template;
+
+        let response = SearchResponse {
+            total_count: 11,
+            page_no: 2,
+            page_size: 10,
+            items: vec![SearchItem {
+                id: "001706".to_string(),
+                title: "민법".to_string(),
+                law_no: None,
+                law_type: Some("법률".to_string()),
+                department: None,
+                enforcement_date: Some("20250131".to_string()),
+                revision_date: None,
+                summary: None,
+                source: "NLIC".to_string(),
+                metadata: HashMap::new(),
+                score: None,
+                sources: Vec::new(),
+            }],
+            source: "NLIC".to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        };
+        let out = Template::load("search")
+            .unwrap()
+            .render("search", &response)
+            .unwrap();
+        assert!(out.contains("11. **민법** · 법률 · 시행 2025-01-31"));
+
+        let detail = LawDetail {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles: vec![Article {
+                number: "제1조".to_string(),
+                title: Some("법원".to_string()),
+                content: "민사에 관하여\n법률에 규정이 없으면".to_string(),
+                paragraphs: Vec::new(),
+            }],
+            attachments: Vec::new(),
+            related_laws: Vec::new(),
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        };
+        let out = Template::load("memo")
+            .unwrap()
+            .render("detail", &detail)
+            .unwrap();
+        assert!(out.contains("# 검토 메모: 민법"));
+        assert!(out.contains("| 법령번호 | - |"));
+        assert!(out.contains("### 제1조 (법원)\n\n> 민사에 관하여\n> 법률에"));
+
+        let history = LawHistory {
+            law_id: "001706".to_string(),
+            law_name: "민<법>".to_string(),
+            total_count: 1,
+            entries: vec![HistoryEntry {
+                revision_no: 1,
+                revision_date: "20240101".to_string(),
+                enforcement_date: None,
+                revision_type: "일부개정".to_string(),
+                reason: None,
+                changed_articles: Vec::new(),
+                version_id: None,
+            }],
+            stale: false,
+        };
+        let out = Template::load("history")
+            .unwrap()
+            .render("history", &history)
+            .unwrap();
+        assert!(out.contains("<h1>민&lt;법&gt; 개정 연혁</h1>"));
+        assert!(out.contains("<td>2024-01-01</td>"));
+    }
+}
+
+#[cfg(test)]
+mod offline_tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+    use warp::api::client::ClientConfig;
+    use warp::api::types::{LawDetail, LawHistory, UnifiedSearchRequest};
+    use warp::api::{ApiClientFactory, ApiType};
+    use warp::cache::{CacheConfig, CacheStore};
+    use warp::error::WarpError;
+
+    async fn offline_client(
+        dir: &TempDir,
+    ) -> (Arc<CacheStore>, Box<dyn warp::api::LegalApiClient>) {
+        let cache = Arc::new(
+            CacheStore::new(CacheConfig {
+                db_path: dir.path().join("cache.db"),
+                ..Default::default()
+            })
+            .await
+            .unwrap(),
+        );
+        let client = ApiClientFactory::create(
+            ApiType::Nlic,
+            ClientConfig {
+                cache: Some(cache.clone()),
+                bypass_cache: true,
+                offline: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        (cache, client)
+    }
+
+    #[tokio::test]
+    async fn test_offline_serves_expired_cache_entries() {
+        let dir = TempDir::new().unwrap();
+        let (cache, client) = offline_client(&dir).await;
+
+        let detail = LawDetail {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            law_no: None,
+            law_type: None,
+            department: None,
+            enforcement_date: None,
+            revision_date: None,
+            content: String::new(),
+            articles: vec![],
+            attachments: vec![],
+            related_laws: vec![],
+            metadata: HashMap::new(),
+            fetched_at: None,
+            stale: false,
+        };
+        cache
+            .put(
+                "nlic:detail:265307",
+                serde_json::to_vec(&detail).unwrap(),
+                ApiType::Nlic,
+                Some(chrono::Duration::seconds(-60)),
+            )
+            .await
+            .unwrap();
+
+        let cached = client.get_detail("265307").await.unwrap();
+        assert_eq!(cached.law_name, "민법");
+        assert!(cached.stale);
+    }
+
+    #[tokio::test]
+    async fn test_offline_history_is_flagged_stale() {
+        let dir = TempDir::new().unwrap();
+        let (cache, client) = offline_client(&dir).await;
+
+        let history = LawHistory {
+            law_id: "001706".to_string(),
+            law_name: "민법".to_string(),
+            total_count: 0,
+            entries: vec![],
+            stale: false,
+        };
+        cache
+            .put(
+                "nlic:history:265307",
+                serde_json::to_vec(&history).unwrap(),
+                ApiType::Nlic,
+                Some(chrono::Duration::seconds(-60)),
+            )
+            .await
+            .unwrap();
+
+        let cached = client.get_history("265307").await.unwrap();
+        assert_eq!(cached.law_name, "민법");
+        assert!(cached.stale);
+
+        let out = warp::output::Formatter::new(warp::cli::OutputFormat::Markdown)
+            .format_history(&cached)
+            .unwrap();
+        assert!(out.contains("캐시된 이전 결과"));
+    }
+
+    #[tokio::test]
+    async fn test_offline_cache_miss_is_an_error() {
+        let dir = TempDir::new().unwrap();
+        let (_cache, client) = offline_client(&dir).await;
+
+        let err = client.get_detail("999999").await.unwrap_err();
+        assert!(matches!(err, WarpError::Offline(_)));
+        assert!(err.hint().is_some());
+
+        let request = UnifiedSearchRequest {
+            query: "민법".to_string(),
+            ..Default::default()
+        };
+        let err = client.search(request).await.unwrap_err();
+        assert!(matches!(err, WarpError::Offline(ref item) if item.contains("민법")));
+    }
+}
+
+#[cfg(test)]
+mod stale_cache_tests {
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+    use warp::api::client::ClientConfig;
+    use warp::api::types::SearchResponse;
+    use warp::api::ApiType;
+    use warp::cache::{CacheConfig, CacheStore};
+    use warp::error::WarpError;
+
+    fn response(source: &str) -> SearchResponse {
+        SearchResponse {
+            total_count: 0,
+            page_no: 1,
+            page_size: 10,
+            items: vec![],
+            source: source.to_string(),
+            timestamp: Utc::now(),
+            stale: false,
+            cursors: Vec::new(),
+        }
+    }
+
+    async fn cached_config(dir: &TempDir, ttl: chrono::Duration) -> ClientConfig {
+        let cache = CacheStore::new(CacheConfig {
+            db_path: dir.path().join("cache.db"),
+            stale_window: chrono::Duration::days(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        cache
+            .put(
+                "nlic:search:test",
+                serde_json::to_vec(&response("cached")).unwrap(),
+                ApiType::Nlic,
+                Some(ttl),
+            )
+            .await
+            .unwrap();
+        ClientConfig {
+            cache: Some(Arc::new(cache)),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_entry_is_served_while_refreshing() {
+        let dir = TempDir::new().unwrap();
+        let config = cached_config(&dir, chrono::Duration::hours(-1)).await;
+        let refreshed = Arc::new(AtomicBool::new(false));
+
+        let flag = refreshed.clone();
+        let served = config
+            .cached_or_fetch(ApiType::Nlic, "nlic:search:test", "test", async move {
+                flag.store(true, Ordering::SeqCst);
+                Ok(response("fresh"))
+            })
+            .await
+            .unwrap();
+        assert_eq!(served.source, "cached");
+        assert!(served.stale);
+
+        warp::cache::wait_for_refreshes(std::time::Duration::from_secs(5)).await;
+        assert!(refreshed.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_is_served_on_retryable_error() {
+        let dir = TempDir::new().unwrap();
+        let config = cached_config(&dir, chrono::Duration::days(-30)).await;
+
+        let served = config
+            .cached_or_fetch(ApiType::Nlic, "nlic:search:test", "test", async {
+                Err::<SearchResponse, _>(WarpError::ServerError("503".to_string()))
+            })
+            .await
+            .unwrap();
+        assert_eq!(served.source, "cached");
+        assert!(served.stale);
+
+        let err = config
+            .cached_or_fetch(ApiType::Nlic, "nlic:search:test", "test", async {
+                Err::<SearchResponse, _>(WarpError::InvalidInput("bad".to_string()))
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WarpError::InvalidInput(_)));
+    }
 }